@@ -0,0 +1,202 @@
+//! USDC arithmetic shared by the Oseme programs.
+//!
+//! Amounts are whole USDC base units (6 decimals). Every proportional amount
+//! names how it rounds, and charges always round down: nobody pays a fraction
+//! of a unit they were not charged, and a remainder inside a fee stays with
+//! the treasury rather than the creator.
+
+/// Denominator of a basis-point rate
+pub const BPS_DENOMINATOR: u16 = 10_000;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Rounding {
+    Down,
+    Up,
+}
+
+/// An amount of USDC in base units
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UsdcAmount(u64);
+
+impl UsdcAmount {
+    pub const ZERO: Self = Self(0);
+
+    pub const fn new(units: u64) -> Self {
+        Self(units)
+    }
+
+    pub const fn units(self) -> u64 {
+        self.0
+    }
+
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        self.0.checked_add(other.0).map(Self)
+    }
+
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        self.0.checked_sub(other.0).map(Self)
+    }
+
+    /// `self × numerator / denominator`; `None` for a zero denominator or a
+    /// result that does not fit in a u64
+    pub fn mul_div(self, numerator: u64, denominator: u64, rounding: Rounding) -> Option<Self> {
+        if denominator == 0 {
+            return None;
+        }
+        let product = self.0 as u128 * numerator as u128;
+        let denominator = denominator as u128;
+        let mut quotient = product / denominator;
+        if rounding == Rounding::Up && quotient * denominator < product {
+            quotient += 1;
+        }
+        u64::try_from(quotient).ok().map(Self)
+    }
+
+    /// `bps` basis points of the amount
+    pub fn bps(self, bps: u16, rounding: Rounding) -> Option<Self> {
+        self.mul_div(bps as u64, BPS_DENOMINATOR as u64, rounding)
+    }
+}
+
+/// How a payout is divided between its recipient, the treasury and the
+/// group creator
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FeeSplit {
+    pub gross: UsdcAmount,
+    pub net: UsdcAmount,                 // Paid to the recipient
+    pub treasury: UsdcAmount,            // Platform's part of the fee
+    pub creator: UsdcAmount,             // Creator's part of the fee
+    pub waived: UsdcAmount,              // Fee not charged because of a waiver
+}
+
+impl FeeSplit {
+    /// Charge `fee_bps` of `gross` less `waiver_bps` of that fee, and give
+    /// the creator `creator_share_bps` of what is charged. The charged fee and
+    /// the creator share round down, so the recipient and then the treasury
+    /// keep the remainders. `None` for a rate above 100% or an inexact split.
+    pub fn new(gross: UsdcAmount, fee_bps: u16, waiver_bps: u16, creator_share_bps: u16) -> Option<Self> {
+        if fee_bps > BPS_DENOMINATOR || waiver_bps > BPS_DENOMINATOR || creator_share_bps > BPS_DENOMINATOR {
+            return None;
+        }
+
+        let fee = gross.bps(fee_bps, Rounding::Down)?;
+        let waived = fee.bps(waiver_bps, Rounding::Up)?;
+        let charged = fee.checked_sub(waived)?;
+        let creator = charged.bps(creator_share_bps, Rounding::Down)?;
+
+        let split = Self {
+            gross,
+            net: gross.checked_sub(charged)?,
+            treasury: charged.checked_sub(creator)?,
+            creator,
+            waived,
+        };
+        split.is_exact().then_some(split)
+    }
+
+    /// Fee charged on the payout, both shares together
+    pub fn platform_fee(&self) -> UsdcAmount {
+        UsdcAmount(self.treasury.0 + self.creator.0)
+    }
+
+    /// Whether the parts add up to exactly the gross amount
+    pub fn is_exact(&self) -> bool {
+        self.net
+            .checked_add(self.treasury)
+            .and_then(|sum| sum.checked_add(self.creator))
+            == Some(self.gross)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn usdc(units: u64) -> UsdcAmount {
+        UsdcAmount::new(units)
+    }
+
+    fn assert_exact(split: &FeeSplit) {
+        assert!(split.is_exact());
+        assert_eq!(
+            split.net.units() + split.treasury.units() + split.creator.units(),
+            split.gross.units()
+        );
+    }
+
+    #[test]
+    fn mul_div_rounds_as_asked() {
+        assert_eq!(usdc(10).mul_div(1, 3, Rounding::Down), Some(usdc(3)));
+        assert_eq!(usdc(10).mul_div(1, 3, Rounding::Up), Some(usdc(4)));
+        assert_eq!(usdc(9).mul_div(1, 3, Rounding::Up), Some(usdc(3)));
+        assert_eq!(usdc(0).mul_div(7, 3, Rounding::Up), Some(usdc(0)));
+    }
+
+    #[test]
+    fn mul_div_rejects_zero_denominator_and_overflow() {
+        assert_eq!(usdc(10).mul_div(1, 0, Rounding::Down), None);
+        assert_eq!(usdc(u64::MAX).mul_div(2, 1, Rounding::Down), None);
+        // The intermediate product is wider than a u64
+        assert_eq!(usdc(u64::MAX).mul_div(3, 3, Rounding::Down), Some(usdc(u64::MAX)));
+    }
+
+    #[test]
+    fn bps_of_an_amount() {
+        assert_eq!(usdc(1_000_000).bps(150, Rounding::Down), Some(usdc(15_000)));
+        assert_eq!(usdc(1).bps(1, Rounding::Down), Some(usdc(0)));
+        assert_eq!(usdc(1).bps(1, Rounding::Up), Some(usdc(1)));
+    }
+
+    #[test]
+    fn fee_split_is_exact_with_remainders() {
+        let split = FeeSplit::new(usdc(999_999), 150, 0, 3_333).unwrap();
+        assert_eq!(split.platform_fee(), usdc(14_999));
+        assert_eq!(split.creator, usdc(4_999));
+        assert_eq!(split.treasury, usdc(10_000));
+        assert_eq!(split.waived, usdc(0));
+        assert_exact(&split);
+    }
+
+    #[test]
+    fn fee_split_without_fee() {
+        let split = FeeSplit::new(usdc(1_000_000), 0, 0, 5_000).unwrap();
+        assert_eq!(split.net, split.gross);
+        assert_eq!(split.platform_fee(), UsdcAmount::ZERO);
+        assert_exact(&split);
+    }
+
+    #[test]
+    fn fee_split_at_full_rate() {
+        let split = FeeSplit::new(usdc(1_000_001), BPS_DENOMINATOR, 0, 2_500).unwrap();
+        assert_eq!(split.net, UsdcAmount::ZERO);
+        assert_eq!(split.creator, usdc(250_000));
+        assert_eq!(split.treasury, usdc(750_001));
+        assert_exact(&split);
+    }
+
+    #[test]
+    fn fee_split_with_full_waiver() {
+        let split = FeeSplit::new(usdc(1_000_000), 200, BPS_DENOMINATOR, 5_000).unwrap();
+        assert_eq!(split.waived, usdc(20_000));
+        assert_eq!(split.platform_fee(), UsdcAmount::ZERO);
+        assert_eq!(split.net, split.gross);
+        assert_exact(&split);
+    }
+
+    #[test]
+    fn fee_split_waiver_rounds_in_payers_favour() {
+        let split = FeeSplit::new(usdc(1_000_000), 101, 5_000, 0).unwrap();
+        assert_eq!(split.waived, usdc(5_050));
+        let split = FeeSplit::new(usdc(999_999), 101, 5_000, 0).unwrap();
+        assert_eq!(split.waived, usdc(5_050));
+        assert_eq!(split.treasury, usdc(5_049));
+        assert_exact(&split);
+    }
+
+    #[test]
+    fn fee_split_rejects_rates_above_full() {
+        assert_eq!(FeeSplit::new(usdc(100), BPS_DENOMINATOR + 1, 0, 0), None);
+        assert_eq!(FeeSplit::new(usdc(100), 100, BPS_DENOMINATOR + 1, 0), None);
+        assert_eq!(FeeSplit::new(usdc(100), 100, 0, BPS_DENOMINATOR + 1), None);
+    }
+}
@@ -48,7 +48,7 @@ const IDL: OsemeGroupIdl = {
   "events": [],
   "errors": [],
   "metadata": {
-    "address": "GrpABCDEFGHIJKLMNOPQRSTUVWXYZ123456789abcdef"
+    "address": "HJoSULsRS49HQaLfDWvsM8qvsnhxHhQRsFTgQCRzGCvs"
   }
 }
 
@@ -53,7 +53,7 @@ export class OsemeProgram {
   public connection: Connection
   public program: Program<any> | null = null
   public wallet: Wallet | null = null
-  private programId = new PublicKey('GrpABCDEFGHIJKLMNOPQRSTUVWXYZ123456789abcdef')
+  private programId = new PublicKey('HJoSULsRS49HQaLfDWvsM8qvsnhxHhQRsFTgQCRzGCvs')
   private usdcMint = new PublicKey('EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v') // Mainnet USDC
 
   constructor(connection?: Connection) {
@@ -16,7 +16,7 @@ export const USDC_MINT = {
 }
 
 // Program ID (replace with actual deployed program ID)
-export const PROGRAM_ID = new PublicKey('GrpABCDEFGHIJKLMNOPQRSTUVWXYZ123456789abcdef')
+export const PROGRAM_ID = new PublicKey('HJoSULsRS49HQaLfDWvsM8qvsnhxHhQRsFTgQCRzGCvs')
 
 export const getCurrentUSDC = () => {
   return SOLANA_NETWORK === 'mainnet' ? USDC_MINT.mainnet : USDC_MINT.devnet
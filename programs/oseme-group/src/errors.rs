@@ -49,4 +49,148 @@ pub enum OsemeGroupError {
     
     #[msg("USDC decimal calculation error")]
     UsdcCalculationError,
-}
\ No newline at end of file
+    
+    #[msg("Invalid group status")]
+    InvalidGroupStatus,
+    
+    #[msg("Payout order exceeds member cap")]
+    PayoutOrderTooLong,
+    
+    #[msg("Account must be migrated to the current layout")]
+    AccountVersionMismatch,
+    
+    #[msg("Account is already on the current layout")]
+    AccountAlreadyMigrated,
+    
+    #[msg("Account layout is not supported for migration")]
+    UnsupportedAccountLayout,
+    
+    #[msg("Group is not finalized or cancelled")]
+    GroupNotClosedOut,
+    
+    #[msg("Stake must be withdrawn first")]
+    StakeNotWithdrawn,
+    
+    #[msg("Escrow still holds tokens")]
+    EscrowNotEmpty,
+    
+    #[msg("Member has unpaid contributions")]
+    OutstandingObligations,
+    
+    #[msg("Model configuration is invalid")]
+    InvalidModelConfig,
+    
+    #[msg("Cycle length outside the model's allowed range")]
+    InvalidCycleLength,
+    
+    #[msg("Contribution amount outside the model's allowed range")]
+    ContributionOutOfRange,
+    
+    #[msg("Member's trust result has already been settled")]
+    TrustAlreadySettled,
+    
+    #[msg("Member's trust result has not been settled")]
+    TrustNotSettled,
+    
+    #[msg("Trust score or history below the model's minimum")]
+    InsufficientTrustScore,
+    
+    #[msg("Vouch exceeds the voucher's available stake")]
+    VouchExceedsStake,
+    
+    #[msg("Members cannot vouch for themselves")]
+    CannotVouchForSelf,
+    
+    #[msg("Vouch is locked until the group is finalized or cancelled")]
+    VouchLocked,
+    
+    #[msg("Guarantor must be another wallet with a non-zero allowance")]
+    InvalidGuarantee,
+    
+    #[msg("Guarantor accounts do not match the guarantee")]
+    GuarantorAccountMismatch,
+    
+    #[msg("Guarantor is still liable for the open turn")]
+    GuarantorStillLiable,
+    
+    #[msg("Debt account is required for a member who was already paid out")]
+    DebtAccountRequired,
+    
+    #[msg("Repayment exceeds the outstanding debt")]
+    RepaymentExceedsDebt,
+    
+    #[msg("No shorted members to receive the repayment")]
+    NoShortedMembers,
+    
+    #[msg("Nothing to claim")]
+    NothingToClaim,
+    
+    #[msg("Wallet has unresolved debts from a previous group")]
+    UnresolvedDebt,
+    
+    #[msg("Group does not admit wallets with past defaults")]
+    PastDefaultsNotAdmitted,
+    
+    #[msg("Full collateral is only available in Trust and Super-Trust groups")]
+    CollateralNotAvailable,
+    
+    #[msg("Turn is covered by the member's collateral")]
+    CollateralCoversTurn,
+    
+    #[msg("Member has no collateral to draw for this turn")]
+    InsufficientCollateral,
+    
+    #[msg("An active subscription of this tier is required to create the group")]
+    SubscriptionRequired,
+    
+    #[msg("Bonus accounts must be member and USDC account pairs of this group")]
+    BonusAccountMismatch,
+    
+    #[msg("Fee schedule has too many tiers or a fee above 100%")]
+    InvalidFeeSchedule,
+    
+    #[msg("Promo needs a waiver of at most 100%, at least one use and a future expiry")]
+    InvalidPromo,
+    
+    #[msg("Promo has expired or has no uses left")]
+    PromoNotAttachable,
+    
+    #[msg("Wallet has reached the promo's group limit")]
+    PromoWalletLimitReached,
+    
+    #[msg("Promo and promo redemption accounts must be passed together and match the group")]
+    PromoAccountMismatch,
+    
+    #[msg("Platform is paused")]
+    PlatformPaused,
+    
+    #[msg("Platform is not paused")]
+    PlatformNotPaused,
+    
+    #[msg("Group is not paused")]
+    GroupNotPaused,
+    
+    #[msg("Pause request does not belong to this group")]
+    PauseRequestMismatch,
+    
+    #[msg("Payout order has a duplicate or default wallet")]
+    InvalidPayoutOrder,
+    
+    #[msg("Platform config is required to migrate a group")]
+    PlatformConfigRequired,
+    
+    #[msg("Vouch is below the stake the newcomer would owe")]
+    VouchTooSmall,
+    
+    #[msg("Group has debts still being repaid")]
+    DebtOutstanding,
+    
+    #[msg("Debt recovery owed to the member must be claimed first")]
+    RecoveryNotClaimed,
+    
+    #[msg("Debt has nothing outstanding")]
+    NoDebtOutstanding,
+    
+    #[msg("Treasury accounts are required when no shorted member can receive the repayment")]
+    TreasuryAccountsRequired,
+}
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::state::FeeTier;
 
 #[event]
 pub struct GroupCreated {
@@ -16,15 +17,19 @@ pub struct MemberJoined {
     pub group: Pubkey,
     pub member: Pubkey,
     pub stake_amount: u64,
+    pub voucher: Option<Pubkey>,
+    pub vouched_amount: u64,
+    pub collateral_amount: u64,          // Deposit covering every remaining turn, 0 if not full-collateral
     pub timestamp: i64,
 }
 
 #[event]
 pub struct ContributionMade {
     pub group: Pubkey,
-    pub member: Pubkey,
+    pub contributor: Pubkey,
     pub turn_index: u8,
     pub amount: u64,
+    pub escrow_balance: u64,
     pub timestamp: i64,
 }
 
@@ -34,8 +39,12 @@ pub struct PayoutReleased {
     pub recipient: Pubkey,
     pub turn_index: u8,
     pub gross_amount: u64,
-    pub fee_amount: u64,
+    pub platform_fee: u64,
+    pub creator_fee: u64,
     pub net_amount: u64,
+    pub fee_bps: u16,                    // Rate charged on gross_amount
+    pub fee_tier: Option<u8>,            // FeeSchedule tier that set the rate, `None` for the model's fee
+    pub fee_waived: u64,                 // Platform fee waived by the group's promo
     pub timestamp: i64,
 }
 
@@ -53,6 +62,8 @@ pub struct GroupFinalized {
     pub group: Pubkey,
     pub final_trust_score: u8,
     pub total_members: u8,
+    pub bonus_reserved: u64,             // Taken from the bonus pool for compliant members
+    pub dust_swept: u64,                 // Rounding remainder moved from the escrow to the treasury
     pub timestamp: i64,
 }
 
@@ -60,7 +71,8 @@ pub struct GroupFinalized {
 pub struct GroupPaused {
     pub group: Pubkey,
     pub admin: Pubkey,
-    pub reason: String,
+    pub reason: u8,                      // PauseReason as u8
+    pub requested_by: Option<Pubkey>,    // Creator whose pause request was confirmed
     pub timestamp: i64,
 }
 
@@ -68,5 +80,259 @@ pub struct GroupPaused {
 pub struct GroupResumed {
     pub group: Pubkey,
     pub admin: Pubkey,
+    pub paused_secs: i64,                // Added to the current turn's deadlines
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct GroupPauseRequested {
+    pub group: Pubkey,
+    pub creator: Pubkey,
+    pub reason: u8,                      // PauseReason as u8
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PlatformPaused {
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PlatformResumed {
+    pub authority: Pubkey,
+    pub paused_secs: i64,                // Length of the pause just ended; deadlines moved by as much
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EscrowDiscrepancy {
+    pub group: Pubkey,
+    pub token_balance: u64,              // Held by the escrow token account
+    pub tracked_balance: u64,            // Owed according to the EscrowVault balances
+    pub current_balance: u64,
+    pub open_turn_pool: u64,             // Contributions recorded for the open turn
+    pub shortfall: u64,                  // Tracked or recorded funds missing from the escrow
+    pub surplus: u64,                    // Tokens beyond the tracked balance
+    pub group_paused: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AccountMigrated {
+    pub account: Pubkey,
+    pub from_version: u8,
+    pub to_version: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct StakeWithdrawn {
+    pub group: Pubkey,
+    pub member: Pubkey,
+    pub amount: u64,
+    pub bonus: u64,                      // Completion bonus paid with the stake
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MemberClosed {
+    pub group: Pubkey,
+    pub member: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct GroupClosed {
+    pub group: Pubkey,
+    pub creator: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ModelConfigUpdated {
+    pub model: u8, // GroupModel as u8
+    pub authority: Pubkey,
+    pub member_cap: u8,
+    pub fee_bps: u16,
+    pub creator_share_bps: u16,
+    pub stake_ratio_bps: u16,
+    pub exposure_stake_bps: u16,
+    pub min_member_score: u16,
+    pub min_creator_score: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FeeScheduleUpdated {
+    pub model: u8, // GroupModel as u8
+    pub authority: Pubkey,
+    pub tiers: Vec<FeeTier>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MemberSlashed {
+    pub group: Pubkey,
+    pub member: Pubkey,
+    pub turn_index: u8,
+    pub covered_amount: u64,
+    pub guarantor_covered: u64,          // Part of covered_amount pulled from a guarantor
+    pub voucher_covered: u64,            // Part of covered_amount taken from a vouch
+    pub shortfall: u64,
+    pub debt_outstanding: u64,           // Debtor's total after this slash, 0 if not yet paid out
+    pub trust_penalty: i8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MemberSettled {
+    pub group: Pubkey,
+    pub member: Pubkey,
+    pub trust_delta: i8,
+    pub clean: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MemberVouched {
+    pub group: Pubkey,
+    pub voucher: Pubkey,
+    pub member: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VouchReleased {
+    pub group: Pubkey,
+    pub voucher: Pubkey,
+    pub member: Pubkey,
+    pub refunded: u64,
+    pub slashed_amount: u64,
+    pub trust_bonus: i8,
     pub timestamp: i64,
-}
\ No newline at end of file
+}
+
+#[event]
+pub struct GuarantorAdded {
+    pub group: Pubkey,
+    pub member: Pubkey,
+    pub guarantor: Pubkey,
+    pub allowance: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct GuarantorRevoked {
+    pub group: Pubkey,
+    pub member: Pubkey,
+    pub guarantor: Pubkey,
+    pub last_liable_turn: u8,
+    pub closed: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct GuarantorDefaulted {
+    pub group: Pubkey,
+    pub member: Pubkey,
+    pub guarantor: Pubkey,
+    pub turn_index: u8,
+    pub promised: u64,                   // Allowance left for the turn when it was slashed
+    pub covered: u64,                    // What could actually be pulled
+    pub trust_penalty: i8,               // Applied to the guarantor's UserTrust
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DebtRepaid {
+    pub group: Pubkey,
+    pub debtor: Pubkey,
+    pub payer: Pubkey,
+    pub amount: u64,
+    pub to_open_turn: u64,               // Part that refilled the open turn's shortfall
+    pub to_treasury: u64,                // Part no shorted member was left to receive
+    pub outstanding: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DebtWrittenOff {
+    pub group: Pubkey,
+    pub debtor: Pubkey,
+    pub amount: u64,                     // Outstanding balance no longer expected in the escrow
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RecoveryClaimed {
+    pub group: Pubkey,
+    pub member: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct StakeReleased {
+    pub group: Pubkey,
+    pub member: Pubkey,
+    pub amount: u64,
+    pub remaining_stake: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CollateralDrawn {
+    pub group: Pubkey,
+    pub member: Pubkey,
+    pub turn_index: u8,
+    pub amount: u64,
+    pub refunded: u64,                   // Collateral beyond what the payout order can still draw
+    pub remaining: u64,
+    pub timestamp: i64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct BonusPayment {
+    pub member: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct BonusesDistributed {
+    pub group: Pubkey,
+    pub bonuses: Vec<BonusPayment>,
+    pub remaining: u64,                  // Reserved bonus still unpaid
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PromoCreated {
+    pub promo: Pubkey,
+    pub code: [u8; 16],
+    pub waiver_bps: u16,
+    pub max_uses: u32,
+    pub expires_at: i64,
+    pub per_wallet_limit: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PromoAttached {
+    pub promo: Pubkey,
+    pub group: Pubkey,
+    pub creator: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PromoRedeemed {
+    pub promo: Pubkey,
+    pub group: Pubkey,
+    pub creator: Pubkey,
+    pub turn_index: u8,
+    pub fee_waived: u64,
+    pub uses_remaining: u32,
+    pub timestamp: i64,
+}
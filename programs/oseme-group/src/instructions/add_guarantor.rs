@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount, Approve, approve};
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct AddGuarantor<'info> {
+    #[account(
+        constraint = group.load()?.version == Group::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub group: AccountLoader<'info, Group>,
+    
+    #[account(
+        seeds = [b"member", group.key().as_ref(), user.key().as_ref()],
+        bump = member.bump,
+        constraint = member.version == Member::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub member: Account<'info, Member>,
+    
+    #[account(
+        init,
+        payer = guarantor_wallet,
+        space = Guarantor::LEN,
+        seeds = [b"guarantor", group.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub guarantor: Account<'info, Guarantor>,
+    
+    #[account(
+        seeds = [b"escrow", group.key().as_ref()],
+        bump = escrow_vault.bump,
+        constraint = escrow_vault.version == EscrowVault::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub escrow_vault: Account<'info, EscrowVault>,
+    
+    /// Guarantor's USDC account; a dedicated account is recommended since
+    /// SPL token accounts hold a single delegate
+    #[account(
+        mut,
+        constraint = guarantor_token_account.mint == platform_config.usdc_mint,
+        constraint = guarantor_token_account.owner == guarantor_wallet.key()
+    )]
+    pub guarantor_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        seeds = [b"platform-config"],
+        bump = platform_config.bump,
+        constraint = platform_config.version == PlatformConfig::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// Guaranteed member
+    pub user: Signer<'info>,
+    
+    #[account(mut)]
+    pub guarantor_wallet: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Co-signed by the member and the guarantor
+pub fn add_guarantor(ctx: Context<AddGuarantor>, allowance: u64) -> Result<()> {
+    let group_key = ctx.accounts.group.key();
+    let group = ctx.accounts.group.load()?;
+    let guarantor = &mut ctx.accounts.guarantor;
+    let user_key = ctx.accounts.user.key();
+    let clock = Clock::get()?;
+    
+    if group.status()? != GroupStatus::Active {
+        return Err(OsemeGroupError::GroupNotActive.into());
+    }
+    
+    if ctx.accounts.guarantor_wallet.key() == user_key || allowance == 0 {
+        return Err(OsemeGroupError::InvalidGuarantee.into());
+    }
+    
+    // Let the escrow vault pull missed contributions from the guarantor
+    let approve_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        Approve {
+            to: ctx.accounts.guarantor_token_account.to_account_info(),
+            delegate: ctx.accounts.escrow_vault.to_account_info(),
+            authority: ctx.accounts.guarantor_wallet.to_account_info(),
+        }
+    );
+    approve(approve_ctx, allowance)?;
+    
+    guarantor.version = Guarantor::VERSION;
+    guarantor.group = group_key;
+    guarantor.member = user_key;
+    guarantor.guarantor = ctx.accounts.guarantor_wallet.key();
+    guarantor.token_account = ctx.accounts.guarantor_token_account.key();
+    guarantor.allowance = allowance;
+    guarantor.pulled_amount = 0;
+    guarantor.last_liable_turn = Guarantor::OPEN_ENDED;
+    guarantor.bump = ctx.bumps.guarantor;
+    
+    emit!(GuarantorAdded {
+        group: group_key,
+        member: user_key,
+        guarantor: guarantor.guarantor,
+        allowance,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount, Transfer, transfer};
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct ClaimRecovery<'info> {
+    #[account(
+        constraint = group.load()?.version == Group::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub group: AccountLoader<'info, Group>,
+    
+    #[account(
+        mut,
+        seeds = [b"member", group.key().as_ref(), user.key().as_ref()],
+        bump = member.bump,
+        constraint = member.version == Member::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub member: Account<'info, Member>,
+    
+    #[account(
+        mut,
+        seeds = [b"escrow", group.key().as_ref()],
+        bump = escrow_vault.bump,
+        constraint = escrow_vault.version == EscrowVault::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub escrow_vault: Account<'info, EscrowVault>,
+    
+    #[account(
+        mut,
+        seeds = [b"escrow-token", group.key().as_ref()],
+        bump
+    )]
+    pub escrow_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = user_token_account.mint == escrow_token_account.mint,
+        constraint = user_token_account.owner == user.key()
+    )]
+    pub user_token_account: Account<'info, TokenAccount>,
+    
+    pub user: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn claim_recovery(ctx: Context<ClaimRecovery>) -> Result<()> {
+    let group_key = ctx.accounts.group.key();
+    let member = &mut ctx.accounts.member;
+    let escrow_vault = &mut ctx.accounts.escrow_vault;
+    let clock = Clock::get()?;
+    
+    member.settle_recovery(escrow_vault.recovery_per_shorted);
+    
+    // Once no debt can be repaid into the group any more, a claim settles
+    // the member's recovery for good and the last one takes the rounding
+    // remainder of the pro rata shares
+    let recovery_final = ctx.accounts.group.load()?.is_closed_out()?
+        && escrow_vault.outstanding_debt == 0;
+    let settled = recovery_final && member.shorted_amount > 0;
+    if settled {
+        escrow_vault.total_shorted = escrow_vault.total_shorted.saturating_sub(member.shorted_amount);
+        member.shorted_amount = 0;
+        if escrow_vault.total_shorted == 0 {
+            member.recovery_claimable = escrow_vault.recovery_balance;
+        }
+    }
+    
+    let amount = member.recovery_claimable;
+    if amount == 0 {
+        if settled {
+            return Ok(());
+        }
+        return Err(OsemeGroupError::NothingToClaim.into());
+    }
+    
+    let seeds = &[
+        b"escrow",
+        group_key.as_ref(),
+        &[escrow_vault.bump]
+    ];
+    let signer_seeds = &[&seeds[..]];
+    
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.escrow_token_account.to_account_info(),
+            to: ctx.accounts.user_token_account.to_account_info(),
+            authority: escrow_vault.to_account_info(),
+        },
+        signer_seeds
+    );
+    transfer(transfer_ctx, amount)?;
+    
+    escrow_vault.recovery_balance = escrow_vault.recovery_balance
+        .checked_sub(amount)
+        .ok_or(OsemeGroupError::UsdcCalculationError)?;
+    member.recovery_claimable = 0;
+    
+    emit!(RecoveryClaimed {
+        group: group_key,
+        member: member.user,
+        amount,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount, CloseAccount, close_account};
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct CloseGroup<'info> {
+    #[account(
+        mut,
+        close = creator,
+        constraint = group.load()?.creator == creator.key() @ OsemeGroupError::Unauthorized,
+        constraint = group.load()?.version == Group::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub group: AccountLoader<'info, Group>,
+    
+    #[account(
+        mut,
+        close = creator,
+        seeds = [b"escrow", group.key().as_ref()],
+        bump = escrow_vault.bump,
+        constraint = escrow_vault.version == EscrowVault::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub escrow_vault: Account<'info, EscrowVault>,
+    
+    #[account(
+        mut,
+        seeds = [b"escrow-token", group.key().as_ref()],
+        bump
+    )]
+    pub escrow_token_account: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn close_group(ctx: Context<CloseGroup>) -> Result<()> {
+    let group_key = ctx.accounts.group.key();
+    let escrow_vault = &ctx.accounts.escrow_vault;
+    let clock = Clock::get()?;
+    
+    if !ctx.accounts.group.load()?.is_closed_out()? {
+        return Err(OsemeGroupError::GroupNotClosedOut.into());
+    }
+    
+    // Unwithdrawn stakes, collateral, bonuses or contributions keep the escrow open
+    if ctx.accounts.escrow_token_account.amount > 0
+        || escrow_vault.current_balance > 0
+        || escrow_vault.stake_balance > 0
+        || escrow_vault.collateral_balance > 0
+        || escrow_vault.bonus_reserved > 0
+    {
+        return Err(OsemeGroupError::EscrowNotEmpty.into());
+    }
+    
+    // Repayments of open debts still need the escrow to land in
+    if escrow_vault.outstanding_debt > 0 {
+        return Err(OsemeGroupError::DebtOutstanding.into());
+    }
+    
+    let seeds = &[
+        b"escrow",
+        group_key.as_ref(),
+        &[escrow_vault.bump]
+    ];
+    let signer_seeds = &[&seeds[..]];
+    
+    let close_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.escrow_token_account.to_account_info(),
+            destination: ctx.accounts.creator.to_account_info(),
+            authority: escrow_vault.to_account_info(),
+        },
+        signer_seeds
+    );
+    close_account(close_ctx)?;
+    
+    emit!(GroupClosed {
+        group: group_key,
+        creator: ctx.accounts.creator.key(),
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct CloseMember<'info> {
+    #[account(
+        mut,
+        close = user,
+        has_one = user,
+        seeds = [b"member", member.group.as_ref(), user.key().as_ref()],
+        bump = member.bump,
+        constraint = member.version == Member::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub member: Account<'info, Member>,
+    
+    /// CHECK: The group may already have been closed by its creator
+    #[account(address = member.group)]
+    pub group: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub user: Signer<'info>,
+}
+
+pub fn close_member(ctx: Context<CloseMember>) -> Result<()> {
+    let member = &ctx.accounts.member;
+    let clock = Clock::get()?;
+    
+    if member.stake_amount > 0 || member.collateral_balance > 0 {
+        return Err(OsemeGroupError::StakeNotWithdrawn.into());
+    }
+    
+    if member.recovery_claimable > 0 {
+        return Err(OsemeGroupError::RecoveryNotClaimed.into());
+    }
+    
+    // A closed group was finalized before close_group ran
+    if !ctx.accounts.group.data_is_empty() {
+        let data = ctx.accounts.group.try_borrow_data()?;
+        if ctx.accounts.group.owner != &crate::ID
+            || data.len() < Group::LEN
+            || data[..8] != Group::DISCRIMINATOR
+        {
+            return Err(OsemeGroupError::AccountVersionMismatch.into());
+        }
+        let group: &Group = bytemuck::from_bytes(&data[8..Group::LEN]);
+        
+        // Shorted members settle their recovery with a final claim_recovery
+        if member.shorted_amount > 0 {
+            return Err(OsemeGroupError::RecoveryNotClaimed.into());
+        }
+        
+        if !group.is_closed_out()? {
+            return Err(OsemeGroupError::GroupNotClosedOut.into());
+        }
+        if member.unpaid_turns(group.current_turn_index) > 0 {
+            return Err(OsemeGroupError::OutstandingObligations.into());
+        }
+        if member.trust_settled_at == 0 {
+            return Err(OsemeGroupError::TrustNotSettled.into());
+        }
+    }
+    
+    emit!(MemberClosed {
+        group: member.group,
+        member: member.user,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
@@ -1,25 +1,32 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{Token, TokenAccount, Transfer, transfer};
+use oseme_trust::program::OsemeTrust;
 use crate::state::*;
+use crate::trust::*;
 use crate::errors::*;
 use crate::events::*;
 
 #[derive(Accounts)]
 pub struct Contribute<'info> {
-    #[account(mut)]
-    pub group: Account<'info, Group>,
+    #[account(
+        mut,
+        constraint = group.load()?.version == Group::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub group: AccountLoader<'info, Group>,
     
     #[account(
         mut,
         seeds = [b"member", group.key().as_ref(), contributor.key().as_ref()],
-        bump = member.bump
+        bump = member.bump,
+        constraint = member.version == Member::VERSION @ OsemeGroupError::AccountVersionMismatch
     )]
     pub member: Account<'info, Member>,
     
     #[account(
         mut,
         seeds = [b"escrow", group.key().as_ref()],
-        bump = escrow_vault.bump
+        bump = escrow_vault.bump,
+        constraint = escrow_vault.version == EscrowVault::VERSION @ OsemeGroupError::AccountVersionMismatch
     )]
     pub escrow_vault: Account<'info, EscrowVault>,
     
@@ -38,42 +45,65 @@ pub struct Contribute<'info> {
     
     #[account(
         seeds = [b"platform-config"],
-        bump = platform_config.bump
+        bump = platform_config.bump,
+        constraint = platform_config.version == PlatformConfig::VERSION @ OsemeGroupError::AccountVersionMismatch
     )]
     pub platform_config: Account<'info, PlatformConfig>,
     
+    #[account(
+        seeds = [b"model-config".as_ref(), &[group.load()?.model]],
+        bump = model_config.bump,
+        constraint = model_config.version == ModelConfig::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub model_config: Account<'info, ModelConfig>,
+    
     #[account(mut)]
     pub contributor: Signer<'info>,
     
+    /// CHECK: Contributor's UserTrust PDA, validated and created by oseme-trust
+    #[account(mut)]
+    pub user_trust: UncheckedAccount<'info>,
+    
+    /// CHECK: Signer PDA for reputation updates
+    #[account(seeds = [TRUST_AUTHORITY_SEED], bump)]
+    pub trust_authority: UncheckedAccount<'info>,
+    
+    pub trust_program: Program<'info, OsemeTrust>,
     pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<Contribute>, amount: u64) -> Result<()> {
-    let group = &mut ctx.accounts.group;
+pub fn contribute(ctx: Context<Contribute>, amount: u64) -> Result<()> {
+    let group_key = ctx.accounts.group.key();
+    let mut group = ctx.accounts.group.load_mut()?;
     let member = &mut ctx.accounts.member;
     let escrow_vault = &mut ctx.accounts.escrow_vault;
     let clock = Clock::get()?;
     
+    if ctx.accounts.platform_config.is_paused() {
+        return Err(OsemeGroupError::PlatformPaused.into());
+    }
+    
     // Verify group is active
-    if group.status != GroupStatus::Active {
+    if group.status()? != GroupStatus::Active {
         return Err(OsemeGroupError::GroupNotActive.into());
     }
     
     // Verify it's the contributor's turn or they can contribute
-    let current_recipient = group.payout_order.get(group.current_turn_index as usize)
+    let current_recipient = group.current_recipient()
         .ok_or(OsemeGroupError::NotCurrentTurnRecipient)?;
     
     // For Basic model, only current recipient contributes
     // For Trust/SuperTrust, any member can contribute
-    match group.model {
+    match group.model()? {
         GroupModel::Basic => {
-            if ctx.accounts.contributor.key() != *current_recipient {
+            if ctx.accounts.contributor.key() != current_recipient {
                 return Err(OsemeGroupError::NotCurrentTurnRecipient.into());
             }
         },
         _ => {
             // Trust/SuperTrust: verify member is in group
-            if member.group != group.key() || member.user != ctx.accounts.contributor.key() {
+            if member.group != group_key || member.user != ctx.accounts.contributor.key() {
                 return Err(OsemeGroupError::MemberNotFound.into());
             }
         }
@@ -84,14 +114,23 @@ pub fn handler(ctx: Context<Contribute>, amount: u64) -> Result<()> {
         return Err(OsemeGroupError::IncorrectContributionAmount.into());
     }
     
-    // Check if turn deadline has passed
-    let turn_duration = group.cycle_days as i64 * 24 * 60 * 60; // Convert days to seconds
-    let turn_deadline = group.current_turn_start + turn_duration;
+    // Late contributions are accepted until the grace period ends
+    let platform_config = &ctx.accounts.platform_config;
+    let grace_deadline = group.grace_deadline(
+        platform_config.paused_secs(clock.unix_timestamp),
+        platform_config.grace_period_days,
+    );
     
-    if clock.unix_timestamp > turn_deadline {
+    if clock.unix_timestamp > grace_deadline {
         return Err(OsemeGroupError::TurnDeadlineNotReached.into());
     }
     
+    // One contribution per member per turn
+    let turn_index = group.current_turn_index;
+    if group.has_contributed(turn_index, member.payout_position) {
+        return Err(OsemeGroupError::TurnAlreadyCompleted.into());
+    }
+    
     // Transfer USDC to escrow
     let transfer_ctx = CpiContext::new(
         ctx.accounts.token_program.to_account_info(),
@@ -108,17 +147,74 @@ pub fn handler(ctx: Context<Contribute>, amount: u64) -> Result<()> {
         .checked_add(amount)
         .ok_or(OsemeGroupError::UsdcCalculationError)?;
     
-    // Mark contribution in member record
-    if (member.contributed_turns.len() as u8) <= group.current_turn_index {
-        member.contributed_turns.resize((group.current_turn_index + 1) as usize, false);
+    // Mark contribution in the turn bitmap and the member record
+    group.mark_contributed(turn_index, member.payout_position);
+    member.mark_contributed(turn_index);
+    
+    // Once paid out, each contribution lowers the exposure the stake covers;
+    // members with a missed turn keep their stake locked
+    let mut released_stake = 0;
+    if member.payout_position < turn_index && member.missed_count == 0 && !member.is_creator {
+        let owed_after_payout = Group::owed_after_payout(
+            member.payout_position,
+            turn_index + 1,
+            group.turn_count(),
+        );
+        let required_stake = ctx.accounts.model_config.stake_for(group.contribution_amount, owed_after_payout)?;
+        released_stake = member.stake_amount.saturating_sub(required_stake);
     }
-    member.contributed_turns[group.current_turn_index as usize] = true;
+    
+    if released_stake > 0 {
+        let seeds = &[
+            b"escrow",
+            group_key.as_ref(),
+            &[escrow_vault.bump]
+        ];
+        let signer_seeds = &[&seeds[..]];
+        
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.escrow_token_account.to_account_info(),
+                to: ctx.accounts.contributor_token_account.to_account_info(),
+                authority: escrow_vault.to_account_info(),
+            },
+            signer_seeds
+        );
+        transfer(transfer_ctx, released_stake)?;
+        
+        member.stake_amount -= released_stake;
+        escrow_vault.stake_balance = escrow_vault.stake_balance
+            .checked_sub(released_stake)
+            .ok_or(OsemeGroupError::UsdcCalculationError)?;
+        escrow_vault.compliant_stake = escrow_vault.compliant_stake.saturating_sub(released_stake);
+        
+        emit!(StakeReleased {
+            group: group_key,
+            member: member.user,
+            amount: released_stake,
+            remaining_stake: member.stake_amount,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+    
+    // Count the contribution towards the contributor's cross-group reputation
+    TrustUpdate {
+        trust_program: ctx.accounts.trust_program.to_account_info(),
+        user_trust: ctx.accounts.user_trust.to_account_info(),
+        user: ctx.accounts.contributor.to_account_info(),
+        trust_authority: ctx.accounts.trust_authority.to_account_info(),
+        payer: ctx.accounts.contributor.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+        trust_authority_bump: ctx.bumps.trust_authority,
+        params: ctx.accounts.platform_config.trust_params(),
+    }.record_contribution()?;
     
     // Emit contribution event
     emit!(ContributionMade {
-        group: group.key(),
+        group: group_key,
         contributor: ctx.accounts.contributor.key(),
-        turn_index: group.current_turn_index,
+        turn_index,
         amount,
         escrow_balance: escrow_vault.current_balance,
         timestamp: clock.unix_timestamp,
@@ -126,4 +222,4 @@ pub fn handler(ctx: Context<Contribute>, amount: u64) -> Result<()> {
     
     msg!("Contribution of {} USDC made by {}", amount, ctx.accounts.contributor.key());
     Ok(())
-}
\ No newline at end of file
+}
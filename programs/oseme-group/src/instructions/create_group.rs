@@ -1,7 +1,9 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Token, TokenAccount};
+use anchor_spl::token::{Mint, Token, TokenAccount};
 use anchor_spl::associated_token::AssociatedToken;
 use crate::state::*;
+use crate::trust::*;
+use crate::treasury::*;
 use crate::errors::*;
 use crate::events::*;
 
@@ -15,7 +17,7 @@ pub struct CreateGroup<'info> {
         seeds = [b"group", creator.key().as_ref(), &Clock::get()?.unix_timestamp.to_le_bytes()],
         bump
     )]
-    pub group: Account<'info, Group>,
+    pub group: AccountLoader<'info, Group>,
     
     #[account(
         init,
@@ -29,7 +31,7 @@ pub struct CreateGroup<'info> {
     #[account(
         init,
         payer = creator,
-        token::mint = platform_config.usdc_mint,
+        token::mint = usdc_mint,
         token::authority = escrow_vault,
         seeds = [b"escrow-token", group.key().as_ref()],
         bump
@@ -38,10 +40,57 @@ pub struct CreateGroup<'info> {
     
     #[account(
         seeds = [b"platform-config"],
-        bump = platform_config.bump
+        bump = platform_config.bump,
+        constraint = platform_config.version == PlatformConfig::VERSION @ OsemeGroupError::AccountVersionMismatch
     )]
     pub platform_config: Account<'info, PlatformConfig>,
     
+    #[account(
+        seeds = [b"model-config".as_ref(), &[model as u8]],
+        bump = model_config.bump,
+        constraint = model_config.version == ModelConfig::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub model_config: Account<'info, ModelConfig>,
+    
+    #[account(address = platform_config.usdc_mint)]
+    pub usdc_mint: Account<'info, Mint>,
+    
+    /// CHECK: Creator's UserTrust PDA in oseme-trust, may not exist yet
+    #[account(
+        seeds = [USER_TRUST_SEED, creator.key().as_ref()],
+        bump,
+        seeds::program = oseme_trust::ID
+    )]
+    pub user_trust: UncheckedAccount<'info>,
+    
+    /// CHECK: Creator's Subscription PDA in oseme-treasury, may not exist
+    #[account(
+        seeds = [SUBSCRIPTION_SEED, creator.key().as_ref()],
+        bump,
+        seeds::program = oseme_treasury::ID
+    )]
+    pub subscription: UncheckedAccount<'info>,
+    
+    /// Promo to attach, if any
+    #[account(
+        mut,
+        constraint = promo.version == Promo::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub promo: Option<Account<'info, Promo>>,
+    
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = PromoRedemption::LEN,
+        seeds = [
+            b"promo-redemption",
+            promo.as_ref().map(|promo| promo.key()).unwrap_or_default().as_ref(),
+            creator.key().as_ref()
+        ],
+        bump
+    )]
+    pub promo_redemption: Option<Account<'info, PromoRedemption>>,
+    
     #[account(mut)]
     pub creator: Signer<'info>,
     
@@ -54,70 +103,162 @@ pub struct CreateGroup<'info> {
 pub fn create_group(
     ctx: Context<CreateGroup>,
     model: GroupModel,
+    contribution_amount: u64,
     cycle_days: Option<u32>,
     member_cap: Option<u8>,
     payout_order: Option<Vec<Pubkey>>,
+    admit_past_defaulters: bool,
 ) -> Result<()> {
-    let group = &mut ctx.accounts.group;
+    let group_key = ctx.accounts.group.key();
+    let escrow_vault_key = ctx.accounts.escrow_vault.key();
+    let mut group = ctx.accounts.group.load_init()?;
     let escrow_vault = &mut ctx.accounts.escrow_vault;
-    let platform_config = &ctx.accounts.platform_config;
+    let model_config = &ctx.accounts.model_config;
     let clock = Clock::get()?;
     
-    // Validate model-specific constraints
-    match model {
-        GroupModel::Basic => {
-            // TODO: Check global Basic group limit
-            // TODO: Check per-creator Basic group limit
-            
-            if member_cap.unwrap_or(5) > 5 {
-                return Err(OsemeGroupError::GroupCapacityExceeded.into());
-            }
-            
-            group.cycle_days = 7; // Fixed 7 days for Basic
-            group.member_cap = member_cap.unwrap_or(5);
-        },
-        GroupModel::Trust => {
-            group.cycle_days = cycle_days.unwrap_or(7);
-            group.member_cap = member_cap.unwrap_or(30).min(30);
-        },
-        GroupModel::SuperTrust => {
-            group.cycle_days = cycle_days.unwrap_or(7);
-            group.member_cap = member_cap.unwrap_or(100).min(100);
-        },
+    // No new groups while the platform is paused
+    if ctx.accounts.platform_config.is_paused() {
+        return Err(OsemeGroupError::PlatformPaused.into());
+    }
+    
+    if model == GroupModel::Basic {
+        // TODO: Check global Basic group limit
+        // TODO: Check per-creator Basic group limit
+    }
+    
+    // Higher tiers require an established reputation
+    let standing = TrustStanding::load(
+        &ctx.accounts.user_trust,
+        clock.unix_timestamp,
+        &ctx.accounts.platform_config.trust_params(),
+    )?;
+    if !model_config.admits_creator(&standing) {
+        return Err(OsemeGroupError::InsufficientTrustScore.into());
+    }
+    
+    // Paid tiers need an active subscription now; it is not checked again
+    // while the group runs
+    if let Some(tier) = model.subscription_tier() {
+        let subscribed = load_subscription(&ctx.accounts.subscription)?
+            .is_some_and(|subscription| subscription.covers(tier, clock.unix_timestamp));
+        if !subscribed {
+            return Err(OsemeGroupError::SubscriptionRequired.into());
+        }
+    }
+    
+    // Validate against the model's tier parameters
+    let member_cap = member_cap.unwrap_or(model_config.member_cap);
+    if member_cap == 0 || member_cap > model_config.member_cap {
+        return Err(OsemeGroupError::GroupCapacityExceeded.into());
+    }
+    
+    let cycle_days = cycle_days.unwrap_or(model_config.default_cycle_days);
+    if cycle_days < model_config.min_cycle_days || cycle_days > model_config.max_cycle_days {
+        return Err(OsemeGroupError::InvalidCycleLength.into());
+    }
+    
+    if contribution_amount < model_config.min_contribution
+        || contribution_amount > model_config.max_contribution
+    {
+        return Err(OsemeGroupError::ContributionOutOfRange.into());
+    }
+    
+    group.cycle_days = cycle_days;
+    group.member_cap = member_cap;
+    
+    // Creator-defined payout order must fit in the group and give each
+    // wallet exactly one slot
+    let payout_order = payout_order.unwrap_or_default();
+    if payout_order.len() > group.member_cap as usize {
+        return Err(OsemeGroupError::PayoutOrderTooLong.into());
+    }
+    for (index, key) in payout_order.iter().enumerate() {
+        if *key == Pubkey::default() || payout_order[..index].contains(key) {
+            return Err(OsemeGroupError::InvalidPayoutOrder.into());
+        }
     }
     
     // Initialize group
+    group.version = Group::VERSION;
     group.group_id = clock.unix_timestamp as u64;
-    group.model = model.clone();
+    group.model = model as u8;
     group.creator = ctx.accounts.creator.key();
     group.current_turn_index = 0;
-    group.payout_order = payout_order.unwrap_or_default();
-    group.escrow_vault = ctx.accounts.escrow_vault.key();
-    group.stake_vault = None; // Set later for Trust/SuperTrust models
-    group.status = GroupStatus::Active;
+    group.payout_order[..payout_order.len()].copy_from_slice(&payout_order);
+    group.payout_order_len = payout_order.len() as u8;
+    group.escrow_vault = escrow_vault_key;
+    group.stake_vault = match model {
+        GroupModel::Basic => Pubkey::default(),
+        // Trust/SuperTrust stakes are held by the escrow vault
+        _ => escrow_vault_key,
+    };
+    group.set_status(GroupStatus::Active);
     group.total_members = 0;
-    group.current_turn_start = clock.unix_timestamp;
-    group.contribution_amount = 0; // Set when first member joins
+    group.start_turn(clock.unix_timestamp, ctx.accounts.platform_config.paused_secs(clock.unix_timestamp));
+    group.contribution_amount = contribution_amount;
     group.total_pool = 0;
     group.trust_score = 100;
     group.created_at = clock.unix_timestamp;
     group.bump = ctx.bumps.group;
+    group.admit_past_defaulters = admit_past_defaulters as u8;
+    group.default_count = 0;
+    group.promo = Pubkey::default();
+    group.paused_at = 0;
+    
+    // A promo is checked and counted now; its waiver applies at payout
+    match (&mut ctx.accounts.promo, &mut ctx.accounts.promo_redemption) {
+        (Some(promo), Some(redemption)) => {
+            if redemption.version == 0 {
+                redemption.version = PromoRedemption::VERSION;
+                redemption.promo = promo.key();
+                redemption.creator = group.creator;
+                redemption.bump = ctx.bumps.promo_redemption;
+            }
+            if redemption.version != PromoRedemption::VERSION {
+                return Err(OsemeGroupError::AccountVersionMismatch.into());
+            }
+            if !promo.is_attachable(clock.unix_timestamp) {
+                return Err(OsemeGroupError::PromoNotAttachable.into());
+            }
+            if !promo.admits_wallet(redemption) {
+                return Err(OsemeGroupError::PromoWalletLimitReached.into());
+            }
+            
+            promo.groups_attached = promo.groups_attached.saturating_add(1);
+            redemption.groups_attached = redemption.groups_attached.saturating_add(1);
+            group.promo = promo.key();
+            
+            emit!(PromoAttached {
+                promo: promo.key(),
+                group: group_key,
+                creator: group.creator,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+        (None, None) => {}
+        _ => return Err(OsemeGroupError::PromoAccountMismatch.into()),
+    }
     
     // Initialize escrow vault
-    escrow_vault.group = group.key();
-    escrow_vault.vault_authority = ctx.accounts.escrow_vault.key();
+    escrow_vault.version = EscrowVault::VERSION;
+    escrow_vault.group = group_key;
+    escrow_vault.vault_authority = escrow_vault_key;
     escrow_vault.current_balance = 0;
+    escrow_vault.stake_balance = 0;
     escrow_vault.bump = ctx.bumps.escrow_vault;
+    escrow_vault.recovery_balance = 0;
+    escrow_vault.total_shorted = 0;
+    escrow_vault.recovery_per_shorted = 0;
+    escrow_vault.outstanding_debt = 0;
+    escrow_vault.collateral_balance = 0;
+    escrow_vault.compliant_stake = 0;
+    escrow_vault.bonus_reserved = 0;
     
     // Emit event
     emit!(GroupCreated {
-        group: group.key(),
+        group: group_key,
         creator: group.creator,
-        model: match model {
-            GroupModel::Basic => 0,
-            GroupModel::Trust => 1,
-            GroupModel::SuperTrust => 2,
-        },
+        model: group.model,
         member_cap: group.member_cap,
         cycle_days: group.cycle_days,
         contribution_amount: group.contribution_amount,
@@ -125,4 +266,4 @@ pub fn create_group(
     });
     
     Ok(())
-}
\ No newline at end of file
+}
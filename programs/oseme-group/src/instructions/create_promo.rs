@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+#[instruction(code: [u8; 16])]
+pub struct CreatePromo<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = Promo::LEN,
+        seeds = [b"promo".as_ref(), code.as_ref()],
+        bump
+    )]
+    pub promo: Account<'info, Promo>,
+    
+    #[account(
+        seeds = [b"platform-config"],
+        bump = platform_config.bump,
+        has_one = authority @ OsemeGroupError::Unauthorized,
+        constraint = platform_config.version == PlatformConfig::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Creators attach a promo to a group in `create_group` until it expires;
+/// each payout of the group then waives part of the platform fee while the
+/// promo has uses left.
+pub fn create_promo(
+    ctx: Context<CreatePromo>,
+    code: [u8; 16],
+    waiver_bps: u16,
+    max_uses: u32,
+    expires_at: i64,
+    per_wallet_limit: u16,
+) -> Result<()> {
+    let promo = &mut ctx.accounts.promo;
+    let clock = Clock::get()?;
+    
+    if waiver_bps == 0 || waiver_bps > 10_000 || max_uses == 0 || expires_at <= clock.unix_timestamp {
+        return Err(OsemeGroupError::InvalidPromo.into());
+    }
+    
+    promo.version = Promo::VERSION;
+    promo.code = code;
+    promo.waiver_bps = waiver_bps;
+    promo.max_uses = max_uses;
+    promo.uses_remaining = max_uses;
+    promo.expires_at = expires_at;
+    promo.per_wallet_limit = per_wallet_limit;
+    promo.groups_attached = 0;
+    promo.total_waived = 0;
+    promo.created_at = clock.unix_timestamp;
+    promo.bump = ctx.bumps.promo;
+    
+    emit!(PromoCreated {
+        promo: promo.key(),
+        code,
+        waiver_bps,
+        max_uses,
+        expires_at,
+        per_wallet_limit,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
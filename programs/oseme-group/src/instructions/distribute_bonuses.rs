@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer, transfer};
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct DistributeBonuses<'info> {
+    #[account(
+        constraint = group.load()?.version == Group::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub group: AccountLoader<'info, Group>,
+    
+    #[account(
+        mut,
+        seeds = [b"escrow", group.key().as_ref()],
+        bump = escrow_vault.bump,
+        constraint = escrow_vault.version == EscrowVault::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub escrow_vault: Account<'info, EscrowVault>,
+    
+    #[account(
+        mut,
+        seeds = [b"escrow-token", group.key().as_ref()],
+        bump
+    )]
+    pub escrow_token_account: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+    // remaining_accounts: (member, member's USDC account) pairs, all writable
+}
+
+/// Permissionless batch payout of completion bonuses after finalization.
+/// Members already paid, or with a missed turn, are skipped.
+pub fn distribute_bonuses<'info>(ctx: Context<'_, '_, '_, 'info, DistributeBonuses<'info>>) -> Result<()> {
+    let group_key = ctx.accounts.group.key();
+    let escrow_vault = &mut ctx.accounts.escrow_vault;
+    let clock = Clock::get()?;
+    
+    if ctx.accounts.group.load()?.status()? != GroupStatus::Finalized {
+        return Err(OsemeGroupError::GroupNotClosedOut.into());
+    }
+    
+    if !ctx.remaining_accounts.len().is_multiple_of(2) {
+        return Err(OsemeGroupError::BonusAccountMismatch.into());
+    }
+    
+    let seeds = &[
+        b"escrow",
+        group_key.as_ref(),
+        &[escrow_vault.bump]
+    ];
+    let signer_seeds = &[&seeds[..]];
+    
+    let mut bonuses = Vec::new();
+    for pair in ctx.remaining_accounts.chunks(2) {
+        let (member_info, token_info) = (&pair[0], &pair[1]);
+        
+        if member_info.owner != &crate::ID || token_info.owner != &token::ID {
+            return Err(ErrorCode::AccountOwnedByWrongProgram.into());
+        }
+        let mut member = Member::try_deserialize(&mut &member_info.try_borrow_data()?[..])?;
+        if member.version != Member::VERSION {
+            return Err(OsemeGroupError::AccountVersionMismatch.into());
+        }
+        if member.group != group_key {
+            return Err(OsemeGroupError::BonusAccountMismatch.into());
+        }
+        if !member.earns_bonus() {
+            continue;
+        }
+        
+        let member_token_account = TokenAccount::try_deserialize(&mut &token_info.try_borrow_data()?[..])?;
+        if member_token_account.owner != member.user
+            || member_token_account.mint != ctx.accounts.escrow_token_account.mint
+        {
+            return Err(OsemeGroupError::BonusAccountMismatch.into());
+        }
+        
+        let amount = escrow_vault.take_bonus(member.stake_amount);
+        member.bonus_settled = true;
+        member.try_serialize(&mut &mut member_info.try_borrow_mut_data()?[..])?;
+        
+        if amount > 0 {
+            let transfer_ctx = CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.escrow_token_account.to_account_info(),
+                    to: token_info.clone(),
+                    authority: escrow_vault.to_account_info(),
+                },
+                signer_seeds
+            );
+            transfer(transfer_ctx, amount)?;
+        }
+        
+        bonuses.push(BonusPayment {
+            member: member.user,
+            amount,
+        });
+    }
+    
+    emit!(BonusesDistributed {
+        group: group_key,
+        bonuses,
+        remaining: escrow_vault.bonus_reserved,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
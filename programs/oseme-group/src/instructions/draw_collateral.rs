@@ -0,0 +1,142 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount, Transfer, transfer};
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct DrawCollateral<'info> {
+    #[account(
+        mut,
+        constraint = group.load()?.version == Group::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub group: AccountLoader<'info, Group>,
+    
+    #[account(
+        mut,
+        seeds = [b"member", group.key().as_ref(), member.user.as_ref()],
+        bump = member.bump,
+        constraint = member.version == Member::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub member: Account<'info, Member>,
+    
+    #[account(
+        mut,
+        seeds = [b"escrow", group.key().as_ref()],
+        bump = escrow_vault.bump,
+        constraint = escrow_vault.version == EscrowVault::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub escrow_vault: Account<'info, EscrowVault>,
+    
+    #[account(
+        mut,
+        seeds = [b"escrow-token", group.key().as_ref()],
+        bump
+    )]
+    pub escrow_token_account: Account<'info, TokenAccount>,
+    
+    /// Receives collateral the member can no longer owe
+    #[account(
+        mut,
+        constraint = user_token_account.mint == escrow_token_account.mint,
+        constraint = user_token_account.owner == member.user
+    )]
+    pub user_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        seeds = [b"platform-config"],
+        bump = platform_config.bump,
+        constraint = platform_config.version == PlatformConfig::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+/// Permissionless crank; the collateral already sits in the escrow token
+/// account, so drawing only moves it into the turn's pool. The deposit was
+/// sized for a full group, so whatever the fixed payout order can no longer
+/// draw is returned to the member.
+pub fn draw_collateral(ctx: Context<DrawCollateral>) -> Result<()> {
+    let group_key = ctx.accounts.group.key();
+    let mut group = ctx.accounts.group.load_mut()?;
+    let member = &mut ctx.accounts.member;
+    let escrow_vault = &mut ctx.accounts.escrow_vault;
+    let clock = Clock::get()?;
+    
+    if ctx.accounts.platform_config.is_paused() {
+        return Err(OsemeGroupError::PlatformPaused.into());
+    }
+    
+    if group.status()? != GroupStatus::Active {
+        return Err(OsemeGroupError::GroupNotActive.into());
+    }
+    
+    group.current_recipient()
+        .ok_or(OsemeGroupError::NotCurrentTurnRecipient)?;
+    
+    let turn_index = group.current_turn_index;
+    
+    if group.has_contributed(turn_index, member.payout_position) {
+        return Err(OsemeGroupError::TurnAlreadyCompleted.into());
+    }
+    
+    let amount = group.contribution_amount;
+    if !member.collateral_covers(amount) {
+        return Err(OsemeGroupError::InsufficientCollateral.into());
+    }
+    
+    member.collateral_balance -= amount;
+    escrow_vault.collateral_balance = escrow_vault.collateral_balance
+        .checked_sub(amount)
+        .ok_or(OsemeGroupError::UsdcCalculationError)?;
+    escrow_vault.current_balance = escrow_vault.current_balance
+        .checked_add(amount)
+        .ok_or(OsemeGroupError::UsdcCalculationError)?;
+    
+    group.mark_contributed(turn_index, member.payout_position);
+    member.mark_contributed(turn_index);
+    
+    // Paying into a turn fixes the payout order, so later turns are bounded by it
+    let turns_left = group.payout_order_len.saturating_sub(turn_index + 1);
+    let collateral_needed = amount
+        .checked_mul(turns_left as u64)
+        .ok_or(OsemeGroupError::UsdcCalculationError)?;
+    let refunded = member.collateral_balance.saturating_sub(collateral_needed);
+    if refunded > 0 {
+        let seeds = &[
+            b"escrow",
+            group_key.as_ref(),
+            &[escrow_vault.bump]
+        ];
+        let signer_seeds = &[&seeds[..]];
+        
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.escrow_token_account.to_account_info(),
+                to: ctx.accounts.user_token_account.to_account_info(),
+                authority: escrow_vault.to_account_info(),
+            },
+            signer_seeds
+        );
+        transfer(transfer_ctx, refunded)?;
+        
+        member.collateral_balance -= refunded;
+        escrow_vault.collateral_balance = escrow_vault.collateral_balance
+            .checked_sub(refunded)
+            .ok_or(OsemeGroupError::UsdcCalculationError)?;
+    }
+    
+    emit!(CollateralDrawn {
+        group: group_key,
+        member: member.user,
+        turn_index,
+        amount,
+        refunded,
+        remaining: member.collateral_balance,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
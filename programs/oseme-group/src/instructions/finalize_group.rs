@@ -0,0 +1,173 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use oseme_treasury::program::OsemeTreasury;
+use crate::state::*;
+use crate::treasury::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct FinalizeGroup<'info> {
+    #[account(
+        mut,
+        constraint = group.load()?.version == Group::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub group: AccountLoader<'info, Group>,
+    
+    /// Anyone may finalize a completed group, so every account below is
+    /// checked by address or seeds, here or in oseme-treasury
+    pub cranker: Signer<'info>,
+    
+    #[account(
+        seeds = [b"platform-config"],
+        bump = platform_config.bump,
+        constraint = platform_config.version == PlatformConfig::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"escrow", group.key().as_ref()],
+        bump = escrow_vault.bump,
+        constraint = escrow_vault.version == EscrowVault::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub escrow_vault: Account<'info, EscrowVault>,
+    
+    #[account(
+        mut,
+        seeds = [b"escrow-token", group.key().as_ref()],
+        bump
+    )]
+    pub escrow_token_account: Account<'info, TokenAccount>,
+    
+    /// CHECK: Creator's Subscription PDA in oseme-treasury, may not exist
+    #[account(
+        mut,
+        seeds = [SUBSCRIPTION_SEED, group.load()?.creator.as_ref()],
+        bump,
+        seeds::program = oseme_treasury::ID
+    )]
+    pub subscription: UncheckedAccount<'info>,
+    
+    /// CHECK: Creator's USDC account for the rebate, validated by oseme-treasury
+    #[account(mut)]
+    pub creator_token_account: UncheckedAccount<'info>,
+    
+    /// CHECK: Treasury PDA, deserialized by oseme-treasury
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED],
+        bump,
+        seeds::program = oseme_treasury::ID
+    )]
+    pub treasury: UncheckedAccount<'info>,
+    
+    /// CHECK: Treasury fee vault, deserialized by oseme-treasury
+    #[account(
+        mut,
+        seeds = [FEE_VAULT_SEED],
+        bump,
+        seeds::program = oseme_treasury::ID
+    )]
+    pub fee_vault: UncheckedAccount<'info>,
+    
+    /// Treasury bonus vault; its balance caps the bonuses reserved
+    #[account(
+        mut,
+        seeds = [BONUS_VAULT_SEED],
+        bump,
+        seeds::program = oseme_treasury::ID
+    )]
+    pub bonus_vault: Account<'info, TokenAccount>,
+    
+    /// CHECK: Signer PDA for treasury CPIs
+    #[account(seeds = [TREASURY_AUTHORITY_SEED], bump)]
+    pub treasury_authority: UncheckedAccount<'info>,
+    
+    pub treasury_program: Program<'info, OsemeTreasury>,
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn finalize_group(ctx: Context<FinalizeGroup>) -> Result<()> {
+    let group_key = ctx.accounts.group.key();
+    let clock = Clock::get()?;
+    
+    let (model, trust_score, default_count, total_members) = {
+        let mut group = ctx.accounts.group.load_mut()?;
+        
+        // All turns must have been paid out
+        if group.status()? != GroupStatus::Completed {
+            return Err(OsemeGroupError::GroupNotReadyForFinalization.into());
+        }
+        
+        group.set_status(GroupStatus::Finalized);
+        (group.model()?, group.trust_score, group.default_count, group.total_members)
+    };
+    
+    // Paid tiers may earn back part of the creator's subscription; groups
+    // created before subscriptions existed have none to rebate. The group
+    // is passed to the CPI, so it must not be borrowed here.
+    if model.subscription_tier().is_some() && !ctx.accounts.subscription.data_is_empty() {
+        RebateRequest {
+            treasury_program: ctx.accounts.treasury_program.to_account_info(),
+            treasury: ctx.accounts.treasury.to_account_info(),
+            fee_vault: ctx.accounts.fee_vault.to_account_info(),
+            subscription: ctx.accounts.subscription.to_account_info(),
+            creator_token_account: ctx.accounts.creator_token_account.to_account_info(),
+            group: ctx.accounts.group.to_account_info(),
+            treasury_authority: ctx.accounts.treasury_authority.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            treasury_authority_bump: ctx.bumps.treasury_authority,
+            params: ctx.accounts.platform_config.rebate_params(),
+        }.process_rebate(trust_score, default_count)?;
+    }
+    
+    // Rounding remainders are owed to nobody; the treasury takes them so the
+    // escrow can be emptied and closed
+    let escrow_vault = &mut ctx.accounts.escrow_vault;
+    let dust_swept = escrow_vault.dust(ctx.accounts.escrow_token_account.amount)?;
+    if dust_swept > 0 {
+        FeeCollection {
+            treasury_program: ctx.accounts.treasury_program.to_account_info(),
+            treasury: ctx.accounts.treasury.to_account_info(),
+            fee_vault: ctx.accounts.fee_vault.to_account_info(),
+            escrow_token_account: ctx.accounts.escrow_token_account.to_account_info(),
+            escrow_vault: escrow_vault.to_account_info(),
+            treasury_authority: ctx.accounts.treasury_authority.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            group: group_key,
+            escrow_bump: escrow_vault.bump,
+            treasury_authority_bump: ctx.bumps.treasury_authority,
+        }.collect_fees(dust_swept)?;
+    }
+    
+    // Reserve the completion bonuses of members who never missed a turn,
+    // scaled down pro rata when the pool cannot cover them all
+    let bonus_reserved = escrow_vault
+        .bonus_owed(ctx.accounts.platform_config.stake_bonus_bps)
+        .min(ctx.accounts.bonus_vault.amount);
+    if bonus_reserved > 0 {
+        BonusRelease {
+            treasury_program: ctx.accounts.treasury_program.to_account_info(),
+            treasury: ctx.accounts.treasury.to_account_info(),
+            bonus_vault: ctx.accounts.bonus_vault.to_account_info(),
+            escrow_token_account: ctx.accounts.escrow_token_account.to_account_info(),
+            treasury_authority: ctx.accounts.treasury_authority.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            treasury_authority_bump: ctx.bumps.treasury_authority,
+        }.release_bonus(bonus_reserved)?;
+    }
+    escrow_vault.bonus_reserved = bonus_reserved;
+    
+    emit!(GroupFinalized {
+        group: group_key,
+        final_trust_score: trust_score,
+        total_members,
+        bonus_reserved,
+        dust_swept,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    msg!("Group {} finalized", group_key);
+    Ok(())
+}
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct GetGroupStatus<'info> {
+    #[account(
+        constraint = group.load()?.version == Group::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub group: AccountLoader<'info, Group>,
+    
+    #[account(
+        seeds = [b"platform-config"],
+        bump = platform_config.bump,
+        constraint = platform_config.version == PlatformConfig::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+}
+
+/// Snapshot of a group's current turn, returned via return data
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct GroupStatusView {
+    pub status: GroupStatus,
+    pub model: GroupModel,
+    pub current_turn_index: u8,
+    pub total_members: u8,
+    pub paid_count: u8,                  // Members who paid into the current turn
+    pub contribution_amount: u64,
+    pub turn_start: i64,
+    pub next_deadline: i64,              // End of the regular contribution window
+    pub grace_deadline: i64,             // Late contributions accepted until here
+    pub in_grace: bool,
+    pub current_recipient: Option<Pubkey>,
+    pub next_recipient: Option<Pubkey>,
+}
+
+pub fn get_group_status(ctx: Context<GetGroupStatus>) -> Result<GroupStatusView> {
+    let group = ctx.accounts.group.load()?;
+    let grace_period_days = ctx.accounts.platform_config.grace_period_days;
+    let clock = Clock::get()?;
+    let paused_secs = ctx.accounts.platform_config.paused_secs(clock.unix_timestamp);
+    
+    let turn_open = group.current_turn_index < group.total_members;
+    
+    Ok(GroupStatusView {
+        status: group.status()?,
+        model: group.model()?,
+        current_turn_index: group.current_turn_index,
+        total_members: group.total_members,
+        paid_count: if turn_open { group.paid_count(group.current_turn_index) } else { 0 },
+        contribution_amount: group.contribution_amount,
+        turn_start: group.turn_start(paused_secs),
+        next_deadline: group.turn_deadline(paused_secs),
+        grace_deadline: group.grace_deadline(paused_secs, grace_period_days),
+        in_grace: group.in_grace(clock.unix_timestamp, paused_secs, grace_period_days),
+        current_recipient: group.current_recipient(),
+        next_recipient: group.next_recipient(),
+    })
+}
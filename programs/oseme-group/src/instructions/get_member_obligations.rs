@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct GetMemberObligations<'info> {
+    #[account(
+        constraint = group.load()?.version == Group::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub group: AccountLoader<'info, Group>,
+    
+    #[account(
+        has_one = group,
+        constraint = member.version == Member::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub member: Account<'info, Member>,
+    
+    #[account(
+        seeds = [b"platform-config"],
+        bump = platform_config.bump,
+        constraint = platform_config.version == PlatformConfig::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    #[account(
+        seeds = [b"model-config".as_ref(), &[group.load()?.model]],
+        bump = model_config.bump,
+        constraint = model_config.version == ModelConfig::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub model_config: Account<'info, ModelConfig>,
+}
+
+/// What a member owes for the current turn, returned via return data
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct MemberObligationsView {
+    pub user: Pubkey,
+    pub payout_position: u8,
+    pub has_received_payout: bool,
+    pub paid_current_turn: bool,
+    pub amount_due: u64,                 // Outstanding for the current turn
+    pub next_deadline: i64,
+    pub grace_deadline: i64,
+    pub in_grace: bool,
+    pub unpaid_turns: u8,                // Past turns without a contribution
+    pub missed_count: u8,
+    pub stake_amount: u64,
+    pub required_stake: u64,             // Stake the model requires at this point in the rotation
+    pub collateral_balance: u64,         // Full-collateral deposit left to draw from
+}
+
+pub fn get_member_obligations(ctx: Context<GetMemberObligations>) -> Result<MemberObligationsView> {
+    let group = ctx.accounts.group.load()?;
+    let member = &ctx.accounts.member;
+    let grace_period_days = ctx.accounts.platform_config.grace_period_days;
+    let clock = Clock::get()?;
+    let paused_secs = ctx.accounts.platform_config.paused_secs(clock.unix_timestamp);
+    
+    let turn_open = group.status()? == GroupStatus::Active
+        && group.current_turn_index < group.total_members;
+    let paid_current_turn = turn_open
+        && group.has_contributed(group.current_turn_index, member.payout_position);
+    
+    // Counted the way contribute does, from the first turn still to be paid
+    let full_collateral = member.collateral_covers(group.contribution_amount);
+    let required_stake = if member.is_creator || full_collateral {
+        0
+    } else {
+        let owed_after_payout = Group::owed_after_payout(
+            member.payout_position,
+            group.current_turn_index + paid_current_turn as u8,
+            group.turn_count(),
+        );
+        ctx.accounts.model_config.stake_for(group.contribution_amount, owed_after_payout)?
+    };
+    
+    Ok(MemberObligationsView {
+        user: member.user,
+        payout_position: member.payout_position,
+        has_received_payout: member.payout_position < group.current_turn_index,
+        paid_current_turn,
+        amount_due: if turn_open && !paid_current_turn && !full_collateral {
+            group.contribution_amount
+        } else {
+            0
+        },
+        next_deadline: group.turn_deadline(paused_secs),
+        grace_deadline: group.grace_deadline(paused_secs, grace_period_days),
+        in_grace: turn_open && group.in_grace(clock.unix_timestamp, paused_secs, grace_period_days),
+        unpaid_turns: member.unpaid_turns(group.current_turn_index),
+        missed_count: member.missed_count,
+        stake_amount: member.stake_amount,
+        required_stake,
+        collateral_balance: member.collateral_balance,
+    })
+}
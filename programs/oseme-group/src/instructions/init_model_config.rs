@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+#[instruction(config: ModelConfig)]
+pub struct InitModelConfig<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = ModelConfig::LEN,
+        seeds = [b"model-config".as_ref(), &[config.model as u8]],
+        bump
+    )]
+    pub model_config: Account<'info, ModelConfig>,
+    
+    #[account(
+        seeds = [b"platform-config"],
+        bump = platform_config.bump,
+        has_one = authority @ OsemeGroupError::Unauthorized,
+        constraint = platform_config.version == PlatformConfig::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn init_model_config(ctx: Context<InitModelConfig>, config: ModelConfig) -> Result<()> {
+    let model_config = &mut ctx.accounts.model_config;
+    
+    model_config.version = ModelConfig::VERSION;
+    model_config.model = config.model;
+    model_config.bump = ctx.bumps.model_config;
+    apply_model_config(model_config, &config)?;
+    
+    emit_model_config_updated(model_config, ctx.accounts.authority.key())
+}
+
+/// Copy the tunable parameters and validate the result
+pub(crate) fn apply_model_config(model_config: &mut ModelConfig, config: &ModelConfig) -> Result<()> {
+    model_config.member_cap = config.member_cap;
+    model_config.min_cycle_days = config.min_cycle_days;
+    model_config.max_cycle_days = config.max_cycle_days;
+    model_config.default_cycle_days = config.default_cycle_days;
+    model_config.min_contribution = config.min_contribution;
+    model_config.max_contribution = config.max_contribution;
+    model_config.stake_ratio_bps = config.stake_ratio_bps;
+    model_config.fee_bps = config.fee_bps;
+    model_config.creator_share_bps = config.creator_share_bps;
+    model_config.min_member_score = config.min_member_score;
+    model_config.min_creator_score = config.min_creator_score;
+    model_config.min_creator_groups_completed = config.min_creator_groups_completed;
+    model_config.exposure_stake_bps = config.exposure_stake_bps;
+    
+    model_config.validate()
+}
+
+pub(crate) fn emit_model_config_updated(model_config: &ModelConfig, authority: Pubkey) -> Result<()> {
+    emit!(ModelConfigUpdated {
+        model: model_config.model as u8,
+        authority,
+        member_cap: model_config.member_cap,
+        fee_bps: model_config.fee_bps,
+        creator_share_bps: model_config.creator_share_bps,
+        stake_ratio_bps: model_config.stake_ratio_bps,
+        exposure_stake_bps: model_config.exposure_stake_bps,
+        min_member_score: model_config.min_member_score,
+        min_creator_score: model_config.min_creator_score,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    Ok(())
+}
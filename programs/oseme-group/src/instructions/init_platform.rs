@@ -21,6 +21,7 @@ pub struct InitPlatform<'info> {
 pub fn init_platform(ctx: Context<InitPlatform>, config: PlatformConfig) -> Result<()> {
     let platform_config = &mut ctx.accounts.platform_config;
     
+    platform_config.version = PlatformConfig::VERSION;
     platform_config.authority = ctx.accounts.authority.key();
     platform_config.fee_bps = config.fee_bps;
     platform_config.trust_subscription_price = config.trust_subscription_price;
@@ -30,11 +31,19 @@ pub fn init_platform(ctx: Context<InitPlatform>, config: PlatformConfig) -> Resu
     platform_config.grace_period_days = config.grace_period_days;
     platform_config.trust_penalty = config.trust_penalty;
     platform_config.trust_bonus = config.trust_bonus;
+    platform_config.trust_decay_period_days = config.trust_decay_period_days;
+    platform_config.trust_decay_step = config.trust_decay_step;
+    platform_config.trust_recovery_groups = config.trust_recovery_groups;
+    platform_config.vouch_bonus = config.vouch_bonus;
+    platform_config.rebate_min_trust_score = config.rebate_min_trust_score;
+    platform_config.rebate_bps = config.rebate_bps;
     platform_config.stake_bonus_bps = config.stake_bonus_bps;
     platform_config.kyc_threshold = config.kyc_threshold;
     platform_config.bonus_pool = config.bonus_pool;
     platform_config.usdc_mint = config.usdc_mint;
     platform_config.bump = ctx.bumps.platform_config;
+    platform_config.paused_at = 0;
+    platform_config.total_paused_secs = 0;
     
     Ok(())
 }
\ No newline at end of file
@@ -0,0 +1,254 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount, Transfer, transfer};
+use crate::state::*;
+use crate::trust::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct JoinGroup<'info> {
+    #[account(
+        mut,
+        constraint = group.load()?.version == Group::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub group: AccountLoader<'info, Group>,
+
+    #[account(
+        init,
+        payer = user,
+        space = Member::LEN,
+        seeds = [b"member", group.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub member: Account<'info, Member>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", group.key().as_ref()],
+        bump = escrow_vault.bump,
+        constraint = escrow_vault.version == EscrowVault::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub escrow_vault: Account<'info, EscrowVault>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow-token", group.key().as_ref()],
+        bump
+    )]
+    pub escrow_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = user_token_account.mint == platform_config.usdc_mint,
+        constraint = user_token_account.owner == user.key()
+    )]
+    pub user_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [b"platform-config"],
+        bump = platform_config.bump,
+        constraint = platform_config.version == PlatformConfig::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        seeds = [b"model-config".as_ref(), &[group.load()?.model]],
+        bump = model_config.bump,
+        constraint = model_config.version == ModelConfig::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub model_config: Account<'info, ModelConfig>,
+
+    /// CHECK: Joining wallet's UserTrust PDA in oseme-trust, may not exist yet
+    #[account(
+        seeds = [USER_TRUST_SEED, user.key().as_ref()],
+        bump,
+        seeds::program = oseme_trust::ID
+    )]
+    pub user_trust: UncheckedAccount<'info>,
+
+    /// CHECK: Joining wallet's DefaulterRecord, may not exist
+    #[account(
+        seeds = [b"defaulter", user.key().as_ref()],
+        bump
+    )]
+    pub defaulter_record: UncheckedAccount<'info>,
+
+    /// CHECK: Vouch for the joining wallet, may not exist
+    #[account(
+        mut,
+        seeds = [b"vouch", group.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub vouch: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+}
+
+/// A full-collateral member deposits every remaining contribution up front
+/// instead of staking; each turn is then drawn from the deposit
+pub fn join_group(ctx: Context<JoinGroup>, full_collateral: bool) -> Result<()> {
+    let group_key = ctx.accounts.group.key();
+    let mut group = ctx.accounts.group.load_mut()?;
+    let member = &mut ctx.accounts.member;
+    let escrow_vault = &mut ctx.accounts.escrow_vault;
+    let user_key = ctx.accounts.user.key();
+    let clock = Clock::get()?;
+
+    if ctx.accounts.platform_config.is_paused() {
+        return Err(OsemeGroupError::PlatformPaused.into());
+    }
+
+    // Verify group is active
+    if group.status()? != GroupStatus::Active {
+        return Err(OsemeGroupError::GroupNotActive.into());
+    }
+
+    if group.total_members >= group.member_cap {
+        return Err(OsemeGroupError::GroupCapacityExceeded.into());
+    }
+
+    // Members listed in a creator-defined payout order take their slot,
+    // everyone else is appended while the order is still open
+    let payout_position = match group.payout_position(&user_key) {
+        Some(position) => position,
+        None => {
+            if group.payout_order_fixed() {
+                return Err(OsemeGroupError::PayoutOrderImmutable.into());
+            }
+            if group.payout_order_len >= group.member_cap {
+                return Err(OsemeGroupError::GroupCapacityExceeded.into());
+            }
+            let position = group.payout_order_len;
+            group.payout_order[position as usize] = user_key;
+            group.payout_order_len += 1;
+            position
+        }
+    };
+
+    // Open debts anywhere block joining; resolved defaults are up to the creator
+    if let Some(record) = DefaulterRecord::read(&ctx.accounts.defaulter_record)? {
+        if record.has_unresolved_debt() {
+            return Err(OsemeGroupError::UnresolvedDebt.into());
+        }
+        if record.defaults > 0 && !group.admits_past_defaulters() {
+            return Err(OsemeGroupError::PastDefaultsNotAdmitted.into());
+        }
+    }
+    
+    let standing = TrustStanding::load(
+        &ctx.accounts.user_trust,
+        clock.unix_timestamp,
+        &ctx.accounts.platform_config.trust_params(),
+    )?;
+    // Stake the model asks of this position, whether or not it is paid
+    let owed_after_payout = Group::owed_after_payout(
+        payout_position,
+        group.current_turn_index,
+        group.turn_count(),
+    );
+    let position_stake = ctx.accounts.model_config.stake_for(group.contribution_amount, owed_after_payout)?;
+
+    // A vouch from an existing member stands in for missing reputation,
+    // provided it locks at least the stake the newcomer would owe
+    let mut vouch = Vouch::read(&ctx.accounts.vouch)?;
+    if !ctx.accounts.model_config.admits_member(&standing) {
+        match &vouch {
+            None => return Err(OsemeGroupError::InsufficientTrustScore.into()),
+            Some(vouch) if !vouch.covers(position_stake) => {
+                return Err(OsemeGroupError::VouchTooSmall.into());
+            }
+            Some(_) => {}
+        }
+    }
+    if let Some(vouch) = vouch.as_mut() {
+        vouch.joined_at = clock.unix_timestamp;
+        vouch.write(&ctx.accounts.vouch)?;
+    }
+
+    let is_creator = user_key == group.creator;
+
+    let collateral_amount = if full_collateral {
+        if group.model()? == GroupModel::Basic {
+            return Err(OsemeGroupError::CollateralNotAvailable.into());
+        }
+        let remaining_turns = group.turn_count().saturating_sub(group.current_turn_index);
+        group.contribution_amount
+            .checked_mul(remaining_turns as u64)
+            .ok_or(OsemeGroupError::UsdcCalculationError)?
+    } else {
+        0
+    };
+
+    // Members stake per the model's formula for their position; the creator
+    // and full-collateral members are exempt
+    let stake_amount = if is_creator || full_collateral {
+        0
+    } else {
+        position_stake
+    };
+
+    let deposit = stake_amount
+        .checked_add(collateral_amount)
+        .ok_or(OsemeGroupError::UsdcCalculationError)?;
+    if deposit > 0 {
+        let transfer_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.user_token_account.to_account_info(),
+                to: ctx.accounts.escrow_token_account.to_account_info(),
+                authority: ctx.accounts.user.to_account_info(),
+            }
+        );
+        transfer(transfer_ctx, deposit)?;
+
+        escrow_vault.stake_balance = escrow_vault.stake_balance
+            .checked_add(stake_amount)
+            .ok_or(OsemeGroupError::UsdcCalculationError)?;
+        escrow_vault.compliant_stake = escrow_vault.compliant_stake
+            .checked_add(stake_amount)
+            .ok_or(OsemeGroupError::UsdcCalculationError)?;
+        escrow_vault.collateral_balance = escrow_vault.collateral_balance
+            .checked_add(collateral_amount)
+            .ok_or(OsemeGroupError::UsdcCalculationError)?;
+    }
+
+    // Initialize member
+    member.version = Member::VERSION;
+    member.group = group_key;
+    member.user = user_key;
+    member.stake_amount = stake_amount;
+    member.contributed_turns = [0; Group::BITMAP_BYTES];
+    member.payout_position = payout_position;
+    member.missed_count = 0;
+    member.trust_delta = 0;
+    member.join_timestamp = clock.unix_timestamp;
+    member.is_creator = is_creator;
+    member.bump = ctx.bumps.member;
+    member.trust_settled_at = 0;
+    member.shorted_amount = 0;
+    member.recovery_checkpoint = 0;
+    member.recovery_claimable = 0;
+    member.collateral_balance = collateral_amount;
+    member.bonus_settled = false;
+
+    group.total_members += 1;
+    group.total_pool = group.contribution_amount
+        .checked_mul(group.total_members as u64)
+        .ok_or(OsemeGroupError::UsdcCalculationError)?;
+
+    emit!(MemberJoined {
+        group: group_key,
+        member: user_key,
+        stake_amount,
+        voucher: vouch.as_ref().map(|vouch| vouch.voucher),
+        vouched_amount: vouch.as_ref().map_or(0, |vouch| vouch.amount),
+        collateral_amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
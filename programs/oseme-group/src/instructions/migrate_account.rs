@@ -0,0 +1,296 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_lang::Discriminator;
+use crate::state::*;
+use crate::legacy::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct MigrateAccount<'info> {
+    /// CHECK: Layout is detected from the discriminator and allocated size
+    #[account(mut, owner = crate::ID)]
+    pub account: UncheckedAccount<'info>,
+
+    /// Group of the member being migrated; must already be on the current layout
+    #[account(mut)]
+    pub group: Option<AccountLoader<'info, Group>>,
+
+    /// Required to migrate a group; must already be on the current layout
+    #[account(
+        seeds = [b"platform-config"],
+        bump = platform_config.bump,
+        constraint = platform_config.version == PlatformConfig::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub platform_config: Option<Account<'info, PlatformConfig>>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Rewrite an older account layout in place as the current one.
+/// Migrate a group before any of its members.
+pub fn migrate_account(ctx: Context<MigrateAccount>) -> Result<()> {
+    let account = ctx.accounts.account.to_account_info();
+    let clock = Clock::get()?;
+
+    let discriminator: [u8; 8] = account.try_borrow_data()?
+        .get(..8)
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or(OsemeGroupError::UnsupportedAccountLayout)?;
+
+    let (from_version, to_version, new_len) = if discriminator == PlatformConfig::DISCRIMINATOR {
+        (migrate_platform_config(&account)?, PlatformConfig::VERSION, PlatformConfig::LEN)
+    } else if discriminator == Group::DISCRIMINATOR {
+        let platform_config = ctx.accounts.platform_config.as_ref()
+            .ok_or(OsemeGroupError::PlatformConfigRequired)?;
+        let platform_paused_secs = platform_config.paused_secs(clock.unix_timestamp);
+        (migrate_group(&account, platform_paused_secs)?, Group::VERSION, Group::LEN)
+    } else if discriminator == Member::DISCRIMINATOR {
+        let group = ctx.accounts.group.as_ref()
+            .ok_or(OsemeGroupError::MemberNotFound)?;
+        (migrate_member(&account, group)?, Member::VERSION, Member::LEN)
+    } else if discriminator == ModelConfig::DISCRIMINATOR {
+        (migrate_model_config(&account)?, ModelConfig::VERSION, ModelConfig::LEN)
+    } else if discriminator == EscrowVault::DISCRIMINATOR {
+        (migrate_escrow_vault(&account)?, EscrowVault::VERSION, EscrowVault::LEN)
+    } else {
+        return Err(OsemeGroupError::UnsupportedAccountLayout.into());
+    };
+
+    // Keep the resized account rent exempt
+    let rent_exempt = Rent::get()?.minimum_balance(new_len);
+    let shortfall = rent_exempt.saturating_sub(account.lamports());
+    if shortfall > 0 {
+        let transfer_ctx = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.payer.to_account_info(),
+                to: account.clone(),
+            }
+        );
+        transfer(transfer_ctx, shortfall)?;
+    }
+
+    emit!(AccountMigrated {
+        account: account.key(),
+        from_version,
+        to_version,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Version stored in a versioned account, or 0 for the unversioned layout
+fn stored_version(account: &AccountInfo, legacy_len: usize, current: u8) -> Result<u8> {
+    let data = account.try_borrow_data()?;
+    let version = if data.len() == legacy_len { 0 } else { data[8] };
+
+    if version == current {
+        return Err(OsemeGroupError::AccountAlreadyMigrated.into());
+    }
+    if version > current {
+        return Err(OsemeGroupError::UnsupportedAccountLayout.into());
+    }
+    Ok(version)
+}
+
+/// Layouts after v1 only append zero-defaulted fields
+fn append_fields(account: &AccountInfo, len: usize, version: u8) -> Result<()> {
+    account.realloc(len, true)?;
+    account.try_borrow_mut_data()?[8] = version;
+    Ok(())
+}
+
+fn write_account<T: AccountSerialize>(account: &AccountInfo, value: &T, len: usize) -> Result<()> {
+    account.realloc(len, true)?;
+    let mut data = account.try_borrow_mut_data()?;
+    let mut writer: &mut [u8] = &mut data;
+    value.try_serialize(&mut writer)
+}
+
+fn migrate_platform_config(account: &AccountInfo) -> Result<u8> {
+    let from_version = stored_version(account, PlatformConfigV0::LEN, PlatformConfig::VERSION)?;
+    if from_version > 0 {
+        append_fields(account, PlatformConfig::LEN, PlatformConfig::VERSION)?;
+        return Ok(from_version);
+    }
+
+    let old = PlatformConfigV0::deserialize(&mut &account.try_borrow_data()?[8..])?;
+    let config = PlatformConfig {
+        version: PlatformConfig::VERSION,
+        authority: old.authority,
+        fee_bps: old.fee_bps,
+        trust_subscription_price: old.trust_subscription_price,
+        super_trust_subscription_price: old.super_trust_subscription_price,
+        basic_group_limit: old.basic_group_limit,
+        basic_per_creator_limit: old.basic_per_creator_limit,
+        grace_period_days: old.grace_period_days,
+        trust_penalty: old.trust_penalty,
+        trust_bonus: old.trust_bonus,
+        stake_bonus_bps: old.stake_bonus_bps,
+        kyc_threshold: old.kyc_threshold,
+        bonus_pool: old.bonus_pool,
+        usdc_mint: old.usdc_mint,
+        bump: old.bump,
+        // Decay and recovery stay off until the authority configures them
+        trust_decay_period_days: 0,
+        trust_decay_step: 0,
+        trust_recovery_groups: 0,
+        vouch_bonus: 0,
+        rebate_min_trust_score: 0,
+        rebate_bps: 0,
+        paused_at: 0,
+        total_paused_secs: 0,
+    };
+    write_account(account, &config, PlatformConfig::LEN)?;
+
+    Ok(from_version)
+}
+
+/// Platform pauses before the migration must not extend the open turn, so
+/// layouts without a pause offset start from the current paused time
+fn migrate_group(account: &AccountInfo, platform_paused_secs: i64) -> Result<u8> {
+    let from_version = stored_version(account, GroupV0::LEN, Group::VERSION)?;
+    if from_version > 0 {
+        append_fields(account, Group::LEN, Group::VERSION)?;
+        if from_version < Group::PAUSE_OFFSET_VERSION {
+            let mut data = account.try_borrow_mut_data()?;
+            let group: &mut Group = bytemuck::from_bytes_mut(&mut data[8..Group::LEN]);
+            group.pause_offset = platform_paused_secs;
+        }
+        return Ok(from_version);
+    }
+
+    let old = GroupV0::deserialize(&mut &account.try_borrow_data()?[8..])?;
+    if old.payout_order.len() > Group::MAX_MEMBERS {
+        return Err(OsemeGroupError::PayoutOrderTooLong.into());
+    }
+
+    // Zero the body and fill the zero-copy layout in place; the account is
+    // too large to build on the stack. Turn bitmaps are rebuilt as each
+    // member is migrated.
+    account.realloc(Group::LEN, false)?;
+    let mut data = account.try_borrow_mut_data()?;
+    data[8..].fill(0);
+    let group: &mut Group = bytemuck::from_bytes_mut(&mut data[8..Group::LEN]);
+
+    group.version = Group::VERSION;
+    group.model = old.model as u8;
+    group.status = old.status as u8;
+    group.member_cap = old.member_cap;
+    group.current_turn_index = old.current_turn_index;
+    group.total_members = old.total_members;
+    group.payout_order_len = old.payout_order.len() as u8;
+    group.trust_score = old.trust_score;
+    group.group_id = old.group_id;
+    group.current_turn_start = old.current_turn_start;
+    group.contribution_amount = old.contribution_amount;
+    group.total_pool = old.total_pool;
+    group.created_at = old.created_at;
+    group.creator = old.creator;
+    group.escrow_vault = old.escrow_vault;
+    group.stake_vault = old.stake_vault.unwrap_or_default();
+    group.payout_order[..old.payout_order.len()].copy_from_slice(&old.payout_order);
+    group.cycle_days = old.cycle_days;
+    group.bump = old.bump;
+    group.pause_offset = platform_paused_secs;
+
+    Ok(from_version)
+}
+
+fn migrate_member(account: &AccountInfo, group_loader: &AccountLoader<Group>) -> Result<u8> {
+    let from_version = stored_version(account, MemberV0::LEN, Member::VERSION)?;
+    if from_version > 0 {
+        append_fields(account, Member::LEN, Member::VERSION)?;
+        return Ok(from_version);
+    }
+
+    let old = MemberV0::deserialize(&mut &account.try_borrow_data()?[8..])?;
+    if old.group != group_loader.key() {
+        return Err(OsemeGroupError::MemberNotFound.into());
+    }
+
+    let mut group = group_loader.load_mut()?;
+    if group.version != Group::VERSION {
+        return Err(OsemeGroupError::AccountVersionMismatch.into());
+    }
+    let payout_position = group.payout_position(&old.user)
+        .ok_or(OsemeGroupError::MemberNotFound)?;
+
+    let mut member = Member {
+        version: Member::VERSION,
+        group: old.group,
+        user: old.user,
+        stake_amount: old.stake_amount,
+        contributed_turns: [0; Group::BITMAP_BYTES],
+        payout_position,
+        missed_count: old.missed_count,
+        trust_delta: old.trust_delta,
+        join_timestamp: old.join_timestamp,
+        is_creator: old.is_creator,
+        bump: old.bump,
+        trust_settled_at: 0,
+        shorted_amount: 0,
+        recovery_checkpoint: 0,
+        recovery_claimable: 0,
+        collateral_balance: 0,
+        bonus_settled: false,
+    };
+
+    // Carry contributions over into both bitmaps
+    for (turn_index, contributed) in old.contributed_turns.iter().enumerate().take(Group::MAX_MEMBERS) {
+        if *contributed {
+            member.mark_contributed(turn_index as u8);
+            group.mark_contributed(turn_index as u8, payout_position);
+        }
+    }
+    write_account(account, &member, Member::LEN)?;
+
+    Ok(from_version)
+}
+
+/// Model configs were created with a version byte, so only appends apply
+fn migrate_model_config(account: &AccountInfo) -> Result<u8> {
+    let from_version = account.try_borrow_data()?[8];
+    if from_version == ModelConfig::VERSION {
+        return Err(OsemeGroupError::AccountAlreadyMigrated.into());
+    }
+    if from_version == 0 || from_version > ModelConfig::VERSION {
+        return Err(OsemeGroupError::UnsupportedAccountLayout.into());
+    }
+    append_fields(account, ModelConfig::LEN, ModelConfig::VERSION)?;
+
+    Ok(from_version)
+}
+
+fn migrate_escrow_vault(account: &AccountInfo) -> Result<u8> {
+    let from_version = stored_version(account, EscrowVaultV0::LEN, EscrowVault::VERSION)?;
+    if from_version > 0 {
+        append_fields(account, EscrowVault::LEN, EscrowVault::VERSION)?;
+        return Ok(from_version);
+    }
+
+    let old = EscrowVaultV0::deserialize(&mut &account.try_borrow_data()?[8..])?;
+    let escrow_vault = EscrowVault {
+        version: EscrowVault::VERSION,
+        group: old.group,
+        vault_authority: old.vault_authority,
+        current_balance: old.current_balance,
+        stake_balance: 0, // Stakes were not tracked by the escrow before v1
+        bump: old.bump,
+        recovery_balance: 0,
+        total_shorted: 0,
+        recovery_per_shorted: 0,
+        outstanding_debt: 0,
+        collateral_balance: 0,
+        compliant_stake: 0,
+        bonus_reserved: 0,
+    };
+    write_account(account, &escrow_vault, EscrowVault::LEN)?;
+
+    Ok(from_version)
+}
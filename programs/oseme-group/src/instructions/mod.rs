@@ -1,17 +1,67 @@
 pub mod init_platform;
+pub mod init_model_config;
+pub mod update_model_config;
+pub mod set_fee_schedule;
+pub mod create_promo;
 pub mod create_group;
 pub mod join_group;
 pub mod contribute;
 pub mod release_payout;
 pub mod finalize_group;
+pub mod request_pause;
 pub mod pause_group;
 pub mod resume_group;
+pub mod pause_platform;
+pub mod resume_platform;
+pub mod migrate_account;
+pub mod withdraw_stake;
+pub mod close_member;
+pub mod close_group;
+pub mod get_group_status;
+pub mod get_member_obligations;
+pub mod slash_member;
+pub mod settle_member;
+pub mod vouch_for_member;
+pub mod release_vouch;
+pub mod add_guarantor;
+pub mod revoke_guarantor;
+pub mod repay_debt;
+pub mod claim_recovery;
+pub mod write_off_debt;
+pub mod draw_collateral;
+pub mod distribute_bonuses;
+pub mod reconcile_escrow;
 
 pub use init_platform::*;
+pub use init_model_config::*;
+pub use update_model_config::*;
+pub use set_fee_schedule::*;
+pub use create_promo::*;
 pub use create_group::*;
 pub use join_group::*;
 pub use contribute::*;
 pub use release_payout::*;
 pub use finalize_group::*;
+pub use request_pause::*;
 pub use pause_group::*;
-pub use resume_group::*;
\ No newline at end of file
+pub use resume_group::*;
+pub use pause_platform::*;
+pub use resume_platform::*;
+pub use migrate_account::*;
+pub use withdraw_stake::*;
+pub use close_member::*;
+pub use close_group::*;
+pub use get_group_status::*;
+pub use get_member_obligations::*;
+pub use slash_member::*;
+pub use settle_member::*;
+pub use vouch_for_member::*;
+pub use release_vouch::*;
+pub use add_guarantor::*;
+pub use revoke_guarantor::*;
+pub use repay_debt::*;
+pub use claim_recovery::*;
+pub use write_off_debt::*;
+pub use draw_collateral::*;
+pub use distribute_bonuses::*;
+pub use reconcile_escrow::*;
\ No newline at end of file
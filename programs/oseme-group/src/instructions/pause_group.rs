@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct PauseGroup<'info> {
+    #[account(
+        mut,
+        constraint = group.load()?.version == Group::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub group: AccountLoader<'info, Group>,
+    
+    /// Creator's request being confirmed, if any; closed to the creator
+    #[account(
+        mut,
+        seeds = [b"pause-request", group.key().as_ref()],
+        bump = pause_request.bump,
+        close = creator
+    )]
+    pub pause_request: Option<Account<'info, PauseRequest>>,
+    
+    /// CHECK: Group creator, receives the pause request's rent
+    #[account(mut, address = group.load()?.creator)]
+    pub creator: UncheckedAccount<'info>,
+    
+    #[account(
+        seeds = [b"platform-config"],
+        bump = platform_config.bump,
+        has_one = authority @ OsemeGroupError::Unauthorized,
+        constraint = platform_config.version == PlatformConfig::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    pub authority: Signer<'info>,
+}
+
+/// Stop contributions, payouts and slashing in one group. The time spent
+/// paused is added to the turn's deadlines by `resume_group`.
+pub fn pause_group(ctx: Context<PauseGroup>, reason: PauseReason) -> Result<()> {
+    let group_key = ctx.accounts.group.key();
+    let mut group = ctx.accounts.group.load_mut()?;
+    let clock = Clock::get()?;
+    
+    if group.status()? != GroupStatus::Active {
+        return Err(OsemeGroupError::GroupNotActive.into());
+    }
+    
+    let requested_by = match &ctx.accounts.pause_request {
+        Some(request) if request.group != group_key => {
+            return Err(OsemeGroupError::PauseRequestMismatch.into());
+        }
+        Some(request) => Some(request.creator),
+        None => None,
+    };
+    
+    let platform_paused_secs = ctx.accounts.platform_config.paused_secs(clock.unix_timestamp);
+    group.pause(clock.unix_timestamp, reason, platform_paused_secs);
+    
+    emit!(GroupPaused {
+        group: group_key,
+        admin: ctx.accounts.authority.key(),
+        reason: reason as u8,
+        requested_by,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
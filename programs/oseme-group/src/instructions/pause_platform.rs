@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct PausePlatform<'info> {
+    #[account(
+        mut,
+        seeds = [b"platform-config"],
+        bump = platform_config.bump,
+        has_one = authority @ OsemeGroupError::Unauthorized,
+        constraint = platform_config.version == PlatformConfig::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    pub authority: Signer<'info>,
+}
+
+/// Emergency stop for every group: creating, joining, contributing, drawing
+/// collateral and releasing payouts fail until `resume_platform`. Withdrawals,
+/// refunds and closing stay open, and turn deadlines run on once the pause ends.
+pub fn pause_platform(ctx: Context<PausePlatform>) -> Result<()> {
+    let platform_config = &mut ctx.accounts.platform_config;
+    let clock = Clock::get()?;
+    
+    if platform_config.is_paused() {
+        return Err(OsemeGroupError::PlatformPaused.into());
+    }
+    platform_config.paused_at = clock.unix_timestamp;
+    
+    emit!(PlatformPaused {
+        authority: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
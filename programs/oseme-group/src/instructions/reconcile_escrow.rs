@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct ReconcileEscrow<'info> {
+    #[account(
+        mut,
+        constraint = group.load()?.version == Group::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub group: AccountLoader<'info, Group>,
+    
+    #[account(
+        seeds = [b"escrow", group.key().as_ref()],
+        bump = escrow_vault.bump,
+        constraint = escrow_vault.version == EscrowVault::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub escrow_vault: Account<'info, EscrowVault>,
+    
+    #[account(
+        seeds = [b"escrow-token", group.key().as_ref()],
+        bump
+    )]
+    pub escrow_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        seeds = [b"platform-config"],
+        bump = platform_config.bump,
+        constraint = platform_config.version == PlatformConfig::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+}
+
+/// Permissionless check that the escrow token account holds everything the
+/// vault's balances say it owes, and that the open turn's recorded
+/// contributions are in the turn's pool. A shortfall pauses an active group;
+/// a surplus is only reported.
+pub fn reconcile_escrow(ctx: Context<ReconcileEscrow>) -> Result<()> {
+    let group_key = ctx.accounts.group.key();
+    let mut group = ctx.accounts.group.load_mut()?;
+    let escrow_vault = &ctx.accounts.escrow_vault;
+    let clock = Clock::get()?;
+    
+    let token_balance = ctx.accounts.escrow_token_account.amount;
+    let tracked_balance = escrow_vault.tracked_balance()?;
+    let open_turn_pool = group.open_turn_pool()?.unwrap_or(0);
+    
+    let shortfall = tracked_balance
+        .saturating_sub(token_balance)
+        .max(open_turn_pool.saturating_sub(escrow_vault.current_balance));
+    let surplus = token_balance.saturating_sub(tracked_balance);
+    
+    if shortfall == 0 && surplus == 0 {
+        msg!("Escrow of {} reconciled: {} USDC", group_key, token_balance);
+        return Ok(());
+    }
+    
+    let group_paused = shortfall > 0 && group.status()? == GroupStatus::Active;
+    if group_paused {
+        let platform_paused_secs = ctx.accounts.platform_config.paused_secs(clock.unix_timestamp);
+        group.pause(clock.unix_timestamp, PauseReason::EscrowDiscrepancy, platform_paused_secs);
+    }
+    
+    emit!(EscrowDiscrepancy {
+        group: group_key,
+        token_balance,
+        tracked_balance,
+        current_balance: escrow_vault.current_balance,
+        open_turn_pool,
+        shortfall,
+        surplus,
+        group_paused,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
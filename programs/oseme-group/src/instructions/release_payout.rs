@@ -1,18 +1,25 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Token, TokenAccount, Transfer, transfer};
+use anchor_spl::token::{Mint, Token, TokenAccount, Transfer, transfer};
+use oseme_math::{FeeSplit, UsdcAmount};
+use oseme_treasury::program::OsemeTreasury;
 use crate::state::*;
+use crate::treasury::*;
 use crate::errors::*;
 use crate::events::*;
 
 #[derive(Accounts)]
 pub struct ReleasePayout<'info> {
-    #[account(mut)]
-    pub group: Account<'info, Group>,
+    #[account(
+        mut,
+        constraint = group.load()?.version == Group::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub group: AccountLoader<'info, Group>,
     
     #[account(
         mut,
         seeds = [b"escrow", group.key().as_ref()],
-        bump = escrow_vault.bump
+        bump = escrow_vault.bump,
+        constraint = escrow_vault.version == EscrowVault::VERSION @ OsemeGroupError::AccountVersionMismatch
     )]
     pub escrow_vault: Account<'info, EscrowVault>,
     
@@ -33,23 +40,107 @@ pub struct ReleasePayout<'info> {
     /// CHECK: Recipient pubkey is validated against payout order
     pub recipient: AccountInfo<'info>,
     
+    #[account(
+        mut,
+        seeds = [b"member", group.key().as_ref(), recipient.key().as_ref()],
+        bump = recipient_member.bump,
+        constraint = recipient_member.version == Member::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub recipient_member: Account<'info, Member>,
+    
     #[account(
         seeds = [b"platform-config"],
-        bump = platform_config.bump
+        bump = platform_config.bump,
+        constraint = platform_config.version == PlatformConfig::VERSION @ OsemeGroupError::AccountVersionMismatch
     )]
     pub platform_config: Account<'info, PlatformConfig>,
     
+    #[account(
+        seeds = [b"model-config".as_ref(), &[group.load()?.model]],
+        bump = model_config.bump,
+        constraint = model_config.version == ModelConfig::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub model_config: Account<'info, ModelConfig>,
+    
+    /// Promo attached to the group; required when there is one
+    #[account(
+        mut,
+        address = group.load()?.promo @ OsemeGroupError::PromoAccountMismatch
+    )]
+    pub promo: Option<Account<'info, Promo>>,
+    
+    #[account(
+        mut,
+        seeds = [b"promo-redemption", group.load()?.promo.as_ref(), group.load()?.creator.as_ref()],
+        bump = promo_redemption.bump
+    )]
+    pub promo_redemption: Option<Account<'info, PromoRedemption>>,
+    
+    /// CHECK: Model's FeeSchedule PDA, may not exist
+    #[account(
+        seeds = [b"fee-schedule".as_ref(), &[group.load()?.model]],
+        bump
+    )]
+    pub fee_schedule: UncheckedAccount<'info>,
+    
+    /// CHECK: Treasury PDA, validated by oseme-treasury
+    #[account(mut)]
+    pub treasury: UncheckedAccount<'info>,
+    
+    /// CHECK: Treasury fee vault, validated by oseme-treasury
+    #[account(mut)]
+    pub fee_vault: UncheckedAccount<'info>,
+    
+    /// CHECK: Group creator, receives the creator share of the fee
+    #[account(address = group.load()?.creator)]
+    pub creator: UncheckedAccount<'info>,
+    
+    #[account(
+        init_if_needed,
+        payer = cranker,
+        space = CreatorVolume::LEN,
+        seeds = [b"creator-volume", creator.key().as_ref()],
+        bump
+    )]
+    pub creator_volume: Account<'info, CreatorVolume>,
+    
+    /// CHECK: Creator's CreatorRevenue PDA, validated and created by oseme-treasury
+    #[account(mut)]
+    pub creator_revenue: UncheckedAccount<'info>,
+    
+    /// CHECK: Treasury creator vault, validated and created by oseme-treasury
+    #[account(mut)]
+    pub creator_vault: UncheckedAccount<'info>,
+    
+    #[account(address = platform_config.usdc_mint)]
+    pub usdc_mint: Account<'info, Mint>,
+    
+    /// CHECK: Signer PDA for fee collection
+    #[account(seeds = [TREASURY_AUTHORITY_SEED], bump)]
+    pub treasury_authority: UncheckedAccount<'info>,
+    
+    /// Pays for the creator's volume and revenue accounts on their first payout
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+    
+    pub treasury_program: Program<'info, OsemeTreasury>,
     pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<ReleasePayout>, turn_index: u8) -> Result<()> {
-    let group = &mut ctx.accounts.group;
+pub fn release_payout(ctx: Context<ReleasePayout>, turn_index: u8) -> Result<()> {
+    let group_key = ctx.accounts.group.key();
+    let mut group = ctx.accounts.group.load_mut()?;
     let escrow_vault = &mut ctx.accounts.escrow_vault;
-    let platform_config = &ctx.accounts.platform_config;
+    let model_config = &ctx.accounts.model_config;
     let clock = Clock::get()?;
     
+    if ctx.accounts.platform_config.is_paused() {
+        return Err(OsemeGroupError::PlatformPaused.into());
+    }
+    
     // Verify group is active
-    if group.status != GroupStatus::Active {
+    if group.status()? != GroupStatus::Active {
         return Err(OsemeGroupError::GroupNotActive.into());
     }
     
@@ -59,51 +150,89 @@ pub fn handler(ctx: Context<ReleasePayout>, turn_index: u8) -> Result<()> {
     }
     
     // Verify recipient is correct for this turn
-    let expected_recipient = group.payout_order.get(turn_index as usize)
+    let expected_recipient = group.current_recipient()
         .ok_or(OsemeGroupError::NotCurrentTurnRecipient)?;
     
-    if ctx.accounts.recipient.key() != *expected_recipient {
+    if ctx.accounts.recipient.key() != expected_recipient {
         return Err(OsemeGroupError::NotCurrentTurnRecipient.into());
     }
     
-    // Verify sufficient contributions received
+    // Verify sufficient contributions received; slashes not covered by stake
+    // reduce what the recipient can be paid
     let required_amount = group.contribution_amount
         .checked_mul(group.total_members as u64)
+        .and_then(|amount| amount.checked_sub(group.turn_shortfall))
         .ok_or(OsemeGroupError::UsdcCalculationError)?;
     
     if escrow_vault.current_balance < required_amount {
         return Err(OsemeGroupError::InsufficientEscrowBalance.into());
     }
     
-    // Calculate fees
-    let gross_amount = required_amount;
-    let platform_fee = gross_amount
-        .checked_mul(platform_config.fee_bps as u64)
-        .ok_or(OsemeGroupError::UsdcCalculationError)?
-        .checked_div(10000)
-        .ok_or(OsemeGroupError::UsdcCalculationError)?;
+    let creator_volume = &mut ctx.accounts.creator_volume;
+    if creator_volume.version == 0 {
+        creator_volume.version = CreatorVolume::VERSION;
+        creator_volume.creator = group.creator;
+        creator_volume.bump = ctx.bumps.creator_volume;
+    }
+    if creator_volume.version != CreatorVolume::VERSION {
+        return Err(OsemeGroupError::AccountVersionMismatch.into());
+    }
     
-    // Calculate creator fee share based on model
-    let creator_fee = match group.model {
-        GroupModel::Basic => 0,
-        GroupModel::Trust => platform_fee
-            .checked_mul(75)
-            .ok_or(OsemeGroupError::UsdcCalculationError)?
-            .checked_div(100)
-            .ok_or(OsemeGroupError::UsdcCalculationError)?,
-        GroupModel::SuperTrust => platform_fee
-            .checked_mul(90)
-            .ok_or(OsemeGroupError::UsdcCalculationError)?
-            .checked_div(100)
-            .ok_or(OsemeGroupError::UsdcCalculationError)?,
+    // The model's fee applies unless a schedule tier undercuts it for this
+    // group's trust score and the creator's volume before this payout
+    let (fee_tier, fee_bps) = match FeeSchedule::read(&ctx.accounts.fee_schedule)? {
+        Some(schedule) => schedule
+            .discount(model_config.fee_bps, group.trust_score, creator_volume.lifetime_volume)
+            .map_or((None, model_config.fee_bps), |(tier, fee_bps)| (Some(tier), fee_bps)),
+        None => (None, model_config.fee_bps),
     };
     
-    let net_amount = gross_amount
-        .checked_sub(platform_fee)
-        .ok_or(OsemeGroupError::UsdcCalculationError)?;
+    // The group's promo waives part of the fee while it has uses left
+    let waiver_bps = match (&ctx.accounts.promo, &ctx.accounts.promo_redemption) {
+        _ if group.promo == Pubkey::default() => 0,
+        (Some(promo), Some(_)) => promo.active_waiver_bps(),
+        _ => return Err(OsemeGroupError::PromoAccountMismatch.into()),
+    };
+    
+    // Calculate fees; the split always adds up to the gross amount
+    let gross_amount = required_amount;
+    let split = FeeSplit::new(
+        UsdcAmount::new(gross_amount),
+        fee_bps,
+        waiver_bps,
+        model_config.creator_share_bps,
+    ).ok_or(OsemeGroupError::UsdcCalculationError)?;
+    
+    let platform_fee = split.platform_fee().units();
+    let net_amount = split.net.units();
+    let fee_waived = split.waived.units();
+    
+    // The platform's part of the fee goes to the treasury, the creator's
+    // part to their revenue account there
+    let treasury_fee = split.treasury.units();
+    let creator_fee = split.creator.units();
+    
+    if fee_waived > 0 {
+        if let (Some(promo), Some(redemption)) = (&mut ctx.accounts.promo, &mut ctx.accounts.promo_redemption) {
+            promo.uses_remaining -= 1;
+            promo.total_waived = promo.total_waived.saturating_add(fee_waived);
+            redemption.payouts_waived = redemption.payouts_waived.saturating_add(1);
+            redemption.total_waived = redemption.total_waived.saturating_add(fee_waived);
+            redemption.last_redeemed_at = clock.unix_timestamp;
+            
+            emit!(PromoRedeemed {
+                promo: promo.key(),
+                group: group_key,
+                creator: group.creator,
+                turn_index,
+                fee_waived,
+                uses_remaining: promo.uses_remaining,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+    }
     
     // Transfer net amount to recipient
-    let group_key = group.key();
     let seeds = &[
         b"escrow",
         group_key.as_ref(),
@@ -122,27 +251,82 @@ pub fn handler(ctx: Context<ReleasePayout>, turn_index: u8) -> Result<()> {
     );
     transfer(transfer_ctx, net_amount)?;
     
+    if treasury_fee > 0 {
+        FeeCollection {
+            treasury_program: ctx.accounts.treasury_program.to_account_info(),
+            treasury: ctx.accounts.treasury.to_account_info(),
+            fee_vault: ctx.accounts.fee_vault.to_account_info(),
+            escrow_token_account: ctx.accounts.escrow_token_account.to_account_info(),
+            escrow_vault: escrow_vault.to_account_info(),
+            treasury_authority: ctx.accounts.treasury_authority.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            group: group_key,
+            escrow_bump: escrow_vault.bump,
+            treasury_authority_bump: ctx.bumps.treasury_authority,
+        }.collect_fees(treasury_fee)?;
+    }
+    
+    if creator_fee > 0 {
+        CreatorFeeCredit {
+            treasury_program: ctx.accounts.treasury_program.to_account_info(),
+            treasury: ctx.accounts.treasury.to_account_info(),
+            creator_revenue: ctx.accounts.creator_revenue.to_account_info(),
+            creator: ctx.accounts.creator.to_account_info(),
+            creator_vault: ctx.accounts.creator_vault.to_account_info(),
+            usdc_mint: ctx.accounts.usdc_mint.to_account_info(),
+            escrow_token_account: ctx.accounts.escrow_token_account.to_account_info(),
+            escrow_vault: escrow_vault.to_account_info(),
+            treasury_authority: ctx.accounts.treasury_authority.to_account_info(),
+            payer: ctx.accounts.cranker.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            group: group_key,
+            escrow_bump: escrow_vault.bump,
+            treasury_authority_bump: ctx.bumps.treasury_authority,
+        }.credit_creator_fees(creator_fee)?;
+    }
+    
+    creator_volume.lifetime_volume = creator_volume.lifetime_volume.saturating_add(gross_amount);
+    creator_volume.payouts = creator_volume.payouts.saturating_add(1);
+    creator_volume.last_payout_at = clock.unix_timestamp;
+    
     // Update escrow balance
     escrow_vault.current_balance = escrow_vault.current_balance
         .checked_sub(gross_amount)
         .ok_or(OsemeGroupError::UsdcCalculationError)?;
     
+    // The recipient shares in later debt repayments for what they were shorted
+    if group.turn_shortfall > 0 {
+        let recipient_member = &mut ctx.accounts.recipient_member;
+        recipient_member.settle_recovery(escrow_vault.recovery_per_shorted);
+        recipient_member.shorted_amount = recipient_member.shorted_amount
+            .checked_add(group.turn_shortfall)
+            .ok_or(OsemeGroupError::UsdcCalculationError)?;
+        escrow_vault.total_shorted = escrow_vault.total_shorted
+            .checked_add(group.turn_shortfall)
+            .ok_or(OsemeGroupError::UsdcCalculationError)?;
+    }
+    
     // Move to next turn
     group.current_turn_index = group.current_turn_index
         .checked_add(1)
         .ok_or(OsemeGroupError::UsdcCalculationError)?;
     
-    group.current_turn_start = clock.unix_timestamp;
+    group.start_turn(clock.unix_timestamp, ctx.accounts.platform_config.paused_secs(clock.unix_timestamp));
+    group.turn_shortfall = 0;
     
     // Emit payout event
     emit!(PayoutReleased {
-        group: group.key(),
+        group: group_key,
         recipient: ctx.accounts.recipient.key(),
         turn_index,
         gross_amount,
         platform_fee,
         creator_fee,
         net_amount,
+        fee_bps,
+        fee_tier,
+        fee_waived,
         timestamp: clock.unix_timestamp,
     });
     
@@ -150,7 +334,7 @@ pub fn handler(ctx: Context<ReleasePayout>, turn_index: u8) -> Result<()> {
     
     // Check if group is completed
     if group.current_turn_index >= group.total_members {
-        group.status = GroupStatus::Completed;
+        group.set_status(GroupStatus::Completed);
         msg!("Group completed after {} turns", group.total_members);
     }
     
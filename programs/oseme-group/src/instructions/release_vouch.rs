@@ -0,0 +1,143 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount, Transfer, transfer};
+use oseme_trust::program::OsemeTrust;
+use crate::state::*;
+use crate::trust::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct ReleaseVouch<'info> {
+    #[account(
+        constraint = group.load()?.version == Group::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub group: AccountLoader<'info, Group>,
+    
+    #[account(
+        mut,
+        close = voucher,
+        has_one = voucher,
+        seeds = [b"vouch", group.key().as_ref(), vouch.member.as_ref()],
+        bump = vouch.bump,
+        constraint = vouch.version == Vouch::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub vouch: Account<'info, Vouch>,
+    
+    #[account(
+        mut,
+        seeds = [b"escrow", group.key().as_ref()],
+        bump = escrow_vault.bump,
+        constraint = escrow_vault.version == EscrowVault::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub escrow_vault: Account<'info, EscrowVault>,
+    
+    #[account(
+        mut,
+        seeds = [b"escrow-token", group.key().as_ref()],
+        bump
+    )]
+    pub escrow_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = voucher_token_account.mint == escrow_token_account.mint,
+        constraint = voucher_token_account.owner == voucher.key()
+    )]
+    pub voucher_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        seeds = [b"platform-config"],
+        bump = platform_config.bump,
+        constraint = platform_config.version == PlatformConfig::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// CHECK: Voucher's UserTrust PDA, validated and created by oseme-trust
+    #[account(mut)]
+    pub user_trust: UncheckedAccount<'info>,
+    
+    /// CHECK: Signer PDA for reputation updates
+    #[account(seeds = [TRUST_AUTHORITY_SEED], bump)]
+    pub trust_authority: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub voucher: Signer<'info>,
+    
+    pub trust_program: Program<'info, OsemeTrust>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Refund an unused vouch at any time, or a used one once the group is over
+pub fn release_vouch(ctx: Context<ReleaseVouch>) -> Result<()> {
+    let group_key = ctx.accounts.group.key();
+    let group = ctx.accounts.group.load()?;
+    let vouch = &ctx.accounts.vouch;
+    let escrow_vault = &mut ctx.accounts.escrow_vault;
+    let clock = Clock::get()?;
+    
+    let joined = vouch.joined_at != 0;
+    if joined && !group.is_closed_out()? {
+        return Err(OsemeGroupError::VouchLocked.into());
+    }
+    
+    let refunded = vouch.amount;
+    if refunded > 0 {
+        let seeds = &[
+            b"escrow",
+            group_key.as_ref(),
+            &[escrow_vault.bump]
+        ];
+        let signer_seeds = &[&seeds[..]];
+        
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.escrow_token_account.to_account_info(),
+                to: ctx.accounts.voucher_token_account.to_account_info(),
+                authority: escrow_vault.to_account_info(),
+            },
+            signer_seeds
+        );
+        transfer(transfer_ctx, refunded)?;
+        
+        escrow_vault.stake_balance = escrow_vault.stake_balance
+            .checked_sub(refunded)
+            .ok_or(OsemeGroupError::UsdcCalculationError)?;
+    }
+    
+    // Vouching for a newcomer who finished without a slash earns a bonus
+    let trust_bonus = if joined
+        && group.status()? == GroupStatus::Finalized
+        && vouch.slashed_amount == 0
+    {
+        ctx.accounts.platform_config.vouch_bonus
+    } else {
+        0
+    };
+    
+    if trust_bonus != 0 {
+        TrustUpdate {
+            trust_program: ctx.accounts.trust_program.to_account_info(),
+            user_trust: ctx.accounts.user_trust.to_account_info(),
+            user: ctx.accounts.voucher.to_account_info(),
+            trust_authority: ctx.accounts.trust_authority.to_account_info(),
+            payer: ctx.accounts.voucher.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            trust_authority_bump: ctx.bumps.trust_authority,
+            params: ctx.accounts.platform_config.trust_params(),
+        }.record_vouch(trust_bonus)?;
+    }
+    
+    emit!(VouchReleased {
+        group: group_key,
+        voucher: vouch.voucher,
+        member: vouch.member,
+        refunded,
+        slashed_amount: vouch.slashed_amount,
+        trust_bonus,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,163 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount, Transfer, transfer};
+use oseme_treasury::program::OsemeTreasury;
+use crate::state::*;
+use crate::treasury::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct RepayDebt<'info> {
+    #[account(
+        mut,
+        constraint = group.load()?.version == Group::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub group: AccountLoader<'info, Group>,
+    
+    #[account(
+        mut,
+        seeds = [b"debt", group.key().as_ref(), debt.debtor.as_ref()],
+        bump = debt.bump,
+        constraint = debt.version == Debt::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub debt: Account<'info, Debt>,
+    
+    #[account(
+        mut,
+        seeds = [b"defaulter", debt.debtor.as_ref()],
+        bump = defaulter_record.bump,
+        constraint = defaulter_record.version == DefaulterRecord::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub defaulter_record: Account<'info, DefaulterRecord>,
+    
+    #[account(
+        mut,
+        seeds = [b"escrow", group.key().as_ref()],
+        bump = escrow_vault.bump,
+        constraint = escrow_vault.version == EscrowVault::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub escrow_vault: Account<'info, EscrowVault>,
+    
+    #[account(
+        mut,
+        seeds = [b"escrow-token", group.key().as_ref()],
+        bump
+    )]
+    pub escrow_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = payer_token_account.mint == escrow_token_account.mint,
+        constraint = payer_token_account.owner == payer.key()
+    )]
+    pub payer_token_account: Account<'info, TokenAccount>,
+    
+    /// Usually the debtor, but anyone may repay on their behalf
+    pub payer: Signer<'info>,
+    
+    /// CHECK: Treasury PDA, validated by oseme-treasury; the treasury accounts
+    /// are required when no shorted member is left to receive the repayment
+    #[account(mut)]
+    pub treasury: Option<UncheckedAccount<'info>>,
+    
+    /// CHECK: Treasury fee vault, validated by oseme-treasury
+    #[account(mut)]
+    pub fee_vault: Option<UncheckedAccount<'info>>,
+    
+    /// CHECK: Signer PDA for fee collection
+    #[account(seeds = [TREASURY_AUTHORITY_SEED], bump)]
+    pub treasury_authority: Option<UncheckedAccount<'info>>,
+    
+    pub treasury_program: Option<Program<'info, OsemeTreasury>>,
+    pub token_program: Program<'info, Token>,
+}
+
+/// Repayments first refill the open turn, the rest goes to shorted members,
+/// or to the treasury when there are none
+pub fn repay_debt(ctx: Context<RepayDebt>, amount: u64) -> Result<()> {
+    let group_key = ctx.accounts.group.key();
+    let mut group = ctx.accounts.group.load_mut()?;
+    let debt = &mut ctx.accounts.debt;
+    let escrow_vault = &mut ctx.accounts.escrow_vault;
+    let clock = Clock::get()?;
+    
+    if amount == 0 || amount > debt.outstanding {
+        return Err(OsemeGroupError::RepaymentExceedsDebt.into());
+    }
+    
+    let transfer_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.payer_token_account.to_account_info(),
+            to: ctx.accounts.escrow_token_account.to_account_info(),
+            authority: ctx.accounts.payer.to_account_info(),
+        }
+    );
+    transfer(transfer_ctx, amount)?;
+    
+    // The open turn's recipient has not been paid yet, so make them whole first
+    let to_open_turn = match group.status()? {
+        GroupStatus::Active | GroupStatus::Paused => amount.min(group.turn_shortfall),
+        _ => 0,
+    };
+    group.turn_shortfall -= to_open_turn;
+    escrow_vault.current_balance = escrow_vault.current_balance
+        .checked_add(to_open_turn)
+        .ok_or(OsemeGroupError::UsdcCalculationError)?;
+    
+    let remainder = amount - to_open_turn;
+    let mut to_treasury = 0;
+    if remainder > 0 && escrow_vault.total_shorted > 0 {
+        escrow_vault.distribute_recovery(remainder)?;
+    } else if remainder > 0 {
+        let (Some(treasury), Some(fee_vault), Some(treasury_authority), Some(treasury_program)) = (
+            ctx.accounts.treasury.as_ref(),
+            ctx.accounts.fee_vault.as_ref(),
+            ctx.accounts.treasury_authority.as_ref(),
+            ctx.accounts.treasury_program.as_ref(),
+        ) else {
+            return Err(OsemeGroupError::TreasuryAccountsRequired.into());
+        };
+        
+        FeeCollection {
+            treasury_program: treasury_program.to_account_info(),
+            treasury: treasury.to_account_info(),
+            fee_vault: fee_vault.to_account_info(),
+            escrow_token_account: ctx.accounts.escrow_token_account.to_account_info(),
+            escrow_vault: escrow_vault.to_account_info(),
+            treasury_authority: treasury_authority.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            group: group_key,
+            escrow_bump: escrow_vault.bump,
+            treasury_authority_bump: ctx.bumps.treasury_authority,
+        }.collect_fees(remainder)?;
+        to_treasury = remainder;
+    }
+    
+    debt.outstanding -= amount;
+    debt.repaid = debt.repaid
+        .checked_add(amount)
+        .ok_or(OsemeGroupError::UsdcCalculationError)?;
+    debt.last_repaid_at = clock.unix_timestamp;
+    escrow_vault.outstanding_debt = escrow_vault.outstanding_debt.saturating_sub(amount);
+    
+    // A fully repaid debt no longer counts against the wallet
+    let defaulter_record = &mut ctx.accounts.defaulter_record;
+    defaulter_record.outstanding_debt = defaulter_record.outstanding_debt.saturating_sub(amount);
+    if debt.outstanding == 0 {
+        defaulter_record.open_debts = defaulter_record.open_debts.saturating_sub(1);
+    }
+    
+    emit!(DebtRepaid {
+        group: group_key,
+        debtor: debt.debtor,
+        payer: ctx.accounts.payer.key(),
+        amount,
+        to_open_turn,
+        to_treasury,
+        outstanding: debt.outstanding,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
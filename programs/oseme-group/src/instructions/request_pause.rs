@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct RequestPause<'info> {
+    #[account(
+        constraint = group.load()?.version == Group::VERSION @ OsemeGroupError::AccountVersionMismatch,
+        constraint = group.load()?.creator == creator.key() @ OsemeGroupError::Unauthorized
+    )]
+    pub group: AccountLoader<'info, Group>,
+    
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = PauseRequest::LEN,
+        seeds = [b"pause-request", group.key().as_ref()],
+        bump
+    )]
+    pub pause_request: Account<'info, PauseRequest>,
+    
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// The group keeps running until the platform authority confirms the
+/// request with `pause_group`. Requesting again replaces the reason.
+pub fn request_pause(ctx: Context<RequestPause>, reason: PauseReason) -> Result<()> {
+    let group_key = ctx.accounts.group.key();
+    let pause_request = &mut ctx.accounts.pause_request;
+    let clock = Clock::get()?;
+    
+    if ctx.accounts.group.load()?.status()? != GroupStatus::Active {
+        return Err(OsemeGroupError::GroupNotActive.into());
+    }
+    
+    if pause_request.version == 0 {
+        pause_request.version = PauseRequest::VERSION;
+        pause_request.group = group_key;
+        pause_request.creator = ctx.accounts.creator.key();
+        pause_request.bump = ctx.bumps.pause_request;
+    }
+    if pause_request.version != PauseRequest::VERSION {
+        return Err(OsemeGroupError::AccountVersionMismatch.into());
+    }
+    pause_request.reason = reason;
+    pause_request.requested_at = clock.unix_timestamp;
+    
+    emit!(GroupPauseRequested {
+        group: group_key,
+        creator: pause_request.creator,
+        reason: reason as u8,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
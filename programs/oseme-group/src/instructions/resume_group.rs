@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct ResumeGroup<'info> {
+    #[account(
+        mut,
+        constraint = group.load()?.version == Group::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub group: AccountLoader<'info, Group>,
+    
+    #[account(
+        seeds = [b"platform-config"],
+        bump = platform_config.bump,
+        has_one = authority @ OsemeGroupError::Unauthorized,
+        constraint = platform_config.version == PlatformConfig::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    pub authority: Signer<'info>,
+}
+
+/// Reactivate a paused group; the current turn starts later by the time
+/// it was paused, so members keep the same time to contribute
+pub fn resume_group(ctx: Context<ResumeGroup>) -> Result<()> {
+    let group_key = ctx.accounts.group.key();
+    let mut group = ctx.accounts.group.load_mut()?;
+    let clock = Clock::get()?;
+    
+    if group.status()? != GroupStatus::Paused {
+        return Err(OsemeGroupError::GroupNotPaused.into());
+    }
+    
+    let platform_paused_secs = ctx.accounts.platform_config.paused_secs(clock.unix_timestamp);
+    let paused_secs = group.resume(clock.unix_timestamp, platform_paused_secs);
+    
+    emit!(GroupResumed {
+        group: group_key,
+        admin: ctx.accounts.authority.key(),
+        paused_secs,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
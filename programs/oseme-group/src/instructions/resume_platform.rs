@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct ResumePlatform<'info> {
+    #[account(
+        mut,
+        seeds = [b"platform-config"],
+        bump = platform_config.bump,
+        has_one = authority @ OsemeGroupError::Unauthorized,
+        constraint = platform_config.version == PlatformConfig::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    pub authority: Signer<'info>,
+}
+
+/// End a platform-wide pause. Its length is added to `total_paused_secs`,
+/// which pushes back the deadline of every turn that was open during it.
+pub fn resume_platform(ctx: Context<ResumePlatform>) -> Result<()> {
+    let platform_config = &mut ctx.accounts.platform_config;
+    let clock = Clock::get()?;
+    
+    if !platform_config.is_paused() {
+        return Err(OsemeGroupError::PlatformNotPaused.into());
+    }
+    let paused_secs = (clock.unix_timestamp - platform_config.paused_at).max(0);
+    platform_config.total_paused_secs = platform_config.total_paused_secs
+        .checked_add(paused_secs)
+        .ok_or(OsemeGroupError::UsdcCalculationError)?;
+    platform_config.paused_at = 0;
+    
+    emit!(PlatformResumed {
+        authority: ctx.accounts.authority.key(),
+        paused_secs,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
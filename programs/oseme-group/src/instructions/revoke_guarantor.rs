@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount, Revoke, revoke};
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct RevokeGuarantor<'info> {
+    #[account(
+        constraint = group.load()?.version == Group::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub group: AccountLoader<'info, Group>,
+    
+    #[account(
+        mut,
+        seeds = [b"guarantor", group.key().as_ref(), guarantor.member.as_ref()],
+        bump = guarantor.bump,
+        constraint = guarantor.guarantor == guarantor_wallet.key() @ OsemeGroupError::Unauthorized,
+        constraint = guarantor.version == Guarantor::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub guarantor: Account<'info, Guarantor>,
+    
+    #[account(
+        mut,
+        address = guarantor.token_account @ OsemeGroupError::GuarantorAccountMismatch
+    )]
+    pub guarantor_token_account: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub guarantor_wallet: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+/// The first call ends liability after the open turn; once that turn is
+/// over, calling again drops the token allowance and closes the guarantee
+pub fn revoke_guarantor(ctx: Context<RevokeGuarantor>) -> Result<()> {
+    let group_key = ctx.accounts.group.key();
+    let group = ctx.accounts.group.load()?;
+    let guarantor = &mut ctx.accounts.guarantor;
+    let clock = Clock::get()?;
+    
+    let open_turn = match group.status()? {
+        GroupStatus::Active | GroupStatus::Paused => Some(group.current_turn_index),
+        _ => None,
+    };
+    
+    let already_revoked = guarantor.last_liable_turn != Guarantor::OPEN_ENDED;
+    if !already_revoked {
+        guarantor.last_liable_turn = open_turn.unwrap_or(0);
+    }
+    
+    let still_liable = open_turn.is_some_and(|turn_index| guarantor.is_liable_for(turn_index));
+    if still_liable {
+        if already_revoked {
+            return Err(OsemeGroupError::GuarantorStillLiable.into());
+        }
+    } else {
+        let revoke_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Revoke {
+                source: ctx.accounts.guarantor_token_account.to_account_info(),
+                authority: ctx.accounts.guarantor_wallet.to_account_info(),
+            }
+        );
+        revoke(revoke_ctx)?;
+        
+        guarantor.close(ctx.accounts.guarantor_wallet.to_account_info())?;
+    }
+    
+    emit!(GuarantorRevoked {
+        group: group_key,
+        member: guarantor.member,
+        guarantor: guarantor.guarantor,
+        last_liable_turn: guarantor.last_liable_turn,
+        closed: !still_liable,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+#[instruction(model: GroupModel)]
+pub struct SetFeeSchedule<'info> {
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = FeeSchedule::LEN,
+        seeds = [b"fee-schedule".as_ref(), &[model as u8]],
+        bump
+    )]
+    pub fee_schedule: Account<'info, FeeSchedule>,
+    
+    #[account(
+        seeds = [b"platform-config"],
+        bump = platform_config.bump,
+        has_one = authority @ OsemeGroupError::Unauthorized,
+        constraint = platform_config.version == PlatformConfig::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Replace a model's fee tiers. Payouts are charged the cheapest tier the
+/// group qualifies for, floored at half the model's fee, or the model's fee
+/// when no tier undercuts it; an empty list switches discounts off.
+pub fn set_fee_schedule(ctx: Context<SetFeeSchedule>, model: GroupModel, tiers: Vec<FeeTier>) -> Result<()> {
+    let fee_schedule = &mut ctx.accounts.fee_schedule;
+    let clock = Clock::get()?;
+    
+    if fee_schedule.version == 0 {
+        fee_schedule.version = FeeSchedule::VERSION;
+        fee_schedule.model = model;
+        fee_schedule.bump = ctx.bumps.fee_schedule;
+    }
+    if fee_schedule.version != FeeSchedule::VERSION {
+        return Err(OsemeGroupError::AccountVersionMismatch.into());
+    }
+    
+    fee_schedule.set_tiers(&tiers)?;
+    fee_schedule.updated_at = clock.unix_timestamp;
+    
+    emit!(FeeScheduleUpdated {
+        model: model as u8,
+        authority: ctx.accounts.authority.key(),
+        tiers,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
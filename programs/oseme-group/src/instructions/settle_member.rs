@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+use oseme_trust::program::OsemeTrust;
+use crate::state::*;
+use crate::trust::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct SettleMember<'info> {
+    #[account(
+        constraint = group.load()?.version == Group::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub group: AccountLoader<'info, Group>,
+    
+    #[account(
+        mut,
+        seeds = [b"member", group.key().as_ref(), member.user.as_ref()],
+        bump = member.bump,
+        constraint = member.version == Member::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub member: Account<'info, Member>,
+    
+    #[account(
+        seeds = [b"platform-config"],
+        bump = platform_config.bump,
+        constraint = platform_config.version == PlatformConfig::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// CHECK: Member's wallet, only used to derive their UserTrust
+    #[account(address = member.user)]
+    pub user: UncheckedAccount<'info>,
+    
+    /// CHECK: Member's UserTrust PDA, validated and created by oseme-trust
+    #[account(mut)]
+    pub user_trust: UncheckedAccount<'info>,
+    
+    /// CHECK: Signer PDA for reputation updates
+    #[account(seeds = [TRUST_AUTHORITY_SEED], bump)]
+    pub trust_authority: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub trust_program: Program<'info, OsemeTrust>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Carry a member's result in a finalized group over to their UserTrust
+pub fn settle_member(ctx: Context<SettleMember>) -> Result<()> {
+    let group_key = ctx.accounts.group.key();
+    let group = ctx.accounts.group.load()?;
+    let member = &mut ctx.accounts.member;
+    let clock = Clock::get()?;
+    
+    if group.status()? != GroupStatus::Finalized {
+        return Err(OsemeGroupError::GroupNotClosedOut.into());
+    }
+    
+    if member.trust_settled_at != 0 {
+        return Err(OsemeGroupError::TrustAlreadySettled.into());
+    }
+    
+    // Defaults were already penalised when slashed; only clean runs earn a bonus
+    let clean = member.missed_count == 0;
+    let bonus = if clean { ctx.accounts.platform_config.trust_bonus } else { 0 };
+    member.trust_delta = member.trust_delta.saturating_add(bonus);
+    member.trust_settled_at = clock.unix_timestamp;
+    
+    TrustUpdate {
+        trust_program: ctx.accounts.trust_program.to_account_info(),
+        user_trust: ctx.accounts.user_trust.to_account_info(),
+        user: ctx.accounts.user.to_account_info(),
+        trust_authority: ctx.accounts.trust_authority.to_account_info(),
+        payer: ctx.accounts.payer.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+        trust_authority_bump: ctx.bumps.trust_authority,
+        params: ctx.accounts.platform_config.trust_params(),
+    }.record_completion(bonus, clean)?;
+    
+    emit!(MemberSettled {
+        group: group_key,
+        member: member.user,
+        trust_delta: member.trust_delta,
+        clean,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,395 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token::{Token, TokenAccount, Transfer, transfer};
+use oseme_trust::program::OsemeTrust;
+use crate::state::*;
+use crate::trust::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct SlashMember<'info> {
+    #[account(
+        mut,
+        constraint = group.load()?.version == Group::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub group: AccountLoader<'info, Group>,
+    
+    #[account(
+        mut,
+        seeds = [b"member", group.key().as_ref(), member.user.as_ref()],
+        bump = member.bump,
+        constraint = member.version == Member::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub member: Account<'info, Member>,
+    
+    #[account(
+        mut,
+        seeds = [b"escrow", group.key().as_ref()],
+        bump = escrow_vault.bump,
+        constraint = escrow_vault.version == EscrowVault::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub escrow_vault: Account<'info, EscrowVault>,
+    
+    #[account(
+        mut,
+        seeds = [b"escrow-token", group.key().as_ref()],
+        bump
+    )]
+    pub escrow_token_account: Account<'info, TokenAccount>,
+    
+    /// CHECK: Guarantee for the member, may not exist
+    #[account(
+        mut,
+        seeds = [b"guarantor", group.key().as_ref(), member.user.as_ref()],
+        bump
+    )]
+    pub guarantor: UncheckedAccount<'info>,
+    
+    /// Required when the member has a guarantor
+    #[account(mut)]
+    pub guarantor_token_account: Option<Account<'info, TokenAccount>>,
+    
+    /// CHECK: Guarantor's wallet, only used to derive their UserTrust;
+    /// required when a liable guarantor falls short of their allowance
+    pub guarantor_wallet: Option<UncheckedAccount<'info>>,
+    
+    /// CHECK: Guarantor's UserTrust PDA, validated and created by oseme-trust
+    #[account(mut)]
+    pub guarantor_trust: Option<UncheckedAccount<'info>>,
+    
+    #[account(
+        seeds = [b"platform-config"],
+        bump = platform_config.bump,
+        constraint = platform_config.version == PlatformConfig::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// CHECK: Vouch for the member, may not exist
+    #[account(
+        mut,
+        seeds = [b"vouch", group.key().as_ref(), member.user.as_ref()],
+        bump
+    )]
+    pub vouch: UncheckedAccount<'info>,
+    
+    /// CHECK: Records what a member who was already paid out still owes;
+    /// required, and created, only when the slash leaves a shortfall
+    #[account(
+        mut,
+        seeds = [b"debt", group.key().as_ref(), member.user.as_ref()],
+        bump
+    )]
+    pub debt: Option<UncheckedAccount<'info>>,
+    
+    #[account(
+        init_if_needed,
+        payer = cranker,
+        space = DefaulterRecord::LEN,
+        seeds = [b"defaulter", member.user.as_ref()],
+        bump
+    )]
+    pub defaulter_record: Account<'info, DefaulterRecord>,
+    
+    /// CHECK: Defaulting member's wallet, only used to derive their UserTrust
+    #[account(address = member.user)]
+    pub user: UncheckedAccount<'info>,
+    
+    /// CHECK: Member's UserTrust PDA, validated and created by oseme-trust
+    #[account(mut)]
+    pub user_trust: UncheckedAccount<'info>,
+    
+    /// CHECK: Signer PDA for reputation updates
+    #[account(seeds = [TRUST_AUTHORITY_SEED], bump)]
+    pub trust_authority: UncheckedAccount<'info>,
+    
+    /// Anyone may slash once the grace period is over
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+    
+    pub trust_program: Program<'info, OsemeTrust>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Cover a missed contribution from the member's stake after the grace period
+pub fn slash_member(ctx: Context<SlashMember>) -> Result<()> {
+    let group_key = ctx.accounts.group.key();
+    let mut group = ctx.accounts.group.load_mut()?;
+    let member = &mut ctx.accounts.member;
+    let escrow_vault = &mut ctx.accounts.escrow_vault;
+    let platform_config = &ctx.accounts.platform_config;
+    let clock = Clock::get()?;
+    
+    if group.status()? != GroupStatus::Active {
+        return Err(OsemeGroupError::GroupNotActive.into());
+    }
+    
+    let turn_index = group.current_turn_index;
+    if group.current_recipient().is_none() {
+        return Err(OsemeGroupError::NotCurrentTurnRecipient.into());
+    }
+    
+    let paused_secs = platform_config.paused_secs(clock.unix_timestamp);
+    if clock.unix_timestamp <= group.grace_deadline(paused_secs, platform_config.grace_period_days) {
+        return Err(OsemeGroupError::TurnDeadlineNotReached.into());
+    }
+    
+    if group.has_contributed(turn_index, member.payout_position) {
+        return Err(OsemeGroupError::TurnAlreadyCompleted.into());
+    }
+    
+    // Covered turns are drawn by draw_collateral instead
+    if member.collateral_covers(group.contribution_amount) {
+        return Err(OsemeGroupError::CollateralCoversTurn.into());
+    }
+    
+    // A guarantor liable for this turn is drawn on before any stake
+    let mut guarantor_covered = 0;
+    if let Some(mut guarantor) = Guarantor::read(&ctx.accounts.guarantor)?
+        .filter(|guarantor| guarantor.is_liable_for(turn_index))
+    {
+        let token_account = ctx.accounts.guarantor_token_account.as_ref()
+            .filter(|token_account| token_account.key() == guarantor.token_account)
+            .ok_or(OsemeGroupError::GuarantorAccountMismatch)?;
+        
+        // Whatever allowance the guarantor has left in place
+        let allowance = if token_account.delegate == Some(escrow_vault.key()).into() {
+            token_account.delegated_amount.min(token_account.amount)
+        } else {
+            0
+        };
+        guarantor_covered = allowance.min(group.contribution_amount);
+        
+        if guarantor_covered > 0 {
+            let seeds = &[
+                b"escrow",
+                group_key.as_ref(),
+                &[escrow_vault.bump]
+            ];
+            let signer_seeds = &[&seeds[..]];
+            
+            let transfer_ctx = CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: token_account.to_account_info(),
+                    to: ctx.accounts.escrow_token_account.to_account_info(),
+                    authority: escrow_vault.to_account_info(),
+                },
+                signer_seeds
+            );
+            transfer(transfer_ctx, guarantor_covered)?;
+            
+        }
+        
+        // Falling short of what is left of the allowance, e.g. by revoking the
+        // delegate or emptying the account, counts as a default of the guarantor
+        let promised = guarantor.allowance
+            .saturating_sub(guarantor.pulled_amount)
+            .min(group.contribution_amount);
+        if guarantor_covered < promised {
+            let guarantor_wallet = ctx.accounts.guarantor_wallet.as_ref()
+                .filter(|wallet| wallet.key() == guarantor.guarantor)
+                .ok_or(OsemeGroupError::GuarantorAccountMismatch)?;
+            let guarantor_trust = ctx.accounts.guarantor_trust.as_ref()
+                .ok_or(OsemeGroupError::GuarantorAccountMismatch)?;
+            
+            let penalty = platform_config.trust_penalty;
+            TrustUpdate {
+                trust_program: ctx.accounts.trust_program.to_account_info(),
+                user_trust: guarantor_trust.to_account_info(),
+                user: guarantor_wallet.to_account_info(),
+                trust_authority: ctx.accounts.trust_authority.to_account_info(),
+                payer: ctx.accounts.cranker.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                trust_authority_bump: ctx.bumps.trust_authority,
+                params: platform_config.trust_params(),
+            }.record_default(penalty)?;
+            
+            emit!(GuarantorDefaulted {
+                group: group_key,
+                member: member.user,
+                guarantor: guarantor.guarantor,
+                turn_index,
+                promised,
+                covered: guarantor_covered,
+                trust_penalty: penalty,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+        
+        if guarantor_covered > 0 {
+            guarantor.pulled_amount += guarantor_covered;
+            guarantor.write(&ctx.accounts.guarantor)?;
+        }
+    }
+    let remaining = group.contribution_amount - guarantor_covered;
+    
+    // A voucher shares the rest pro rata to the stake they locked
+    let mut vouch = Vouch::read(&ctx.accounts.vouch)?
+        .filter(|vouch| vouch.joined_at != 0);
+    let (member_covered, voucher_covered) = match vouch.as_ref() {
+        Some(vouch) => vouch.split_slash(remaining, member.stake_amount),
+        None => (member.stake_amount.min(remaining), 0),
+    };
+    if let Some(vouch) = vouch.as_mut() {
+        vouch.amount -= voucher_covered;
+        vouch.slashed_amount += voucher_covered;
+        vouch.write(&ctx.accounts.vouch)?;
+    }
+    
+    // Stake already sits in the escrow token account, so covering the turn
+    // only moves it from the stake balance into the turn's pool
+    let stake_covered = member_covered + voucher_covered;
+    let covered_amount = guarantor_covered + stake_covered;
+    let shortfall = group.contribution_amount - covered_amount;
+    
+    // A first miss takes the member's stake out of the completion bonus
+    if member.missed_count == 0 {
+        escrow_vault.compliant_stake = escrow_vault.compliant_stake.saturating_sub(member.stake_amount);
+    }
+    member.stake_amount -= member_covered;
+    escrow_vault.stake_balance = escrow_vault.stake_balance
+        .checked_sub(stake_covered)
+        .ok_or(OsemeGroupError::UsdcCalculationError)?;
+    escrow_vault.current_balance = escrow_vault.current_balance
+        .checked_add(covered_amount)
+        .ok_or(OsemeGroupError::UsdcCalculationError)?;
+    group.turn_shortfall = group.turn_shortfall
+        .checked_add(shortfall)
+        .ok_or(OsemeGroupError::UsdcCalculationError)?;
+    
+    // Every default goes on the wallet's platform-wide record
+    let defaulter_record = &mut ctx.accounts.defaulter_record;
+    if defaulter_record.version == 0 {
+        defaulter_record.version = DefaulterRecord::VERSION;
+        defaulter_record.user = member.user;
+        defaulter_record.bump = ctx.bumps.defaulter_record;
+    }
+    if defaulter_record.version != DefaulterRecord::VERSION {
+        return Err(OsemeGroupError::AccountVersionMismatch.into());
+    }
+    defaulter_record.defaults = defaulter_record.defaults.saturating_add(1);
+    defaulter_record.last_default_at = clock.unix_timestamp;
+    
+    // A member who already received their payout owes the uncovered part
+    let mut debt_outstanding = 0;
+    if member.payout_position < turn_index && shortfall > 0 {
+        let debt_account = ctx.accounts.debt.as_ref()
+            .ok_or(OsemeGroupError::DebtAccountRequired)?;
+        let mut debt = match Debt::read(debt_account)? {
+            Some(debt) => debt,
+            None => {
+                create_debt_account(
+                    debt_account,
+                    &ctx.accounts.cranker,
+                    &ctx.accounts.system_program,
+                    &[b"debt", group_key.as_ref(), member.user.as_ref(), &[ctx.bumps.debt]],
+                )?;
+                Debt {
+                    version: Debt::VERSION,
+                    group: group_key,
+                    debtor: member.user,
+                    outstanding: 0,
+                    repaid: 0,
+                    defaulted_turns: 0,
+                    created_at: clock.unix_timestamp,
+                    last_repaid_at: 0,
+                    bump: ctx.bumps.debt,
+                }
+            }
+        };
+        
+        if debt.outstanding == 0 {
+            defaulter_record.open_debts = defaulter_record.open_debts.saturating_add(1);
+        }
+        debt.outstanding = debt.outstanding
+            .checked_add(shortfall)
+            .ok_or(OsemeGroupError::UsdcCalculationError)?;
+        defaulter_record.outstanding_debt = defaulter_record.outstanding_debt.saturating_add(shortfall);
+        debt.defaulted_turns = debt.defaulted_turns.saturating_add(1);
+        debt_outstanding = debt.outstanding;
+        debt.write(debt_account)?;
+        
+        escrow_vault.outstanding_debt = escrow_vault.outstanding_debt
+            .checked_add(shortfall)
+            .ok_or(OsemeGroupError::UsdcCalculationError)?;
+    }
+    
+    // The turn is settled for this member; the default is kept on record
+    group.mark_contributed(turn_index, member.payout_position);
+    member.mark_contributed(turn_index);
+    member.missed_count = member.missed_count.saturating_add(1);
+    group.default_count = group.default_count.saturating_add(1);
+    
+    let penalty = platform_config.trust_penalty;
+    member.trust_delta = member.trust_delta.saturating_add(penalty);
+    group.trust_score = group.trust_score.saturating_sub(penalty.unsigned_abs());
+    
+    TrustUpdate {
+        trust_program: ctx.accounts.trust_program.to_account_info(),
+        user_trust: ctx.accounts.user_trust.to_account_info(),
+        user: ctx.accounts.user.to_account_info(),
+        trust_authority: ctx.accounts.trust_authority.to_account_info(),
+        payer: ctx.accounts.cranker.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+        trust_authority_bump: ctx.bumps.trust_authority,
+        params: ctx.accounts.platform_config.trust_params(),
+    }.record_default(penalty)?;
+    
+    emit!(MemberSlashed {
+        group: group_key,
+        member: member.user,
+        turn_index,
+        covered_amount,
+        guarantor_covered,
+        voucher_covered,
+        shortfall,
+        debt_outstanding,
+        trust_penalty: penalty,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    msg!("Member {} slashed for turn {}", member.user, turn_index);
+    Ok(())
+}
+
+/// Allocate the debtor's Debt PDA, topping up an account someone already
+/// sent lamports to
+fn create_debt_account<'info>(
+    debt: &AccountInfo<'info>,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+    seeds: &[&[u8]],
+) -> Result<()> {
+    let signer_seeds = &[seeds];
+    let required = Rent::get()?.minimum_balance(Debt::LEN).saturating_sub(debt.lamports());
+    if required > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                system_program::Transfer {
+                    from: payer.to_account_info(),
+                    to: debt.clone(),
+                },
+            ),
+            required,
+        )?;
+    }
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            system_program::Allocate { account_to_allocate: debt.clone() },
+            signer_seeds,
+        ),
+        Debt::LEN as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            system_program::Assign { account_to_assign: debt.clone() },
+            signer_seeds,
+        ),
+        &crate::ID,
+    )
+}
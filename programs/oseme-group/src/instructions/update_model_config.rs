@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use super::init_model_config::{apply_model_config, emit_model_config_updated};
+
+#[derive(Accounts)]
+pub struct UpdateModelConfig<'info> {
+    #[account(
+        mut,
+        seeds = [b"model-config".as_ref(), &[model_config.model as u8]],
+        bump = model_config.bump,
+        constraint = model_config.version == ModelConfig::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub model_config: Account<'info, ModelConfig>,
+    
+    #[account(
+        seeds = [b"platform-config"],
+        bump = platform_config.bump,
+        has_one = authority @ OsemeGroupError::Unauthorized,
+        constraint = platform_config.version == PlatformConfig::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    pub authority: Signer<'info>,
+}
+
+/// Retune a model; the model itself cannot change. Running groups keep their
+/// member cap and cycle length but pick up fee and stake changes.
+pub fn update_model_config(ctx: Context<UpdateModelConfig>, config: ModelConfig) -> Result<()> {
+    let model_config = &mut ctx.accounts.model_config;
+    
+    if config.model != model_config.model {
+        return Err(OsemeGroupError::InvalidGroupModel.into());
+    }
+    
+    apply_model_config(model_config, &config)?;
+    
+    emit_model_config_updated(model_config, ctx.accounts.authority.key())
+}
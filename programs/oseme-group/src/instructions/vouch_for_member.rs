@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct VouchForMember<'info> {
+    #[account(
+        constraint = group.load()?.version == Group::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub group: AccountLoader<'info, Group>,
+    
+    #[account(
+        mut,
+        seeds = [b"member", group.key().as_ref(), voucher.key().as_ref()],
+        bump = voucher_member.bump,
+        constraint = voucher_member.version == Member::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub voucher_member: Account<'info, Member>,
+    
+    #[account(
+        mut,
+        seeds = [b"escrow", group.key().as_ref()],
+        bump = escrow_vault.bump,
+        constraint = escrow_vault.version == EscrowVault::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub escrow_vault: Account<'info, EscrowVault>,
+    
+    #[account(
+        init,
+        payer = voucher,
+        space = Vouch::LEN,
+        seeds = [b"vouch", group.key().as_ref(), member.key().as_ref()],
+        bump
+    )]
+    pub vouch: Account<'info, Vouch>,
+    
+    #[account(mut)]
+    pub voucher: Signer<'info>,
+    
+    /// Wallet being vouched for; co-signs so nobody can take its vouch slot
+    pub member: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn vouch_for_member(ctx: Context<VouchForMember>, amount: u64) -> Result<()> {
+    let group_key = ctx.accounts.group.key();
+    let group = ctx.accounts.group.load()?;
+    let voucher_member = &mut ctx.accounts.voucher_member;
+    let vouch = &mut ctx.accounts.vouch;
+    let voucher_key = ctx.accounts.voucher.key();
+    let member = ctx.accounts.member.key();
+    let clock = Clock::get()?;
+    
+    if group.status()? != GroupStatus::Active {
+        return Err(OsemeGroupError::GroupNotActive.into());
+    }
+    
+    if member == voucher_key {
+        return Err(OsemeGroupError::CannotVouchForSelf.into());
+    }
+    
+    // The vouch is carved out of stake the voucher already holds in escrow
+    if amount == 0 || amount > voucher_member.stake_amount {
+        return Err(OsemeGroupError::VouchExceedsStake.into());
+    }
+    voucher_member.stake_amount -= amount;
+    // Vouched stake earns no completion bonus
+    if voucher_member.missed_count == 0 {
+        let escrow_vault = &mut ctx.accounts.escrow_vault;
+        escrow_vault.compliant_stake = escrow_vault.compliant_stake.saturating_sub(amount);
+    }
+    
+    vouch.version = Vouch::VERSION;
+    vouch.group = group_key;
+    vouch.voucher = voucher_key;
+    vouch.member = member;
+    vouch.amount = amount;
+    vouch.slashed_amount = 0;
+    vouch.joined_at = 0;
+    vouch.bump = ctx.bumps.vouch;
+    
+    emit!(MemberVouched {
+        group: group_key,
+        voucher: voucher_key,
+        member,
+        amount,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
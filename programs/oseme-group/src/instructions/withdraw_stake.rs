@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount, Transfer, transfer};
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct WithdrawStake<'info> {
+    #[account(
+        constraint = group.load()?.version == Group::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub group: AccountLoader<'info, Group>,
+    
+    #[account(
+        mut,
+        seeds = [b"member", group.key().as_ref(), user.key().as_ref()],
+        bump = member.bump,
+        constraint = member.version == Member::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub member: Account<'info, Member>,
+    
+    #[account(
+        mut,
+        seeds = [b"escrow", group.key().as_ref()],
+        bump = escrow_vault.bump,
+        constraint = escrow_vault.version == EscrowVault::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub escrow_vault: Account<'info, EscrowVault>,
+    
+    #[account(
+        mut,
+        seeds = [b"escrow-token", group.key().as_ref()],
+        bump
+    )]
+    pub escrow_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = user_token_account.mint == escrow_token_account.mint,
+        constraint = user_token_account.owner == user.key()
+    )]
+    pub user_token_account: Account<'info, TokenAccount>,
+    
+    pub user: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn withdraw_stake(ctx: Context<WithdrawStake>) -> Result<()> {
+    let group_key = ctx.accounts.group.key();
+    let group = ctx.accounts.group.load()?;
+    let member = &mut ctx.accounts.member;
+    let escrow_vault = &mut ctx.accounts.escrow_vault;
+    let clock = Clock::get()?;
+    
+    // Stake is locked until the group is over
+    if !group.is_closed_out()? {
+        return Err(OsemeGroupError::GroupNotClosedOut.into());
+    }
+    
+    // A compliant member's completion bonus is paid with the stake unless
+    // distribute_bonuses already did
+    let bonus = if group.status()? == GroupStatus::Finalized && member.earns_bonus() {
+        member.bonus_settled = true;
+        escrow_vault.take_bonus(member.stake_amount)
+    } else {
+        0
+    };
+    
+    // Undrawn collateral is returned along with the stake
+    let amount = member.stake_amount
+        .checked_add(member.collateral_balance)
+        .and_then(|amount| amount.checked_add(bonus))
+        .ok_or(OsemeGroupError::UsdcCalculationError)?;
+    
+    let seeds = &[
+        b"escrow",
+        group_key.as_ref(),
+        &[escrow_vault.bump]
+    ];
+    let signer_seeds = &[&seeds[..]];
+    
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.escrow_token_account.to_account_info(),
+            to: ctx.accounts.user_token_account.to_account_info(),
+            authority: escrow_vault.to_account_info(),
+        },
+        signer_seeds
+    );
+    transfer(transfer_ctx, amount)?;
+    
+    escrow_vault.stake_balance = escrow_vault.stake_balance
+        .checked_sub(member.stake_amount)
+        .ok_or(OsemeGroupError::UsdcCalculationError)?;
+    escrow_vault.collateral_balance = escrow_vault.collateral_balance
+        .checked_sub(member.collateral_balance)
+        .ok_or(OsemeGroupError::UsdcCalculationError)?;
+    member.stake_amount = 0;
+    member.collateral_balance = 0;
+    
+    emit!(StakeWithdrawn {
+        group: group_key,
+        member: member.user,
+        amount,
+        bonus,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct WriteOffDebt<'info> {
+    #[account(
+        constraint = group.load()?.version == Group::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub group: AccountLoader<'info, Group>,
+    
+    #[account(
+        mut,
+        seeds = [b"debt", group.key().as_ref(), debt.debtor.as_ref()],
+        bump = debt.bump,
+        constraint = debt.version == Debt::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub debt: Account<'info, Debt>,
+    
+    #[account(
+        mut,
+        seeds = [b"escrow", group.key().as_ref()],
+        bump = escrow_vault.bump,
+        constraint = escrow_vault.version == EscrowVault::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub escrow_vault: Account<'info, EscrowVault>,
+    
+    #[account(
+        seeds = [b"platform-config"],
+        bump = platform_config.bump,
+        has_one = authority @ OsemeGroupError::Unauthorized,
+        constraint = platform_config.version == PlatformConfig::VERSION @ OsemeGroupError::AccountVersionMismatch
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    pub authority: Signer<'info>,
+}
+
+/// Give up on a finalized group's unrecoverable debt so its shorted members
+/// can settle and the group can be closed. The debtor's DefaulterRecord keeps
+/// the debt open: written off is not repaid.
+pub fn write_off_debt(ctx: Context<WriteOffDebt>) -> Result<()> {
+    let debt = &mut ctx.accounts.debt;
+    let escrow_vault = &mut ctx.accounts.escrow_vault;
+    let clock = Clock::get()?;
+    
+    if !ctx.accounts.group.load()?.is_closed_out()? {
+        return Err(OsemeGroupError::GroupNotClosedOut.into());
+    }
+    
+    let amount = debt.outstanding;
+    if amount == 0 {
+        return Err(OsemeGroupError::NoDebtOutstanding.into());
+    }
+    
+    debt.outstanding = 0;
+    escrow_vault.outstanding_debt = escrow_vault.outstanding_debt.saturating_sub(amount);
+    
+    emit!(DebtWrittenOff {
+        group: ctx.accounts.group.key(),
+        debtor: debt.debtor,
+        amount,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,94 @@
+//! Account layouts that predate the `version` byte.
+//!
+//! Kept only so `migrate_account` can read accounts created by earlier
+//! program deployments. Unversioned accounts are recognised by their
+//! allocated size, which is fixed per layout.
+
+use anchor_lang::prelude::*;
+use crate::state::{EscrowVault, Group, GroupModel, GroupStatus, Member, PlatformConfig};
+
+/// Original `PlatformConfig` layout
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct PlatformConfigV0 {
+    pub authority: Pubkey,
+    pub fee_bps: u16,
+    pub trust_subscription_price: u64,
+    pub super_trust_subscription_price: u64,
+    pub basic_group_limit: u8,
+    pub basic_per_creator_limit: u8,
+    pub grace_period_days: u8,
+    pub trust_penalty: i8,
+    pub trust_bonus: i8,
+    pub stake_bonus_bps: u16,
+    pub kyc_threshold: u64,
+    pub bonus_pool: u64,
+    pub usdc_mint: Pubkey,
+    pub bump: u8,
+}
+
+/// Original Borsh `Group` layout with a `Vec<Pubkey>` payout order
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct GroupV0 {
+    pub group_id: u64,
+    pub model: GroupModel,
+    pub creator: Pubkey,
+    pub member_cap: u8,
+    pub current_turn_index: u8,
+    pub cycle_days: u32,
+    pub payout_order: Vec<Pubkey>,
+    pub escrow_vault: Pubkey,
+    pub stake_vault: Option<Pubkey>,
+    pub status: GroupStatus,
+    pub total_members: u8,
+    pub current_turn_start: i64,
+    pub contribution_amount: u64,
+    pub total_pool: u64,
+    pub trust_score: u8,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+/// Original `Member` layout with a `Vec<bool>` of contributed turns
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct MemberV0 {
+    pub group: Pubkey,
+    pub user: Pubkey,
+    pub stake_amount: u64,
+    pub contributed_turns: Vec<bool>,
+    pub missed_count: u8,
+    pub trust_delta: i8,
+    pub join_timestamp: i64,
+    pub is_creator: bool,
+    pub bump: u8,
+}
+
+/// Original `EscrowVault` layout without stake tracking
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct EscrowVaultV0 {
+    pub group: Pubkey,
+    pub vault_authority: Pubkey,
+    pub current_balance: u64,
+    pub bump: u8,
+}
+
+// Unversioned accounts are detected by size, so no current layout may share it
+const _: () = assert!(PlatformConfig::LEN != PlatformConfigV0::LEN);
+const _: () = assert!(Group::LEN != GroupV0::LEN);
+const _: () = assert!(Member::LEN != MemberV0::LEN);
+const _: () = assert!(EscrowVault::LEN != EscrowVaultV0::LEN);
+
+impl PlatformConfigV0 {
+    pub const LEN: usize = 8 + 32 + 2 + 8 + 8 + 1 + 1 + 1 + 1 + 1 + 2 + 8 + 8 + 32 + 1;
+}
+
+impl GroupV0 {
+    pub const LEN: usize = 8 + 8 + 1 + 32 + 1 + 1 + 4 + 4 + 32 * 100 + 32 + 33 + 1 + 1 + 8 + 8 + 8 + 1 + 8 + 1;
+}
+
+impl MemberV0 {
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 4 + 100 / 8 + 1 + 1 + 8 + 1 + 1;
+}
+
+impl EscrowVaultV0 {
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 1;
+}
@@ -2,12 +2,15 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 use anchor_spl::associated_token::AssociatedToken;
 
-declare_id!("GrpABCDEFGHIJKLMNOPQRSTUVWXYZ123456789abcdef");
+declare_id!("HJoSULsRS49HQaLfDWvsM8qvsnhxHhQRsFTgQCRzGCvs");
 
 pub mod instructions;
 pub mod state;
 pub mod errors;
 pub mod events;
+pub mod legacy;
+pub mod trust;
+pub mod treasury;
 
 use instructions::*;
 use state::*;
@@ -22,20 +25,49 @@ pub mod oseme_group {
         instructions::init_platform(ctx, config)
     }
 
+    /// Create the tier parameters for a group model (admin only)
+    pub fn init_model_config(ctx: Context<InitModelConfig>, config: ModelConfig) -> Result<()> {
+        instructions::init_model_config(ctx, config)
+    }
+
+    /// Retune the tier parameters for a group model (admin only)
+    pub fn update_model_config(ctx: Context<UpdateModelConfig>, config: ModelConfig) -> Result<()> {
+        instructions::update_model_config(ctx, config)
+    }
+
+    /// Replace the discounted fee tiers for a group model (admin only)
+    pub fn set_fee_schedule(ctx: Context<SetFeeSchedule>, model: GroupModel, tiers: Vec<FeeTier>) -> Result<()> {
+        instructions::set_fee_schedule(ctx, model, tiers)
+    }
+
+    /// Create a promo code waiving part of the platform fee (admin only)
+    pub fn create_promo(
+        ctx: Context<CreatePromo>,
+        code: [u8; 16],
+        waiver_bps: u16,
+        max_uses: u32,
+        expires_at: i64,
+        per_wallet_limit: u16,
+    ) -> Result<()> {
+        instructions::create_promo(ctx, code, waiver_bps, max_uses, expires_at, per_wallet_limit)
+    }
+
     /// Create a new thrift group
     pub fn create_group(
         ctx: Context<CreateGroup>,
         model: GroupModel,
+        contribution_amount: u64,
         cycle_days: Option<u32>,
         member_cap: Option<u8>,
         payout_order: Option<Vec<Pubkey>>,
+        admit_past_defaulters: bool,
     ) -> Result<()> {
-        instructions::create_group(ctx, model, cycle_days, member_cap, payout_order)
+        instructions::create_group(ctx, model, contribution_amount, cycle_days, member_cap, payout_order, admit_past_defaulters)
     }
 
     /// Join an existing group
-    pub fn join_group(ctx: Context<JoinGroup>) -> Result<()> {
-        instructions::join_group(ctx)
+    pub fn join_group(ctx: Context<JoinGroup>, full_collateral: bool) -> Result<()> {
+        instructions::join_group(ctx, full_collateral)
     }
 
     /// Make a contribution to the current turn
@@ -44,8 +76,8 @@ pub mod oseme_group {
     }
 
     /// Release payout to the current turn recipient
-    pub fn release_payout(ctx: Context<ReleasePayout>) -> Result<()> {
-        instructions::release_payout(ctx)
+    pub fn release_payout(ctx: Context<ReleasePayout>, turn_index: u8) -> Result<()> {
+        instructions::release_payout(ctx, turn_index)
     }
 
     /// Finalize a completed group
@@ -53,13 +85,118 @@ pub mod oseme_group {
         instructions::finalize_group(ctx)
     }
 
-    /// Admin function to pause a group
-    pub fn pause_group(ctx: Context<PauseGroup>) -> Result<()> {
-        instructions::pause_group(ctx)
+    /// Pay completion bonuses to compliant members of a finalized group
+    pub fn distribute_bonuses<'info>(ctx: Context<'_, '_, '_, 'info, DistributeBonuses<'info>>) -> Result<()> {
+        instructions::distribute_bonuses(ctx)
+    }
+
+    /// Ask the platform authority to pause the creator's group
+    pub fn request_pause(ctx: Context<RequestPause>, reason: PauseReason) -> Result<()> {
+        instructions::request_pause(ctx, reason)
+    }
+
+    /// Admin function to pause a group, optionally confirming the creator's request
+    pub fn pause_group(ctx: Context<PauseGroup>, reason: PauseReason) -> Result<()> {
+        instructions::pause_group(ctx, reason)
     }
 
     /// Admin function to resume a paused group
     pub fn resume_group(ctx: Context<ResumeGroup>) -> Result<()> {
         instructions::resume_group(ctx)
     }
+
+    /// Admin function to pause every group on the platform
+    pub fn pause_platform(ctx: Context<PausePlatform>) -> Result<()> {
+        instructions::pause_platform(ctx)
+    }
+
+    /// Admin function to end a platform-wide pause
+    pub fn resume_platform(ctx: Context<ResumePlatform>) -> Result<()> {
+        instructions::resume_platform(ctx)
+    }
+
+    /// Cover a missed contribution from stake once the grace period is over
+    pub fn slash_member(ctx: Context<SlashMember>) -> Result<()> {
+        instructions::slash_member(ctx)
+    }
+
+    /// Record a member's result in a finalized group on their UserTrust
+    pub fn settle_member(ctx: Context<SettleMember>) -> Result<()> {
+        instructions::settle_member(ctx)
+    }
+
+    /// Lock part of the caller's stake to vouch for a newcomer joining the group, co-signed by the newcomer
+    pub fn vouch_for_member(ctx: Context<VouchForMember>, amount: u64) -> Result<()> {
+        instructions::vouch_for_member(ctx, amount)
+    }
+
+    /// Return what is left of a vouch once it can no longer be slashed
+    pub fn release_vouch(ctx: Context<ReleaseVouch>) -> Result<()> {
+        instructions::release_vouch(ctx)
+    }
+
+    /// Name a guarantor who is liable for a member's missed contributions
+    pub fn add_guarantor(ctx: Context<AddGuarantor>, allowance: u64) -> Result<()> {
+        instructions::add_guarantor(ctx, allowance)
+    }
+
+    /// End a guarantor's liability after the currently open turn
+    pub fn revoke_guarantor(ctx: Context<RevokeGuarantor>) -> Result<()> {
+        instructions::revoke_guarantor(ctx)
+    }
+
+    /// Pay down a debt left by defaulting after receiving a payout
+    pub fn repay_debt(ctx: Context<RepayDebt>, amount: u64) -> Result<()> {
+        instructions::repay_debt(ctx, amount)
+    }
+
+    /// Withdraw debt repayments owed to a shorted member
+    pub fn claim_recovery(ctx: Context<ClaimRecovery>) -> Result<()> {
+        instructions::claim_recovery(ctx)
+    }
+
+    /// Platform authority: stop expecting an unrecoverable debt in a finalized group
+    pub fn write_off_debt(ctx: Context<WriteOffDebt>) -> Result<()> {
+        instructions::write_off_debt(ctx)
+    }
+
+    /// Draw a full-collateral member's contribution for the open turn
+    pub fn draw_collateral(ctx: Context<DrawCollateral>) -> Result<()> {
+        instructions::draw_collateral(ctx)
+    }
+
+    /// Check the escrow token balance against the tracked balances, pausing the group on a shortfall
+    pub fn reconcile_escrow(ctx: Context<ReconcileEscrow>) -> Result<()> {
+        instructions::reconcile_escrow(ctx)
+    }
+
+    /// Withdraw stake after the group is finalized
+    pub fn withdraw_stake(ctx: Context<WithdrawStake>) -> Result<()> {
+        instructions::withdraw_stake(ctx)
+    }
+
+    /// Close a member account and return its rent to the member
+    pub fn close_member(ctx: Context<CloseMember>) -> Result<()> {
+        instructions::close_member(ctx)
+    }
+
+    /// Close a finished group, its escrow accounts, and return rent to the creator
+    pub fn close_group(ctx: Context<CloseGroup>) -> Result<()> {
+        instructions::close_group(ctx)
+    }
+
+    /// View: current turn, deadlines and recipients of a group
+    pub fn get_group_status(ctx: Context<GetGroupStatus>) -> Result<GroupStatusView> {
+        instructions::get_group_status(ctx)
+    }
+
+    /// View: what a member owes for the current turn
+    pub fn get_member_obligations(ctx: Context<GetMemberObligations>) -> Result<MemberObligationsView> {
+        instructions::get_member_obligations(ctx)
+    }
+
+    /// Upgrade an account created by an earlier program version to the current layout
+    pub fn migrate_account(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account(ctx)
+    }
 }
\ No newline at end of file
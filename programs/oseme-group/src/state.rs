@@ -1,10 +1,16 @@
 use anchor_lang::prelude::*;
+use crate::errors::OsemeGroupError;
+use crate::trust::TrustStanding;
+use oseme_math::{Rounding, UsdcAmount, BPS_DENOMINATOR};
+use oseme_trust::state::TrustParams;
+use oseme_treasury::state::{RebateParams, SubscriptionTier};
 
 /// Platform configuration account
 #[account]
 pub struct PlatformConfig {
+    pub version: u8,                     // Layout version, see PlatformConfig::VERSION
     pub authority: Pubkey,
-    pub fee_bps: u16,                    // Platform fee in basis points
+    pub fee_bps: u16,                    // Default platform fee; the charged fee comes from ModelConfig
     pub trust_subscription_price: u64,   // Trust model subscription in USDC
     pub super_trust_subscription_price: u64, // Super-Trust model subscription in USDC
     pub basic_group_limit: u8,           // Max active Basic groups globally
@@ -14,73 +20,284 @@ pub struct PlatformConfig {
     pub trust_bonus: i8,                 // Trust score bonus for completion (default +2)
     pub stake_bonus_bps: u16,            // Stake bonus in basis points for compliant members
     pub kyc_threshold: u64,              // KYC required above this USDC amount
-    pub bonus_pool: u64,                 // Platform bonus pool for stake bonuses
+    pub bonus_pool: u64,                 // Unused; the bonus pool is held by oseme-treasury
     pub usdc_mint: Pubkey,               // USDC mint address
     pub bump: u8,
+    pub trust_decay_period_days: u16,    // UserTrust moves one step toward neutral per period (0 disables)
+    pub trust_decay_step: u8,            // Points per decay period
+    pub trust_recovery_groups: u8,       // Clean completions to win back default penalties (0 disables)
+    pub vouch_bonus: i8,                 // Trust score bonus for vouching for a clean newcomer
+    pub rebate_min_trust_score: u8,      // Group trust score needed for a subscription rebate
+    pub rebate_bps: u16,                 // Share of the creator's subscription rebated (0 disables)
+    pub paused_at: i64,                  // Start of the current platform-wide pause, 0 when running
+    pub total_paused_secs: i64,          // Length of all finished platform-wide pauses
 }
 
-/// Group account
+/// Per-model tier parameters, managed by the platform authority
 #[account]
-pub struct Group {
-    pub group_id: u64,
+pub struct ModelConfig {
+    pub version: u8,                     // Layout version, see ModelConfig::VERSION
     pub model: GroupModel,
-    pub creator: Pubkey,
+    pub member_cap: u8,                  // Max members per group
+    pub min_cycle_days: u32,
+    pub max_cycle_days: u32,
+    pub default_cycle_days: u32,         // Used when the creator does not choose
+    pub min_contribution: u64,           // USDC per contribution
+    pub max_contribution: u64,
+    pub stake_ratio_bps: u16,            // Member stake as bps of contribution_amount
+    pub fee_bps: u16,                    // Platform fee on payouts
+    pub creator_share_bps: u16,          // Creator's share of the platform fee
+    pub bump: u8,
+    pub min_member_score: u16,           // UserTrust score required to join
+    pub min_creator_score: u16,          // UserTrust score required to create
+    pub min_creator_groups_completed: u32, // Finished memberships required to create
+    pub exposure_stake_bps: u16,         // Extra stake per contribution still owed after the member's payout
+}
+
+/// Group account
+///
+/// Zero-copy so that a 100-member Super-Trust group can be loaded without
+/// Borsh-deserializing the whole payout order on every instruction.
+#[account(zero_copy)]
+pub struct Group {
+    pub version: u8,                     // Layout version, see Group::VERSION
+    pub model: u8,                       // GroupModel as u8
+    pub status: u8,                      // GroupStatus as u8
     pub member_cap: u8,
     pub current_turn_index: u8,
-    pub cycle_days: u32,                 // Days per turn (Basic: 7, configurable for Trust/Super-Trust)
-    pub payout_order: Vec<Pubkey>,       // Immutable after first contribution
-    pub escrow_vault: Pubkey,            // Escrow PDA for contributions
-    pub stake_vault: Option<Pubkey>,     // Stake PDA for Trust/Super-Trust models
-    pub status: GroupStatus,
     pub total_members: u8,
+    pub payout_order_len: u8,            // Number of filled entries in payout_order
+    pub trust_score: u8,                 // Group trust score (starts at 100)
+    pub group_id: u64,
     pub current_turn_start: i64,         // Unix timestamp
     pub contribution_amount: u64,        // USDC amount per contribution
     pub total_pool: u64,                 // Total USDC pool size
-    pub trust_score: u8,                 // Group trust score (starts at 100)
     pub created_at: i64,
+    pub creator: Pubkey,
+    pub escrow_vault: Pubkey,            // Escrow PDA for contributions
+    pub stake_vault: Pubkey,             // Stake holder for Trust/Super-Trust models (default for Basic)
+    pub payout_order: [Pubkey; 100],     // Immutable after first contribution
+    pub turn_contributions: [[u8; 13]; 100], // Per-turn bitmap of paid payout positions
+    pub cycle_days: u32,                 // Days per turn (Basic: 7, configurable for Trust/Super-Trust)
     pub bump: u8,
+    pub admit_past_defaulters: u8,       // Non-zero admits wallets whose defaults are resolved
+    pub default_count: u8,               // Slashes in this group, saturating
+    pub pause_reason: u8,                // PauseReason as u8 while paused
+    pub reserved: [u8; 4],               // Keeps the layout 8-byte aligned
+    pub turn_shortfall: u64,             // Slashed amount of the current turn not covered by stake
+    pub promo: Pubkey,                   // Promo attached at creation (default when none)
+    pub pause_offset: i64,               // PlatformConfig::paused_secs when the current turn started
+    pub paused_at: i64,                  // When the group was paused, 0 unless paused
 }
 
 /// Member account (per group membership)
 #[account]
 pub struct Member {
+    pub version: u8,                     // Layout version, see Member::VERSION
     pub group: Pubkey,
     pub user: Pubkey,
     pub stake_amount: u64,               // Staked amount for Trust/Super-Trust
-    pub contributed_turns: Vec<bool>,    // Bitmap of completed contributions
+    pub contributed_turns: [u8; 13],     // Bitmap of completed contributions
+    pub payout_position: u8,             // Index in Group::payout_order, also the member's bit
     pub missed_count: u8,                // Number of missed payments
     pub trust_delta: i8,                 // Trust score change for this group
     pub join_timestamp: i64,
     pub is_creator: bool,
     pub bump: u8,
+    pub trust_settled_at: i64,           // When completion was recorded in oseme-trust (0 = pending)
+    pub shorted_amount: u64,             // Payout missing because of uncovered defaults
+    pub recovery_checkpoint: u128,       // EscrowVault::recovery_per_shorted last settled at
+    pub recovery_claimable: u64,         // Debt repayments owed to this member
+    pub collateral_balance: u64,         // Full-collateral deposit not yet drawn
+    pub bonus_settled: bool,             // Completion bonus paid (or forfeited)
 }
 
 /// Escrow vault for group contributions
 #[account]
 pub struct EscrowVault {
+    pub version: u8,                     // Layout version, see EscrowVault::VERSION
     pub group: Pubkey,
     pub vault_authority: Pubkey,
     pub current_balance: u64,
+    pub stake_balance: u64,              // Member stakes held alongside contributions
+    pub bump: u8,
+    pub recovery_balance: u64,           // Debt repayments not yet claimed by shorted members
+    pub total_shorted: u64,              // Sum of Member::shorted_amount
+    pub recovery_per_shorted: u128,      // Repaid per shorted unit, scaled by RECOVERY_SCALE
+    pub outstanding_debt: u64,           // Sum of Debt::outstanding
+    pub collateral_balance: u64,         // Sum of Member::collateral_balance
+    pub compliant_stake: u64,            // Stake of members without a missed turn; once finalized, of those not yet paid a bonus
+    pub bonus_reserved: u64,             // Bonus pool USDC not yet paid to compliant members
+}
+
+/// Platform-wide default history of a wallet, consulted when joining a group
+#[account]
+pub struct DefaulterRecord {
+    pub version: u8,                     // Layout version, see DefaulterRecord::VERSION
+    pub user: Pubkey,
+    pub defaults: u32,                   // Slashed turns across all groups
+    pub open_debts: u16,                 // Debt accounts with an outstanding balance
+    pub outstanding_debt: u64,           // Sum of those balances
+    pub last_default_at: i64,
+    pub bump: u8,
+}
+
+/// Unpaid contributions of a member who defaulted after receiving their payout
+#[account]
+pub struct Debt {
+    pub version: u8,                     // Layout version, see Debt::VERSION
+    pub group: Pubkey,
+    pub debtor: Pubkey,
+    pub outstanding: u64,
+    pub repaid: u64,
+    pub defaulted_turns: u8,             // Slashed turns that added to the debt
+    pub created_at: i64,
+    pub last_repaid_at: i64,
+    pub bump: u8,
+}
+
+/// Stake an established member has locked to vouch for a newcomer
+#[account]
+pub struct Vouch {
+    pub version: u8,                     // Layout version, see Vouch::VERSION
+    pub group: Pubkey,
+    pub voucher: Pubkey,
+    pub member: Pubkey,                  // Wallet being vouched for
+    pub amount: u64,                     // Locked stake still at risk
+    pub slashed_amount: u64,             // Taken to cover the member's missed contributions
+    pub joined_at: i64,                  // When the member joined on this vouch (0 until then)
+    pub bump: u8,
+}
+
+/// Wallet that co-signed a membership and is liable for its missed contributions
+#[account]
+pub struct Guarantor {
+    pub version: u8,                     // Layout version, see Guarantor::VERSION
+    pub group: Pubkey,
+    pub member: Pubkey,                  // Guaranteed wallet
+    pub guarantor: Pubkey,
+    pub token_account: Pubkey,           // Guarantor's USDC account, delegated to the escrow vault
+    pub allowance: u64,                  // Delegated when the guarantee was given
+    pub pulled_amount: u64,              // Taken to cover missed contributions
+    pub last_liable_turn: u8,            // Guarantor::OPEN_ENDED until revoked
+    pub bump: u8,
+}
+
+/// A creator's request for the platform authority to pause their group
+#[account]
+pub struct PauseRequest {
+    pub version: u8,                     // Layout version, see PauseRequest::VERSION
+    pub group: Pubkey,
+    pub creator: Pubkey,
+    pub reason: PauseReason,
+    pub requested_at: i64,
+    pub bump: u8,
+}
+
+/// Discounted fee tiers for a model, managed by the platform authority
+#[account]
+pub struct FeeSchedule {
+    pub version: u8,                     // Layout version, see FeeSchedule::VERSION
+    pub model: GroupModel,
+    pub tier_count: u8,                  // Number of filled entries in tiers
+    pub tiers: [FeeTier; FeeSchedule::MAX_TIERS],
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+/// Fee charged once a group and its creator meet both thresholds
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FeeTier {
+    pub min_trust_score: u8,             // Group trust score at payout
+    pub min_creator_volume: u64,         // Creator's lifetime payout volume in USDC
+    pub fee_bps: u16,
+}
+
+/// Promotional fee waiver created by the platform authority
+#[account]
+pub struct Promo {
+    pub version: u8,                     // Layout version, see Promo::VERSION
+    pub code: [u8; 16],                  // Promo code, right-padded with zeros
+    pub waiver_bps: u16,                 // Share of the platform fee waived on each payout
+    pub max_uses: u32,                   // Waived payouts across all groups
+    pub uses_remaining: u32,
+    pub expires_at: i64,                 // No groups can be attached afterwards
+    pub per_wallet_limit: u16,           // Groups each creator may attach it to, 0 for no limit
+    pub groups_attached: u32,
+    pub total_waived: u64,               // USDC of platform fees waived
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+/// A creator's redemptions of a promo, kept for reconciliation
+#[account]
+pub struct PromoRedemption {
+    pub version: u8,                     // Layout version, see PromoRedemption::VERSION
+    pub promo: Pubkey,
+    pub creator: Pubkey,
+    pub groups_attached: u16,
+    pub payouts_waived: u32,
+    pub total_waived: u64,               // USDC of platform fees waived
+    pub last_redeemed_at: i64,
+    pub bump: u8,
+}
+
+/// Lifetime payout volume of a creator's groups, consulted by fee schedules
+#[account]
+pub struct CreatorVolume {
+    pub version: u8,                     // Layout version, see CreatorVolume::VERSION
+    pub creator: Pubkey,
+    pub lifetime_volume: u64,            // Gross USDC paid out across all groups
+    pub payouts: u32,
+    pub last_payout_at: i64,
     pub bump: u8,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
 pub enum GroupModel {
     Basic,
     Trust,
     SuperTrust,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+impl GroupModel {
+    /// Subscription tier a creator needs to start a group of this model
+    pub fn subscription_tier(&self) -> Option<SubscriptionTier> {
+        match self {
+            GroupModel::Basic => None,
+            GroupModel::Trust => Some(SubscriptionTier::Trust),
+            GroupModel::SuperTrust => Some(SubscriptionTier::SuperTrust),
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
 pub enum GroupStatus {
     Active,
     Paused,
     Completed,
     Cancelled,
+    Finalized,
+}
+
+/// Why a group was paused
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum PauseReason {
+    Maintenance,
+    Dispute,
+    SuspectedFraud,
+    EscrowDiscrepancy,                   // Set by reconcile_escrow
+    CreatorRequest,
 }
 
 impl PlatformConfig {
+    pub const VERSION: u8 = 5;
+
     pub const LEN: usize = 8 + // discriminator
+        1 + // version
         32 + // authority
         2 + // fee_bps
         8 + // trust_subscription_price
@@ -94,51 +311,697 @@ impl PlatformConfig {
         8 + // kyc_threshold
         8 + // bonus_pool
         32 + // usdc_mint
-        1; // bump
+        1 + // bump
+        2 + // trust_decay_period_days
+        1 + // trust_decay_step
+        1 + // trust_recovery_groups
+        1 + // vouch_bonus
+        1 + // rebate_min_trust_score
+        2 + // rebate_bps
+        8 + // paused_at
+        8; // total_paused_secs
+
+    /// Decay and recovery schedule passed to oseme-trust
+    pub fn trust_params(&self) -> TrustParams {
+        TrustParams {
+            decay_period_days: self.trust_decay_period_days,
+            decay_step: self.trust_decay_step,
+            recovery_groups: self.trust_recovery_groups,
+        }
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused_at != 0
+    }
+
+    /// Time the platform has spent paused, including a pause still running
+    pub fn paused_secs(&self, now: i64) -> i64 {
+        if self.is_paused() {
+            self.total_paused_secs + (now - self.paused_at).max(0)
+        } else {
+            self.total_paused_secs
+        }
+    }
+
+    /// Rebate policy passed to oseme-treasury at finalization
+    pub fn rebate_params(&self) -> RebateParams {
+        RebateParams {
+            min_trust_score: self.rebate_min_trust_score,
+            rebate_bps: self.rebate_bps,
+        }
+    }
 }
 
-impl Group {
-    pub const MAX_MEMBERS: usize = 100; // Super-Trust max
-    
+impl TryFrom<u8> for GroupModel {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self> {
+        match value {
+            0 => Ok(GroupModel::Basic),
+            1 => Ok(GroupModel::Trust),
+            2 => Ok(GroupModel::SuperTrust),
+            _ => Err(OsemeGroupError::InvalidGroupModel.into()),
+        }
+    }
+}
+
+impl TryFrom<u8> for GroupStatus {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self> {
+        match value {
+            0 => Ok(GroupStatus::Active),
+            1 => Ok(GroupStatus::Paused),
+            2 => Ok(GroupStatus::Completed),
+            3 => Ok(GroupStatus::Cancelled),
+            4 => Ok(GroupStatus::Finalized),
+            _ => Err(OsemeGroupError::InvalidGroupStatus.into()),
+        }
+    }
+}
+
+impl ModelConfig {
+    pub const VERSION: u8 = 3;
+
     pub const LEN: usize = 8 + // discriminator
-        8 + // group_id
+        1 + // version
         1 + // model
-        32 + // creator
         1 + // member_cap
-        1 + // current_turn_index
-        4 + // cycle_days
-        4 + 32 * Self::MAX_MEMBERS + // payout_order (Vec<Pubkey>)
-        32 + // escrow_vault
-        33 + // stake_vault (Option<Pubkey>)
-        1 + // status
-        1 + // total_members
-        8 + // current_turn_start
-        8 + // contribution_amount
-        8 + // total_pool
-        1 + // trust_score
-        8 + // created_at
-        1; // bump
+        4 + // min_cycle_days
+        4 + // max_cycle_days
+        4 + // default_cycle_days
+        8 + // min_contribution
+        8 + // max_contribution
+        2 + // stake_ratio_bps
+        2 + // fee_bps
+        2 + // creator_share_bps
+        1 + // bump
+        2 + // min_member_score
+        2 + // min_creator_score
+        4 + // min_creator_groups_completed
+        2; // exposure_stake_bps
+
+    pub fn validate(&self) -> Result<()> {
+        let valid = self.member_cap > 0
+            && self.member_cap as usize <= Group::MAX_MEMBERS
+            && self.min_cycle_days > 0
+            && self.min_cycle_days <= self.default_cycle_days
+            && self.default_cycle_days <= self.max_cycle_days
+            && self.min_contribution > 0
+            && self.min_contribution <= self.max_contribution
+            && self.fee_bps <= 10_000
+            && self.creator_share_bps <= 10_000
+            && self.exposure_stake_bps <= 10_000;
+
+        if !valid {
+            return Err(OsemeGroupError::InvalidModelConfig.into());
+        }
+        Ok(())
+    }
+
+    pub fn admits_member(&self, standing: &TrustStanding) -> bool {
+        standing.score >= self.min_member_score
+    }
+
+    pub fn admits_creator(&self, standing: &TrustStanding) -> bool {
+        standing.score >= self.min_creator_score
+            && standing.groups_completed >= self.min_creator_groups_completed
+    }
+
+    /// Stake required from a non-creator member: a flat part plus a part
+    /// for every contribution they will still owe after being paid out, so
+    /// early recipients stake more and the excess is released as they pay
+    pub fn stake_for(&self, contribution_amount: u64, owed_after_payout: u8) -> Result<u64> {
+        let stake_bps = self.stake_ratio_bps as u64
+            + owed_after_payout as u64 * self.exposure_stake_bps as u64;
+
+        UsdcAmount::new(contribution_amount)
+            .mul_div(stake_bps, BPS_DENOMINATOR as u64, Rounding::Down)
+            .map(UsdcAmount::units)
+            .ok_or(OsemeGroupError::UsdcCalculationError.into())
+    }
+}
+
+impl Group {
+    pub const MAX_MEMBERS: usize = 100; // Super-Trust max
+    pub const BITMAP_BYTES: usize = Self::MAX_MEMBERS.div_ceil(8);
+    pub const VERSION: u8 = 5;
+    pub const PAUSE_OFFSET_VERSION: u8 = 4; // First layout with pause_offset
+    pub const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+    pub const LEN: usize = 8 + std::mem::size_of::<Group>();
+
+    pub fn model(&self) -> Result<GroupModel> {
+        GroupModel::try_from(self.model)
+    }
+
+    pub fn status(&self) -> Result<GroupStatus> {
+        GroupStatus::try_from(self.status)
+    }
+
+    pub fn set_status(&mut self, status: GroupStatus) {
+        self.status = status as u8;
+    }
+
+    /// Filled part of the payout order
+    pub fn payout_order(&self) -> &[Pubkey] {
+        &self.payout_order[..self.payout_order_len as usize]
+    }
+
+    pub fn payout_position(&self, user: &Pubkey) -> Option<u8> {
+        self.payout_order()
+            .iter()
+            .position(|key| key == user)
+            .map(|position| position as u8)
+    }
+
+    pub fn current_recipient(&self) -> Option<Pubkey> {
+        self.payout_order().get(self.current_turn_index as usize).copied()
+    }
+
+    pub fn has_contributed(&self, turn_index: u8, position: u8) -> bool {
+        bitmap_get(&self.turn_contributions[turn_index as usize], position)
+    }
+
+    pub fn mark_contributed(&mut self, turn_index: u8, position: u8) {
+        bitmap_set(&mut self.turn_contributions[turn_index as usize], position);
+    }
+
+    /// Pause the group, folding platform pauses so far into the turn start
+    /// so that `resume` only has to account for the time since
+    pub fn pause(&mut self, now: i64, reason: PauseReason, platform_paused_secs: i64) {
+        self.current_turn_start = self.turn_start(platform_paused_secs);
+        self.pause_offset = platform_paused_secs;
+        self.set_status(GroupStatus::Paused);
+        self.paused_at = now;
+        self.pause_reason = reason as u8;
+    }
+
+    /// Reactivate a paused group and move the turn start forward by the
+    /// time it was paused. Returns that time.
+    pub fn resume(&mut self, now: i64, platform_paused_secs: i64) -> i64 {
+        // Groups paused before pauses were timestamped get no extension
+        let mut paused_secs = 0;
+        if self.paused_at > 0 {
+            paused_secs = (now - self.paused_at).max(0);
+            // A platform pause during the group pause is already covered by it
+            self.current_turn_start += paused_secs;
+            self.pause_offset = platform_paused_secs;
+        }
+        self.set_status(GroupStatus::Active);
+        self.paused_at = 0;
+        self.pause_reason = 0;
+        paused_secs
+    }
+
+    /// Begin a new turn, remembering how long the platform had been paused
+    pub fn start_turn(&mut self, now: i64, platform_paused_secs: i64) {
+        self.current_turn_start = now;
+        self.pause_offset = platform_paused_secs;
+    }
+
+    /// Start of the current turn, pushed back by platform-wide pauses since
+    /// it began
+    pub fn turn_start(&self, platform_paused_secs: i64) -> i64 {
+        self.current_turn_start + (platform_paused_secs - self.pause_offset).max(0)
+    }
+
+    /// End of the regular contribution window for the current turn
+    pub fn turn_deadline(&self, platform_paused_secs: i64) -> i64 {
+        self.turn_start(platform_paused_secs) + self.cycle_days as i64 * Self::SECONDS_PER_DAY
+    }
+
+    /// Last moment a late contribution is accepted before the member can be slashed
+    pub fn grace_deadline(&self, platform_paused_secs: i64, grace_period_days: u8) -> i64 {
+        self.turn_deadline(platform_paused_secs) + grace_period_days as i64 * Self::SECONDS_PER_DAY
+    }
+
+    pub fn in_grace(&self, now: i64, platform_paused_secs: i64, grace_period_days: u8) -> bool {
+        now > self.turn_deadline(platform_paused_secs)
+            && now <= self.grace_deadline(platform_paused_secs, grace_period_days)
+    }
+
+    /// Recipient of the turn after the current one
+    pub fn next_recipient(&self) -> Option<Pubkey> {
+        self.payout_order().get(self.current_turn_index as usize + 1).copied()
+    }
+
+    /// The first contribution fixes the payout order
+    pub fn payout_order_fixed(&self) -> bool {
+        self.current_turn_index > 0 || self.paid_count(0) > 0
+    }
+
+    /// Turns the rotation runs: the payout order once it is fixed, the
+    /// member cap until then since the group may still fill up
+    pub fn turn_count(&self) -> u8 {
+        if self.payout_order_fixed() {
+            self.payout_order_len
+        } else {
+            self.member_cap
+        }
+    }
+
+    /// Contributions a member at `position` will still owe after their payout,
+    /// counting turns from `from_turn` up to `turn_count`
+    pub fn owed_after_payout(position: u8, from_turn: u8, turn_count: u8) -> u8 {
+        turn_count.saturating_sub(from_turn.max(position + 1))
+    }
+
+    /// Whether wallets with past defaults but no unresolved debt may join
+    pub fn admits_past_defaulters(&self) -> bool {
+        self.admit_past_defaulters != 0
+    }
+
+    /// Finalized groups no longer hold member obligations
+    pub fn is_closed_out(&self) -> Result<bool> {
+        Ok(self.status()? == GroupStatus::Finalized)
+    }
+
+    /// Contributions the escrow must be holding for the open turn: every
+    /// member marked as paid less what slashing could not cover.
+    /// `None` when no turn is open.
+    pub fn open_turn_pool(&self) -> Result<Option<u64>> {
+        if !matches!(self.status()?, GroupStatus::Active | GroupStatus::Paused)
+            || self.current_turn_index >= self.total_members
+        {
+            return Ok(None);
+        }
+        self.contribution_amount
+            .checked_mul(self.paid_count(self.current_turn_index) as u64)
+            .and_then(|paid| paid.checked_sub(self.turn_shortfall))
+            .map(Some)
+            .ok_or(OsemeGroupError::UsdcCalculationError.into())
+    }
+
+    /// Number of members who have paid into the given turn
+    pub fn paid_count(&self, turn_index: u8) -> u8 {
+        self.turn_contributions[turn_index as usize]
+            .iter()
+            .map(|byte| byte.count_ones() as u8)
+            .sum()
+    }
 }
 
 impl Member {
-    pub const MAX_TURNS: usize = 100; // Super-Trust max members
-    
+    pub const VERSION: u8 = 5;
+
     pub const LEN: usize = 8 + // discriminator
+        1 + // version
         32 + // group
         32 + // user
         8 + // stake_amount
-        4 + Self::MAX_TURNS / 8 + // contributed_turns (bitmap)
+        Group::BITMAP_BYTES + // contributed_turns (bitmap)
+        1 + // payout_position
         1 + // missed_count
         1 + // trust_delta
         8 + // join_timestamp
         1 + // is_creator
+        1 + // bump
+        8 + // trust_settled_at
+        8 + // shorted_amount
+        16 + // recovery_checkpoint
+        8 + // recovery_claimable
+        8 + // collateral_balance
+        1; // bonus_settled
+
+    /// Members who never missed a turn share the completion bonus once
+    pub fn earns_bonus(&self) -> bool {
+        self.missed_count == 0 && !self.bonus_settled
+    }
+
+    /// Full-collateral members have the turn's contribution drawn for them
+    pub fn collateral_covers(&self, contribution_amount: u64) -> bool {
+        self.collateral_balance >= contribution_amount
+    }
+
+    pub fn has_contributed(&self, turn_index: u8) -> bool {
+        bitmap_get(&self.contributed_turns, turn_index)
+    }
+
+    pub fn mark_contributed(&mut self, turn_index: u8) {
+        bitmap_set(&mut self.contributed_turns, turn_index);
+    }
+
+    /// Move repayments distributed since the last checkpoint into `recovery_claimable`
+    pub fn settle_recovery(&mut self, recovery_per_shorted: u128) {
+        let pending = self.shorted_amount as u128
+            * (recovery_per_shorted - self.recovery_checkpoint)
+            / EscrowVault::RECOVERY_SCALE;
+        self.recovery_claimable += pending as u64;
+        self.recovery_checkpoint = recovery_per_shorted;
+    }
+
+    /// Turns before `turn_count` this member has not paid
+    pub fn unpaid_turns(&self, turn_count: u8) -> u8 {
+        (0..turn_count).filter(|turn_index| !self.has_contributed(*turn_index)).count() as u8
+    }
+}
+
+impl Vouch {
+    pub const VERSION: u8 = 1;
+
+    pub const LEN: usize = 8 + // discriminator
+        1 + // version
+        32 + // group
+        32 + // voucher
+        32 + // member
+        8 + // amount
+        8 + // slashed_amount
+        8 + // joined_at
         1; // bump
+
+    /// Read a vouch passed by address; `None` when nobody vouched
+    pub fn read(account: &AccountInfo) -> Result<Option<Self>> {
+        read_optional(account, |vouch: &Self| vouch.version == Self::VERSION)
+    }
+
+    pub fn write(&self, account: &AccountInfo) -> Result<()> {
+        write_optional(account, self)
+    }
+
+    /// Whether the vouch locks enough to replace the model's trust threshold
+    /// for a newcomer owing `required_stake`
+    pub fn covers(&self, required_stake: u64) -> bool {
+        self.amount > 0 && self.amount >= required_stake
+    }
+
+    /// Split what stake can cover of a missed contribution between the
+    /// member and the voucher, pro rata to what each has locked.
+    /// Returns `(member_part, voucher_part)`.
+    pub fn split_slash(&self, contribution_amount: u64, member_stake: u64) -> (u64, u64) {
+        let locked = member_stake as u128 + self.amount as u128;
+        if locked == 0 {
+            return (0, 0);
+        }
+        let covered = locked.min(contribution_amount as u128);
+        let voucher_part = (covered * self.amount as u128 / locked) as u64;
+        (covered as u64 - voucher_part, voucher_part)
+    }
+}
+
+impl Guarantor {
+    pub const VERSION: u8 = 1;
+    pub const OPEN_ENDED: u8 = u8::MAX;
+
+    pub const LEN: usize = 8 + // discriminator
+        1 + // version
+        32 + // group
+        32 + // member
+        32 + // guarantor
+        32 + // token_account
+        8 + // allowance
+        8 + // pulled_amount
+        1 + // last_liable_turn
+        1; // bump
+
+    /// Read a guarantee passed by address; `None` when there is none
+    pub fn read(account: &AccountInfo) -> Result<Option<Self>> {
+        read_optional(account, |guarantor: &Self| guarantor.version == Self::VERSION)
+    }
+
+    pub fn write(&self, account: &AccountInfo) -> Result<()> {
+        write_optional(account, self)
+    }
+
+    pub fn is_liable_for(&self, turn_index: u8) -> bool {
+        turn_index <= self.last_liable_turn
+    }
+}
+
+impl PauseRequest {
+    pub const VERSION: u8 = 1;
+
+    pub const LEN: usize = 8 + // discriminator
+        1 + // version
+        32 + // group
+        32 + // creator
+        1 + // reason
+        8 + // requested_at
+        1; // bump
+}
+
+impl FeeSchedule {
+    pub const VERSION: u8 = 1;
+    pub const MAX_TIERS: usize = 8;
+    /// Largest cut a tier may take off the model's fee
+    pub const MAX_DISCOUNT_BPS: u16 = 5_000;
+
+    pub const LEN: usize = 8 + // discriminator
+        1 + // version
+        1 + // model
+        1 + // tier_count
+        FeeTier::LEN * Self::MAX_TIERS + // tiers
+        8 + // updated_at
+        1; // bump
+
+    /// Read a model's schedule passed by address; `None` when the authority
+    /// has not set one
+    pub fn read(account: &AccountInfo) -> Result<Option<Self>> {
+        read_optional(account, |schedule: &Self| schedule.version == Self::VERSION)
+    }
+
+    pub fn set_tiers(&mut self, tiers: &[FeeTier]) -> Result<()> {
+        if tiers.len() > Self::MAX_TIERS || tiers.iter().any(|tier| tier.fee_bps > 10_000) {
+            return Err(OsemeGroupError::InvalidFeeSchedule.into());
+        }
+        self.tiers = [FeeTier::default(); Self::MAX_TIERS];
+        self.tiers[..tiers.len()].copy_from_slice(tiers);
+        self.tier_count = tiers.len() as u8;
+        Ok(())
+    }
+
+    pub fn tiers(&self) -> &[FeeTier] {
+        &self.tiers[..self.tier_count as usize]
+    }
+
+    /// Cheapest tier the group qualifies for, if it undercuts `base_bps`.
+    /// Creator volume also counts groups a creator filled with their own
+    /// wallets, so no tier charges less than `MAX_DISCOUNT_BPS` off the base.
+    /// Returns the tier index and the rate charged.
+    pub fn discount(&self, base_bps: u16, trust_score: u8, creator_volume: u64) -> Option<(u8, u16)> {
+        let floor_bps = base_bps - (base_bps as u32 * Self::MAX_DISCOUNT_BPS as u32 / 10_000) as u16;
+        self.tiers()
+            .iter()
+            .enumerate()
+            .filter(|(_, tier)| {
+                trust_score >= tier.min_trust_score && creator_volume >= tier.min_creator_volume
+            })
+            .map(|(index, tier)| (index as u8, tier.fee_bps.max(floor_bps)))
+            .filter(|(_, fee_bps)| *fee_bps < base_bps)
+            .min_by_key(|(_, fee_bps)| *fee_bps)
+    }
+}
+
+impl FeeTier {
+    pub const LEN: usize = 1 + // min_trust_score
+        8 + // min_creator_volume
+        2; // fee_bps
+}
+
+impl Promo {
+    pub const VERSION: u8 = 1;
+
+    pub const LEN: usize = 8 + // discriminator
+        1 + // version
+        16 + // code
+        2 + // waiver_bps
+        4 + // max_uses
+        4 + // uses_remaining
+        8 + // expires_at
+        2 + // per_wallet_limit
+        4 + // groups_attached
+        8 + // total_waived
+        8 + // created_at
+        1; // bump
+
+    /// Whether a group may still be created with this promo
+    pub fn is_attachable(&self, now: i64) -> bool {
+        now < self.expires_at && self.uses_remaining > 0
+    }
+
+    pub fn admits_wallet(&self, redemption: &PromoRedemption) -> bool {
+        self.per_wallet_limit == 0 || redemption.groups_attached < self.per_wallet_limit
+    }
+
+    /// Share of the platform fee waived on a payout, 0 once the uses run out
+    pub fn active_waiver_bps(&self) -> u16 {
+        if self.uses_remaining == 0 {
+            return 0;
+        }
+        self.waiver_bps
+    }
+}
+
+impl PromoRedemption {
+    pub const VERSION: u8 = 1;
+
+    pub const LEN: usize = 8 + // discriminator
+        1 + // version
+        32 + // promo
+        32 + // creator
+        2 + // groups_attached
+        4 + // payouts_waived
+        8 + // total_waived
+        8 + // last_redeemed_at
+        1; // bump
+}
+
+impl CreatorVolume {
+    pub const VERSION: u8 = 1;
+
+    pub const LEN: usize = 8 + // discriminator
+        1 + // version
+        32 + // creator
+        8 + // lifetime_volume
+        4 + // payouts
+        8 + // last_payout_at
+        1; // bump
+}
+
+/// Deserialize a PDA passed by address that may not have been created yet
+fn read_optional<T: AccountDeserialize>(
+    account: &AccountInfo,
+    is_current: impl Fn(&T) -> bool,
+) -> Result<Option<T>> {
+    if account.data_is_empty() {
+        return Ok(None);
+    }
+    if account.owner != &crate::ID {
+        return Err(ErrorCode::AccountOwnedByWrongProgram.into());
+    }
+
+    let value = T::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+    if !is_current(&value) {
+        return Err(OsemeGroupError::AccountVersionMismatch.into());
+    }
+    Ok(Some(value))
+}
+
+fn write_optional<T: AccountSerialize>(account: &AccountInfo, value: &T) -> Result<()> {
+    let mut data = account.try_borrow_mut_data()?;
+    let mut writer: &mut [u8] = &mut data;
+    value.try_serialize(&mut writer)
+}
+
+fn bitmap_get(bitmap: &[u8; Group::BITMAP_BYTES], index: u8) -> bool {
+    bitmap[index as usize / 8] & (1 << (index % 8)) != 0
+}
+
+fn bitmap_set(bitmap: &mut [u8; Group::BITMAP_BYTES], index: u8) {
+    bitmap[index as usize / 8] |= 1 << (index % 8);
 }
 
 impl EscrowVault {
+    pub const VERSION: u8 = 4;
+    pub const RECOVERY_SCALE: u128 = 1_000_000_000_000;
+
     pub const LEN: usize = 8 + // discriminator
+        1 + // version
         32 + // group
         32 + // vault_authority
         8 + // current_balance
+        8 + // stake_balance
+        1 + // bump
+        8 + // recovery_balance
+        8 + // total_shorted
+        16 + // recovery_per_shorted
+        8 + // outstanding_debt
+        8 + // collateral_balance
+        8 + // compliant_stake
+        8; // bonus_reserved
+
+    /// USDC the escrow still owes to members, creators or the recipient
+    pub fn tracked_balance(&self) -> Result<u64> {
+        [self.stake_balance, self.recovery_balance, self.collateral_balance, self.bonus_reserved]
+            .into_iter()
+            .try_fold(self.current_balance, u64::checked_add)
+            .ok_or(OsemeGroupError::UsdcCalculationError.into())
+    }
+
+    /// Tokens in the escrow beyond what it owes, left there by rounding
+    pub fn dust(&self, token_balance: u64) -> Result<u64> {
+        Ok(token_balance.saturating_sub(self.tracked_balance()?))
+    }
+
+    /// Full completion bonus for the compliant stake, before capping by the pool
+    pub fn bonus_owed(&self, stake_bonus_bps: u16) -> u64 {
+        UsdcAmount::new(self.compliant_stake)
+            .bps(stake_bonus_bps, Rounding::Down)
+            .map_or(0, UsdcAmount::units)
+    }
+
+    /// Share of the reserved bonus for `stake`, proportional to the stake
+    /// still unpaid, so the last member paid takes the rounding remainder.
+    /// Only valid once the group is finalized.
+    pub fn take_bonus(&mut self, stake: u64) -> u64 {
+        if self.compliant_stake == 0 {
+            return 0;
+        }
+        let stake = stake.min(self.compliant_stake);
+        let bonus = UsdcAmount::new(self.bonus_reserved)
+            .mul_div(stake, self.compliant_stake, Rounding::Down)
+            .map_or(0, UsdcAmount::units);
+        self.compliant_stake -= stake;
+        self.bonus_reserved -= bonus;
+        bonus
+    }
+
+    /// Spread a repayment over every shorted member, pro rata to how much
+    /// each was shorted
+    pub fn distribute_recovery(&mut self, amount: u64) -> Result<()> {
+        if self.total_shorted == 0 {
+            return Err(OsemeGroupError::NoShortedMembers.into());
+        }
+        self.recovery_per_shorted += amount as u128 * Self::RECOVERY_SCALE / self.total_shorted as u128;
+        self.recovery_balance = self.recovery_balance
+            .checked_add(amount)
+            .ok_or(OsemeGroupError::UsdcCalculationError)?;
+        Ok(())
+    }
+}
+
+impl DefaulterRecord {
+    pub const VERSION: u8 = 1;
+
+    pub const LEN: usize = 8 + // discriminator
+        1 + // version
+        32 + // user
+        4 + // defaults
+        2 + // open_debts
+        8 + // outstanding_debt
+        8 + // last_default_at
         1; // bump
+
+    /// Read a wallet's record passed by address; `None` for a clean wallet
+    pub fn read(account: &AccountInfo) -> Result<Option<Self>> {
+        read_optional(account, |record: &Self| record.version == Self::VERSION)
+    }
+
+    pub fn has_unresolved_debt(&self) -> bool {
+        self.open_debts > 0
+    }
+}
+
+impl Debt {
+    pub const VERSION: u8 = 1;
+
+    pub const LEN: usize = 8 + // discriminator
+        1 + // version
+        32 + // group
+        32 + // debtor
+        8 + // outstanding
+        8 + // repaid
+        1 + // defaulted_turns
+        8 + // created_at
+        8 + // last_repaid_at
+        1; // bump
+
+    /// Read a debt passed by address; `None` until the member first owes
+    pub fn read(account: &AccountInfo) -> Result<Option<Self>> {
+        read_optional(account, |debt: &Self| debt.version == Self::VERSION)
+    }
+
+    pub fn write(&self, account: &AccountInfo) -> Result<()> {
+        write_optional(account, self)
+    }
 }
\ No newline at end of file
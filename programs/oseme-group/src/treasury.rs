@@ -0,0 +1,189 @@
+use anchor_lang::prelude::*;
+use oseme_treasury::cpi::accounts::{CollectFees, CreditCreatorFees, ProcessRebate, ReleaseBonus};
+use oseme_treasury::state::{RebateParams, Subscription};
+
+pub use oseme_treasury::state::{BONUS_VAULT_SEED, FEE_VAULT_SEED, SUBSCRIPTION_SEED, TREASURY_SEED};
+
+/// Seed of the PDA that signs fee collection in oseme-treasury
+pub const TREASURY_AUTHORITY_SEED: &[u8] = b"treasury-authority";
+
+/// Accounts needed to credit the creator's share of a payout fee
+pub struct CreatorFeeCredit<'info> {
+    pub treasury_program: AccountInfo<'info>,
+    pub treasury: AccountInfo<'info>,
+    pub creator_revenue: AccountInfo<'info>,
+    pub creator: AccountInfo<'info>,
+    pub creator_vault: AccountInfo<'info>,
+    pub usdc_mint: AccountInfo<'info>,
+    pub escrow_token_account: AccountInfo<'info>,
+    pub escrow_vault: AccountInfo<'info>,
+    pub treasury_authority: AccountInfo<'info>,
+    pub payer: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub group: Pubkey,
+    pub escrow_bump: u8,
+    pub treasury_authority_bump: u8,
+}
+
+impl<'info> CreatorFeeCredit<'info> {
+    pub fn credit_creator_fees(&self, amount: u64) -> Result<()> {
+        let escrow_bump = [self.escrow_bump];
+        let escrow_seeds: &[&[u8]] = &[b"escrow", self.group.as_ref(), &escrow_bump];
+        let authority_bump = [self.treasury_authority_bump];
+        let authority_seeds: &[&[u8]] = &[TREASURY_AUTHORITY_SEED, &authority_bump];
+        let signer_seeds = &[escrow_seeds, authority_seeds];
+
+        oseme_treasury::cpi::credit_creator_fees(
+            CpiContext::new_with_signer(
+                self.treasury_program.clone(),
+                CreditCreatorFees {
+                    treasury: self.treasury.clone(),
+                    creator_revenue: self.creator_revenue.clone(),
+                    creator: self.creator.clone(),
+                    creator_vault: self.creator_vault.clone(),
+                    usdc_mint: self.usdc_mint.clone(),
+                    source: self.escrow_token_account.clone(),
+                    source_authority: self.escrow_vault.clone(),
+                    group_authority: self.treasury_authority.clone(),
+                    payer: self.payer.clone(),
+                    system_program: self.system_program.clone(),
+                    token_program: self.token_program.clone(),
+                },
+                signer_seeds,
+            ),
+            amount,
+        )
+    }
+}
+
+/// A creator's Subscription in oseme-treasury, `None` if they never paid
+pub fn load_subscription(subscription: &AccountInfo) -> Result<Option<Subscription>> {
+    if subscription.data_is_empty() {
+        return Ok(None);
+    }
+    if subscription.owner != &oseme_treasury::ID {
+        return Err(ErrorCode::AccountOwnedByWrongProgram.into());
+    }
+
+    let subscription = Subscription::try_deserialize(&mut &subscription.try_borrow_data()?[..])?;
+    Ok(Some(subscription))
+}
+
+/// Accounts needed to move a group's platform fee into the treasury
+pub struct FeeCollection<'info> {
+    pub treasury_program: AccountInfo<'info>,
+    pub treasury: AccountInfo<'info>,
+    pub fee_vault: AccountInfo<'info>,
+    pub escrow_token_account: AccountInfo<'info>,
+    pub escrow_vault: AccountInfo<'info>,
+    pub treasury_authority: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub group: Pubkey,
+    pub escrow_bump: u8,
+    pub treasury_authority_bump: u8,
+}
+
+impl<'info> FeeCollection<'info> {
+    /// The escrow vault signs for the token transfer, the treasury authority
+    /// proves the call comes from this program
+    pub fn collect_fees(&self, amount: u64) -> Result<()> {
+        let escrow_bump = [self.escrow_bump];
+        let escrow_seeds: &[&[u8]] = &[b"escrow", self.group.as_ref(), &escrow_bump];
+        let authority_bump = [self.treasury_authority_bump];
+        let authority_seeds: &[&[u8]] = &[TREASURY_AUTHORITY_SEED, &authority_bump];
+        let signer_seeds = &[escrow_seeds, authority_seeds];
+
+        oseme_treasury::cpi::collect_fees(
+            CpiContext::new_with_signer(
+                self.treasury_program.clone(),
+                CollectFees {
+                    treasury: self.treasury.clone(),
+                    fee_vault: self.fee_vault.clone(),
+                    source: self.escrow_token_account.clone(),
+                    source_authority: self.escrow_vault.clone(),
+                    group_authority: self.treasury_authority.clone(),
+                    token_program: self.token_program.clone(),
+                },
+                signer_seeds,
+            ),
+            amount,
+        )
+    }
+}
+
+/// Accounts needed to rebate part of a creator's subscription
+pub struct RebateRequest<'info> {
+    pub treasury_program: AccountInfo<'info>,
+    pub treasury: AccountInfo<'info>,
+    pub fee_vault: AccountInfo<'info>,
+    pub subscription: AccountInfo<'info>,
+    pub creator_token_account: AccountInfo<'info>,
+    pub group: AccountInfo<'info>,
+    pub treasury_authority: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub treasury_authority_bump: u8,
+    pub params: RebateParams,
+}
+
+impl<'info> RebateRequest<'info> {
+    /// oseme-treasury decides eligibility and the amount
+    pub fn process_rebate(&self, trust_score: u8, defaults: u8) -> Result<()> {
+        let bump = [self.treasury_authority_bump];
+        let seeds: &[&[u8]] = &[TREASURY_AUTHORITY_SEED, &bump];
+        let signer_seeds = &[seeds];
+
+        oseme_treasury::cpi::process_rebate(
+            CpiContext::new_with_signer(
+                self.treasury_program.clone(),
+                ProcessRebate {
+                    treasury: self.treasury.clone(),
+                    fee_vault: self.fee_vault.clone(),
+                    subscription: self.subscription.clone(),
+                    creator_token_account: self.creator_token_account.clone(),
+                    group: self.group.clone(),
+                    group_authority: self.treasury_authority.clone(),
+                    token_program: self.token_program.clone(),
+                },
+                signer_seeds,
+            ),
+            trust_score,
+            defaults,
+            self.params,
+        )
+    }
+}
+
+/// Accounts needed to move completion bonuses from the pool into a group escrow
+pub struct BonusRelease<'info> {
+    pub treasury_program: AccountInfo<'info>,
+    pub treasury: AccountInfo<'info>,
+    pub bonus_vault: AccountInfo<'info>,
+    pub escrow_token_account: AccountInfo<'info>,
+    pub treasury_authority: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub treasury_authority_bump: u8,
+}
+
+impl<'info> BonusRelease<'info> {
+    pub fn release_bonus(&self, amount: u64) -> Result<()> {
+        let bump = [self.treasury_authority_bump];
+        let seeds: &[&[u8]] = &[TREASURY_AUTHORITY_SEED, &bump];
+        let signer_seeds = &[seeds];
+
+        oseme_treasury::cpi::release_bonus(
+            CpiContext::new_with_signer(
+                self.treasury_program.clone(),
+                ReleaseBonus {
+                    treasury: self.treasury.clone(),
+                    bonus_vault: self.bonus_vault.clone(),
+                    destination: self.escrow_token_account.clone(),
+                    group_authority: self.treasury_authority.clone(),
+                    token_program: self.token_program.clone(),
+                },
+                signer_seeds,
+            ),
+            amount,
+        )
+    }
+}
@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+use oseme_trust::cpi::accounts::RecordTrustEvent;
+use oseme_trust::state::{TrustParams, UserTrust};
+
+/// Seed of the PDA that signs reputation updates in oseme-trust
+pub const TRUST_AUTHORITY_SEED: &[u8] = b"trust-authority";
+
+/// Seed of a wallet's UserTrust PDA in oseme-trust
+pub const USER_TRUST_SEED: &[u8] = b"user-trust";
+
+/// Cross-group reputation used to gate higher-tier groups
+pub struct TrustStanding {
+    pub score: u16,
+    pub groups_completed: u32,
+}
+
+impl TrustStanding {
+    /// Wallets without a UserTrust yet start from the initial score;
+    /// decay is applied as of `now`
+    pub fn load(user_trust: &AccountInfo, now: i64, params: &TrustParams) -> Result<Self> {
+        if user_trust.data_is_empty() {
+            return Ok(Self {
+                score: UserTrust::INITIAL_SCORE,
+                groups_completed: 0,
+            });
+        }
+        if user_trust.owner != &oseme_trust::ID {
+            return Err(ErrorCode::AccountOwnedByWrongProgram.into());
+        }
+
+        let user_trust = UserTrust::try_deserialize(&mut &user_trust.try_borrow_data()?[..])?;
+        Ok(Self {
+            score: user_trust.effective_score(now, params),
+            groups_completed: user_trust.groups_completed,
+        })
+    }
+}
+
+/// Accounts needed to update a wallet's cross-group reputation
+pub struct TrustUpdate<'info> {
+    pub trust_program: AccountInfo<'info>,
+    pub user_trust: AccountInfo<'info>,
+    pub user: AccountInfo<'info>,
+    pub trust_authority: AccountInfo<'info>,
+    pub payer: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    pub trust_authority_bump: u8,
+    pub params: TrustParams,
+}
+
+impl<'info> TrustUpdate<'info> {
+    pub fn record_contribution(&self) -> Result<()> {
+        let bump = [self.trust_authority_bump];
+        let seeds: &[&[u8]] = &[TRUST_AUTHORITY_SEED, &bump];
+        let signer_seeds = &[seeds];
+        oseme_trust::cpi::record_contribution(self.context(signer_seeds), self.params)
+    }
+
+    pub fn record_default(&self, penalty: i8) -> Result<()> {
+        let bump = [self.trust_authority_bump];
+        let seeds: &[&[u8]] = &[TRUST_AUTHORITY_SEED, &bump];
+        let signer_seeds = &[seeds];
+        oseme_trust::cpi::record_default(self.context(signer_seeds), penalty, self.params)
+    }
+
+    pub fn record_completion(&self, bonus: i8, clean: bool) -> Result<()> {
+        let bump = [self.trust_authority_bump];
+        let seeds: &[&[u8]] = &[TRUST_AUTHORITY_SEED, &bump];
+        let signer_seeds = &[seeds];
+        oseme_trust::cpi::record_completion(self.context(signer_seeds), bonus, clean, self.params)
+    }
+
+    pub fn record_vouch(&self, bonus: i8) -> Result<()> {
+        let bump = [self.trust_authority_bump];
+        let seeds: &[&[u8]] = &[TRUST_AUTHORITY_SEED, &bump];
+        let signer_seeds = &[seeds];
+        oseme_trust::cpi::record_vouch(self.context(signer_seeds), bonus, self.params)
+    }
+
+    fn context<'a, 'b, 'c>(
+        &self,
+        signer_seeds: &'a [&'b [&'c [u8]]],
+    ) -> CpiContext<'a, 'b, 'c, 'info, RecordTrustEvent<'info>> {
+        CpiContext::new_with_signer(
+            self.trust_program.clone(),
+            RecordTrustEvent {
+                user_trust: self.user_trust.clone(),
+                user: self.user.clone(),
+                group_authority: self.trust_authority.clone(),
+                payer: self.payer.clone(),
+                system_program: self.system_program.clone(),
+            },
+            signer_seeds,
+        )
+    }
+}
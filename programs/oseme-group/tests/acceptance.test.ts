@@ -123,7 +123,7 @@ describe("Oseme Acceptance Tests", () => {
                 );
 
                 await program.methods
-                    .createGroup({ basic: {} }, null, null, null)
+                    .createGroup({ basic: {} }, new anchor.BN(100 * 1e6), null, null, null, false)
                     .accounts({
                         group: basicGroup,
                         escrowVault,
@@ -159,7 +159,7 @@ describe("Oseme Acceptance Tests", () => {
                 );
 
                 await program.methods
-                    .createGroup({ basic: {} }, null, null, null)
+                    .createGroup({ basic: {} }, new anchor.BN(100 * 1e6), null, null, null, false)
                     .accounts({
                         group: sixthGroup,
                         platformConfig,
@@ -194,7 +194,7 @@ describe("Oseme Acceptance Tests", () => {
                 );
 
                 await program.methods
-                    .createGroup({ basic: {} }, null, null, null)
+                    .createGroup({ basic: {} }, new anchor.BN(100 * 1e6), null, null, null, false)
                     .accounts({
                         group: secondGroup,
                         platformConfig,
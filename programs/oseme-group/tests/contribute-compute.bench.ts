@@ -0,0 +1,340 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { OsemeGroup } from "../target/types/oseme_group";
+import { OsemeTreasury } from "../target/types/oseme_treasury";
+import { expect } from "chai";
+import {
+    PublicKey,
+    Keypair,
+    SystemProgram,
+    SYSVAR_RENT_PUBKEY,
+    SYSVAR_CLOCK_PUBKEY,
+    ComputeBudgetProgram,
+    TransactionMessage,
+    VersionedTransaction,
+} from "@solana/web3.js";
+import {
+    TOKEN_PROGRAM_ID,
+    ASSOCIATED_TOKEN_PROGRAM_ID,
+    createMint,
+    createAccount,
+    mintTo,
+} from "@solana/spl-token";
+
+/**
+ * Compute unit benchmarks for `contribute` against zero-copy groups.
+ *
+ * For each group size the last member in the payout order contributes, so
+ * the bitmap write lands in the final byte of the turn bitmap.
+ * Run with: anchor test -- --grep "Contribute compute units"
+ */
+describe("Contribute compute units", () => {
+    const provider = anchor.AnchorProvider.env();
+    anchor.setProvider(provider);
+
+    const program = anchor.workspace.OsemeGroup as Program<OsemeGroup>;
+    const treasuryProgram = anchor.workspace.OsemeTreasury as Program<OsemeTreasury>;
+    const trustProgram = anchor.workspace.OsemeTrust.programId as PublicKey;
+    const [trustAuthority] = PublicKey.findProgramAddressSync(
+        [Buffer.from("trust-authority")],
+        program.programId
+    );
+
+    const CONTRIBUTION = new anchor.BN(10 * 1e6); // 10 USDC
+    const SUBSCRIPTION = new anchor.BN(500 * 1e6); // Super-Trust subscription price
+    const GROUP_SIZES = [5, 30, 100];
+    const COMPUTE_LIMIT = 200_000;
+    // Fails the run when contribute regresses; zero-copy keeps it flat in group size
+    const COMPUTE_BUDGET = 60_000;
+    // Anchor's ConstraintSeeds, raised when the slot moved past the seed timestamp
+    const CONSTRAINT_SEEDS = 2006;
+
+    let usdcMint: PublicKey;
+    let platformAuthority: Keypair;
+    let platformConfig: PublicKey;
+    let modelConfig: PublicKey;
+    const results: { members: number; units: number }[] = [];
+
+    // The group seed is the Clock sysvar's unix_timestamp when create_group runs
+    const clockTimestamp = async (): Promise<number> => {
+        const clock = await provider.connection.getAccountInfo(SYSVAR_CLOCK_PUBKEY, "processed");
+        return Number(clock!.data.readBigInt64LE(32));
+    };
+
+    const airdrop = async (key: PublicKey) => {
+        const sig = await provider.connection.requestAirdrop(key, 1e9);
+        await provider.connection.confirmTransaction(sig);
+    };
+
+    before(async () => {
+        platformAuthority = Keypair.generate();
+        await airdrop(platformAuthority.publicKey);
+
+        usdcMint = await createMint(
+            provider.connection,
+            platformAuthority,
+            platformAuthority.publicKey,
+            null,
+            6 // USDC decimals
+        );
+
+        [platformConfig] = PublicKey.findProgramAddressSync(
+            [Buffer.from("platform-config")],
+            program.programId
+        );
+
+        [modelConfig] = PublicKey.findProgramAddressSync(
+            [Buffer.from("model-config"), Buffer.from([2])], // GroupModel::SuperTrust
+            program.programId
+        );
+
+        const existing = await provider.connection.getAccountInfo(platformConfig);
+        if (!existing) {
+            await program.methods
+                .initPlatform({
+                    version: 0,
+                    authority: platformAuthority.publicKey,
+                    feeBps: 250,
+                    trustSubscriptionPrice: new anchor.BN(100 * 1e6),
+                    superTrustSubscriptionPrice: new anchor.BN(500 * 1e6),
+                    basicGroupLimit: 5,
+                    basicPerCreatorLimit: 1,
+                    gracePeriodDays: 2,
+                    trustPenalty: -5,
+                    trustBonus: 2,
+                    stakeBonusBps: 100,
+                    kycThreshold: new anchor.BN(1000 * 1e6),
+                    bonusPool: new anchor.BN(0),
+                    usdcMint,
+                    trustDecayPeriodDays: 0,
+                    trustDecayStep: 0,
+                    trustRecoveryGroups: 0,
+                    vouchBonus: 0,
+                    rebateMinTrustScore: 95,
+                    rebateBps: 500,
+                    pausedAt: new anchor.BN(0),
+                    totalPausedSecs: new anchor.BN(0),
+                })
+                .accounts({
+                    platformConfig,
+                    authority: platformAuthority.publicKey,
+                    systemProgram: SystemProgram.programId,
+                })
+                .signers([platformAuthority])
+                .rpc();
+
+            await program.methods
+                .initModelConfig({
+                    version: 0,
+                    model: { superTrust: {} },
+                    memberCap: 100,
+                    minCycleDays: 1,
+                    maxCycleDays: 30,
+                    defaultCycleDays: 7,
+                    minContribution: new anchor.BN(1e6),
+                    maxContribution: new anchor.BN(10_000 * 1e6),
+                    stakeRatioBps: 10_000, // one contribution
+                    feeBps: 250,
+                    creatorShareBps: 9_000,
+                    bump: 0,
+                    minMemberScore: 0,
+                    minCreatorScore: 0,
+                    minCreatorGroupsCompleted: 0,
+                    exposureStakeBps: 0,
+                })
+                .accounts({
+                    modelConfig,
+                    platformConfig,
+                    authority: platformAuthority.publicKey,
+                    systemProgram: SystemProgram.programId,
+                })
+                .signers([platformAuthority])
+                .rpc();
+
+            await treasuryProgram.methods
+                .initTreasury(new anchor.BN(0), 30)
+                .accounts({
+                    usdcMint,
+                    authority: platformAuthority.publicKey,
+                })
+                .signers([platformAuthority])
+                .rpc();
+        }
+    });
+
+    after(() => {
+        console.table(results);
+    });
+
+    for (const size of GROUP_SIZES) {
+        it(`measures contribute with ${size} members`, async () => {
+            const creator = Keypair.generate();
+            await airdrop(creator.publicKey);
+
+            const subscriptionAccount = await createAccount(
+                provider.connection,
+                creator,
+                usdcMint,
+                creator.publicKey,
+                Keypair.generate() // the creator's associated account is created when joining
+            );
+            await mintTo(
+                provider.connection,
+                platformAuthority,
+                usdcMint,
+                subscriptionAccount,
+                platformAuthority,
+                SUBSCRIPTION.toNumber()
+            );
+            await treasuryProgram.methods
+                .paySubscription({ superTrust: {} })
+                .accounts({
+                    creatorTokenAccount: subscriptionAccount,
+                    creator: creator.publicKey,
+                })
+                .signers([creator])
+                .rpc();
+
+            const groupAddresses = (timestamp: number) => {
+                const [group] = PublicKey.findProgramAddressSync(
+                    [
+                        Buffer.from("group"),
+                        creator.publicKey.toBuffer(),
+                        new anchor.BN(timestamp).toArrayLike(Buffer, "le", 8),
+                    ],
+                    program.programId
+                );
+                const [escrowVault] = PublicKey.findProgramAddressSync(
+                    [Buffer.from("escrow"), group.toBuffer()],
+                    program.programId
+                );
+                const [escrowTokenAccount] = PublicKey.findProgramAddressSync(
+                    [Buffer.from("escrow-token"), group.toBuffer()],
+                    program.programId
+                );
+                return { group, escrowVault, escrowTokenAccount };
+            };
+
+            // Retry when the transaction lands in a slot with a later timestamp
+            let created: ReturnType<typeof groupAddresses> | undefined;
+            for (let attempt = 0; !created; attempt++) {
+                const timestamp = await clockTimestamp();
+                const addresses = groupAddresses(timestamp);
+                try {
+                    await program.methods
+                        .createGroup({ superTrust: {} }, CONTRIBUTION, 7, size, null, false)
+                        .accounts({
+                            ...addresses,
+                            platformConfig,
+                            modelConfig,
+                            usdcMint,
+                            creator: creator.publicKey,
+                            systemProgram: SystemProgram.programId,
+                            tokenProgram: TOKEN_PROGRAM_ID,
+                            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+                            rent: SYSVAR_RENT_PUBKEY,
+                        })
+                        .signers([creator])
+                        .rpc();
+                } catch (err) {
+                    const code = (err as anchor.AnchorError).error?.errorCode?.number;
+                    if (code !== CONSTRAINT_SEEDS || attempt >= 5) throw err;
+                    continue;
+                }
+                const stored = await program.account.group.fetch(addresses.group);
+                expect(stored.groupId.toNumber()).to.equal(timestamp);
+                created = addresses;
+            }
+            const { group, escrowVault, escrowTokenAccount } = created;
+
+            // Fill the group; each member holds enough for stake and one contribution
+            let last: { user: Keypair; member: PublicKey; tokenAccount: PublicKey } | undefined;
+            for (let i = 0; i < size; i++) {
+                const user = i === 0 ? creator : Keypair.generate();
+                if (i > 0) await airdrop(user.publicKey);
+
+                const tokenAccount = await createAccount(
+                    provider.connection,
+                    user,
+                    usdcMint,
+                    user.publicKey
+                );
+                await mintTo(
+                    provider.connection,
+                    platformAuthority,
+                    usdcMint,
+                    tokenAccount,
+                    platformAuthority,
+                    CONTRIBUTION.muln(2).toNumber()
+                );
+
+                const [member] = PublicKey.findProgramAddressSync(
+                    [Buffer.from("member"), group.toBuffer(), user.publicKey.toBuffer()],
+                    program.programId
+                );
+
+                await program.methods
+                    .joinGroup(false)
+                    .accounts({
+                        group,
+                        member,
+                        escrowVault,
+                        escrowTokenAccount,
+                        userTokenAccount: tokenAccount,
+                        platformConfig,
+                        modelConfig,
+                        user: user.publicKey,
+                        systemProgram: SystemProgram.programId,
+                        tokenProgram: TOKEN_PROGRAM_ID,
+                    })
+                    .signers([user])
+                    .rpc();
+
+                last = { user, member, tokenAccount };
+            }
+
+            const [userTrust] = PublicKey.findProgramAddressSync(
+                [Buffer.from("user-trust"), last!.user.publicKey.toBuffer()],
+                trustProgram
+            );
+
+            const instruction = await program.methods
+                .contribute(CONTRIBUTION)
+                .accounts({
+                    group,
+                    member: last!.member,
+                    escrowVault,
+                    escrowTokenAccount,
+                    contributorTokenAccount: last!.tokenAccount,
+                    platformConfig,
+                    modelConfig,
+                    contributor: last!.user.publicKey,
+                    userTrust,
+                    trustAuthority,
+                    trustProgram,
+                    tokenProgram: TOKEN_PROGRAM_ID,
+                    systemProgram: SystemProgram.programId,
+                })
+                .instruction();
+
+            const { blockhash } = await provider.connection.getLatestBlockhash();
+            const message = new TransactionMessage({
+                payerKey: last!.user.publicKey,
+                recentBlockhash: blockhash,
+                instructions: [
+                    ComputeBudgetProgram.setComputeUnitLimit({ units: COMPUTE_LIMIT }),
+                    instruction,
+                ],
+            }).compileToV0Message();
+            const transaction = new VersionedTransaction(message);
+            transaction.sign([last!.user]);
+
+            const simulation = await provider.connection.simulateTransaction(transaction);
+            expect(simulation.value.err).to.be.null;
+
+            const units = simulation.value.unitsConsumed!;
+            results.push({ members: size, units });
+            expect(units).to.be.lessThan(COMPUTE_BUDGET);
+        });
+    }
+});
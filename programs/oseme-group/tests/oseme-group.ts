@@ -1,6 +1,7 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { OsemeGroup } from "../target/types/oseme_group";
+import { OsemeTreasury } from "../target/types/oseme_treasury";
 import { expect } from "chai";
 import {
     PublicKey,
@@ -22,6 +23,7 @@ describe("Oseme Group Program", () => {
     anchor.setProvider(provider);
 
     const program = anchor.workspace.OsemeGroup as Program<OsemeGroup>;
+    const treasuryProgram = anchor.workspace.OsemeTreasury as Program<OsemeTreasury>;
 
     let usdcMint: PublicKey;
     let platformAuthority: Keypair;
@@ -128,9 +130,11 @@ describe("Oseme Group Program", () => {
             await program.methods
                 .createGroup(
                     { basic: {} }, // GroupModel::Basic
+                    new anchor.BN(100 * 1e6), // 100 USDC per contribution
                     null, // cycle_days (auto-set to 7 for Basic)
                     null, // member_cap (auto-set to 5 for Basic)
-                    null  // payout_order (empty initially)
+                    null, // payout_order (empty initially)
+                    false // admit_past_defaulters
                 )
                 .accounts({
                     group: basicGroup,
@@ -147,11 +151,11 @@ describe("Oseme Group Program", () => {
                 .rpc();
 
             const groupAccount = await program.account.group.fetch(basicGroup);
-            expect(groupAccount.model).to.deep.equal({ basic: {} });
+            expect(groupAccount.model).to.equal(0); // GroupModel::Basic
             expect(groupAccount.creator.toString()).to.equal(creator.publicKey.toString());
             expect(groupAccount.memberCap).to.equal(5);
             expect(groupAccount.cycleDays).to.equal(7);
-            expect(groupAccount.status).to.deep.equal({ active: {} });
+            expect(groupAccount.status).to.equal(0); // GroupStatus::Active
         });
 
         it("Prevents creating more than 5 Basic groups globally", async () => {
@@ -174,7 +178,7 @@ describe("Oseme Group Program", () => {
 
                 // This should fail if 5 Basic groups already exist
                 await program.methods
-                    .createGroup({ basic: {} }, null, null, null)
+                    .createGroup({ basic: {} }, new anchor.BN(100 * 1e6), null, null, null, false)
                     .accounts({
                         group: sixthGroup,
                         // ... other accounts
@@ -199,7 +203,7 @@ describe("Oseme Group Program", () => {
             );
 
             await program.methods
-                .joinGroup()
+                .joinGroup(false)
                 .accounts({
                     group: basicGroup,
                     member: memberAccount,
@@ -258,6 +262,31 @@ describe("Oseme Group Program", () => {
                 platformAuthority,
                 5000 * 1e6 // 5,000 USDC
             );
+
+            // Trust groups require an active creator subscription
+            const [treasury] = PublicKey.findProgramAddressSync(
+                [Buffer.from("treasury")],
+                treasuryProgram.programId
+            );
+            if (!(await provider.connection.getAccountInfo(treasury))) {
+                await treasuryProgram.methods
+                    .initTreasury(new anchor.BN(10_000 * 1e6), 30)
+                    .accounts({
+                        usdcMint,
+                        authority: platformAuthority.publicKey,
+                    })
+                    .signers([platformAuthority])
+                    .rpc();
+            }
+
+            await treasuryProgram.methods
+                .paySubscription({ trust: {} })
+                .accounts({
+                    creatorTokenAccount,
+                    creator: creator.publicKey,
+                })
+                .signers([creator])
+                .rpc();
         });
 
         it("Creates a Trust group with custom parameters", async () => {
@@ -282,9 +311,11 @@ describe("Oseme Group Program", () => {
             await program.methods
                 .createGroup(
                     { trust: {} }, // GroupModel::Trust
+                    new anchor.BN(100 * 1e6), // 100 USDC per contribution
                     14, // 14-day cycles
                     10, // max 10 members
-                    payoutOrder
+                    payoutOrder,
+                    false // admit_past_defaulters
                 )
                 .accounts({
                     group: trustGroup,
@@ -297,10 +328,15 @@ describe("Oseme Group Program", () => {
                 .rpc();
 
             const groupAccount = await program.account.group.fetch(trustGroup);
-            expect(groupAccount.model).to.deep.equal({ trust: {} });
+            expect(groupAccount.model).to.equal(1); // GroupModel::Trust
             expect(groupAccount.cycleDays).to.equal(14);
             expect(groupAccount.memberCap).to.equal(10);
-            expect(groupAccount.payoutOrder.length).to.equal(3);
+            expect(groupAccount.payoutOrderLen).to.equal(payoutOrder.length);
+            expect(
+                groupAccount.payoutOrder
+                    .slice(0, groupAccount.payoutOrderLen)
+                    .map((key: PublicKey) => key.toBase58())
+            ).to.deep.equal(payoutOrder.map((key) => key.toBase58()));
         });
 
         it("Requires stake when joining Trust group", async () => {
@@ -316,7 +352,7 @@ describe("Oseme Group Program", () => {
             const stakeAmount = 1000 * 1e6; // 1000 USDC stake
 
             await program.methods
-                .joinGroup()
+                .joinGroup(false)
                 .accounts({
                     group: trustGroup,
                     member: memberAccount,
@@ -0,0 +1,88 @@
+//! `add_guarantor` and how `slash_member` draws on the guarantee
+
+use anchor_lang::prelude::Pubkey;
+use oseme_trust::state::UserTrust;
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::common::*;
+
+const CONTRIBUTION: u64 = 10 * USDC;
+
+/// A Trust group whose second member misses the first turn, guaranteed for `allowance`
+async fn guaranteed_default(env: &mut TestEnv, allowance: u64) -> (Pubkey, Keypair, Keypair) {
+    let options = GroupOptions {
+        member_cap: Some(3),
+        cycle_days: Some(1),
+        ..Default::default()
+    };
+    let (group, creator, others) = env.group_with_members(GroupModel::Trust, CONTRIBUTION, options, 3).await;
+    let guarantor_wallet = env.user(100 * USDC).await;
+    env.add_guarantor(&group, &others[0], &guarantor_wallet, allowance).await.unwrap();
+
+    env.contribute(&group, &creator).await.unwrap();
+    env.contribute(&group, &others[1]).await.unwrap();
+    env.pass_grace_period(&group).await;
+
+    let [defaulter, _]: [Keypair; 2] = others.try_into().unwrap();
+    (group, defaulter, guarantor_wallet)
+}
+
+#[tokio::test]
+async fn a_guarantor_covers_a_missed_turn_before_stake() {
+    let mut env = TestEnv::start().await;
+    let (group, defaulter, guarantor_wallet) = guaranteed_default(&mut env, 2 * CONTRIBUTION).await;
+    let stake = env.fetch::<Member>(&member(&group, &defaulter.pubkey())).await.stake_amount;
+
+    env.slash_member(&group, &defaulter.pubkey()).await.unwrap();
+
+    assert_eq!(env.balance(&token_account(&guarantor_wallet.pubkey())).await, 100 * USDC - CONTRIBUTION);
+    let guarantee: Guarantor = env.fetch(&guarantor(&group, &defaulter.pubkey())).await;
+    assert_eq!(guarantee.pulled_amount, CONTRIBUTION);
+    let slashed: Member = env.fetch(&member(&group, &defaulter.pubkey())).await;
+    assert_eq!(slashed.stake_amount, stake);
+    assert_eq!(env.group(&group).await.turn_shortfall, 0);
+
+    // Honouring the guarantee is not a default of the guarantor
+    assert!(!env.exists(&user_trust(&guarantor_wallet.pubkey())).await);
+}
+
+#[tokio::test]
+async fn an_exhausted_allowance_falls_through_to_stake() {
+    let mut env = TestEnv::start().await;
+    let (group, defaulter, guarantor_wallet) = guaranteed_default(&mut env, 4 * USDC).await;
+    let stake = env.fetch::<Member>(&member(&group, &defaulter.pubkey())).await.stake_amount;
+
+    env.slash_member(&group, &defaulter.pubkey()).await.unwrap();
+
+    let guarantee: Guarantor = env.fetch(&guarantor(&group, &defaulter.pubkey())).await;
+    assert_eq!(guarantee.pulled_amount, 4 * USDC);
+    let slashed: Member = env.fetch(&member(&group, &defaulter.pubkey())).await;
+    assert_eq!(slashed.stake_amount, 0);
+    assert_eq!(env.group(&group).await.turn_shortfall, CONTRIBUTION - 4 * USDC - stake);
+    assert!(!env.exists(&user_trust(&guarantor_wallet.pubkey())).await);
+}
+
+#[tokio::test]
+async fn revoking_the_delegate_is_recorded_as_a_guarantor_default() {
+    let mut env = TestEnv::start().await;
+    let (group, defaulter, guarantor_wallet) = guaranteed_default(&mut env, 2 * CONTRIBUTION).await;
+    let guarantor_key = guarantor_wallet.pubkey();
+    let stake = env.fetch::<Member>(&member(&group, &defaulter.pubkey())).await.stake_amount;
+
+    let revoke = spl_token::instruction::revoke(&spl_token::ID, &token_account(&guarantor_key), &guarantor_key, &[]).unwrap();
+    env.send(vec![revoke], &[&guarantor_wallet]).await.unwrap();
+
+    env.slash_member(&group, &defaulter.pubkey()).await.unwrap();
+
+    assert_eq!(env.balance(&token_account(&guarantor_key)).await, 100 * USDC);
+    let guarantee: Guarantor = env.fetch(&guarantor(&group, &defaulter.pubkey())).await;
+    assert_eq!(guarantee.pulled_amount, 0);
+    let slashed: Member = env.fetch(&member(&group, &defaulter.pubkey())).await;
+    assert_eq!(slashed.stake_amount, 0);
+    assert_eq!(env.group(&group).await.turn_shortfall, CONTRIBUTION - stake);
+
+    let config: PlatformConfig = env.fetch(&platform_config()).await;
+    let reputation: UserTrust = env.fetch(&user_trust(&guarantor_key)).await;
+    assert_eq!(reputation.defaults, 1);
+    assert_eq!(reputation.score, (UserTrust::INITIAL_SCORE as i16 + config.trust_penalty as i16) as u16);
+}
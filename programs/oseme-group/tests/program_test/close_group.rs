@@ -0,0 +1,112 @@
+//! `close_group` and `write_off_debt`
+
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::common::*;
+
+#[tokio::test]
+async fn closes_a_finalized_group_once_emptied() {
+    let mut env = TestEnv::start().await;
+    let (group, creator, members) = env
+        .group_with_members(GroupModel::Trust, 10 * USDC, GroupOptions { member_cap: Some(3), ..Default::default() }, 3)
+        .await;
+    let everyone = [&creator, &members[0], &members[1]];
+    for _ in 0..3 {
+        env.run_turn(&group, &everyone).await;
+    }
+
+    // Completed but not finalized
+    assert_group_error(env.close_group(&group, &creator).await, OsemeGroupError::GroupNotClosedOut);
+
+    env.finalize_group(&group).await.unwrap();
+    assert_group_error(env.close_group(&group, &creator).await, OsemeGroupError::EscrowNotEmpty);
+
+    for user in everyone {
+        env.withdraw_stake(&group, user).await.unwrap();
+    }
+    let lamports_before = env.account(&creator.pubkey()).await.unwrap().lamports;
+    env.close_group(&group, &creator).await.unwrap();
+
+    assert!(!env.exists(&group).await);
+    assert!(!env.exists(&escrow_vault(&group)).await);
+    assert!(!env.exists(&escrow_token(&group)).await);
+    assert!(env.account(&creator.pubkey()).await.unwrap().lamports > lamports_before);
+}
+
+#[tokio::test]
+async fn only_the_creator_closes_the_group() {
+    let mut env = TestEnv::start().await;
+    let (group, creator, members) = env
+        .group_with_members(GroupModel::Trust, 10 * USDC, GroupOptions { member_cap: Some(2), ..Default::default() }, 2)
+        .await;
+    for _ in 0..2 {
+        env.run_turn(&group, &[&creator, &members[0]]).await;
+    }
+    env.finalize_group(&group).await.unwrap();
+
+    assert_group_error(env.close_group(&group, &members[0]).await, OsemeGroupError::Unauthorized);
+}
+
+#[tokio::test]
+async fn an_outstanding_debt_keeps_the_group_open_until_written_off() {
+    let mut env = TestEnv::start().await;
+    let (group, creator, debtor, shorted) = env.finalized_group_with_debt().await;
+    for user in [&creator, &debtor, &shorted] {
+        env.withdraw_stake(&group, user).await.unwrap();
+    }
+
+    assert_group_error(env.close_group(&group, &creator).await, OsemeGroupError::DebtOutstanding);
+
+    let authority = env.authority.insecure_clone();
+    env.write_off_debt(&group, &debtor.pubkey(), &authority).await.unwrap();
+    env.close_group(&group, &creator).await.unwrap();
+    assert!(!env.exists(&group).await);
+}
+
+#[tokio::test]
+async fn write_off_keeps_the_debt_on_the_defaulter_record() {
+    let mut env = TestEnv::start().await;
+    let (group, _creator, debtor, _shorted) = env.finalized_group_with_debt().await;
+    let owed: Debt = env.fetch(&debt(&group, &debtor.pubkey())).await;
+    assert!(owed.outstanding > 0);
+
+    let authority = env.authority.insecure_clone();
+    env.write_off_debt(&group, &debtor.pubkey(), &authority).await.unwrap();
+
+    let written_off: Debt = env.fetch(&debt(&group, &debtor.pubkey())).await;
+    assert_eq!(written_off.outstanding, 0);
+    assert_eq!(written_off.repaid, 0);
+    let vault: EscrowVault = env.fetch(&escrow_vault(&group)).await;
+    assert_eq!(vault.outstanding_debt, 0);
+
+    let record: DefaulterRecord = env.fetch(&defaulter_record(&debtor.pubkey())).await;
+    assert!(record.has_unresolved_debt());
+    assert_eq!(record.outstanding_debt, owed.outstanding);
+
+    // Nothing is left to write off or repay
+    assert_group_error(
+        env.write_off_debt(&group, &debtor.pubkey(), &authority).await,
+        OsemeGroupError::NoDebtOutstanding,
+    );
+    env.mint_usdc(&debtor.pubkey(), USDC).await;
+    assert_group_error(
+        env.repay_debt(&group, &debtor.pubkey(), &debtor, USDC).await,
+        OsemeGroupError::RepaymentExceedsDebt,
+    );
+}
+
+#[tokio::test]
+async fn write_off_requires_the_platform_authority() {
+    let mut env = TestEnv::start().await;
+    let (group, creator, debtor, _shorted) = env.finalized_group_with_debt().await;
+
+    assert_group_error(
+        env.write_off_debt(&group, &debtor.pubkey(), &creator).await,
+        OsemeGroupError::Unauthorized,
+    );
+    let stranger = Keypair::new();
+    assert_group_error(
+        env.write_off_debt(&group, &debtor.pubkey(), &stranger).await,
+        OsemeGroupError::Unauthorized,
+    );
+}
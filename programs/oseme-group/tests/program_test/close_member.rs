@@ -0,0 +1,84 @@
+//! `close_member`
+
+use solana_sdk::signature::Signer;
+
+use crate::common::*;
+
+#[tokio::test]
+async fn closes_a_settled_member_of_a_finalized_group() {
+    let mut env = TestEnv::start().await;
+    let (group, creator, members) = env
+        .group_with_members(GroupModel::Trust, 10 * USDC, GroupOptions { member_cap: Some(2), ..Default::default() }, 2)
+        .await;
+    let member_wallet = &members[0];
+
+    // Stake is locked, then the group must be over
+    assert_group_error(env.close_member(&group, member_wallet).await, OsemeGroupError::StakeNotWithdrawn);
+    assert_group_error(env.close_member(&group, &creator).await, OsemeGroupError::GroupNotClosedOut);
+
+    for _ in 0..2 {
+        env.run_turn(&group, &[&creator, member_wallet]).await;
+    }
+    env.finalize_group(&group).await.unwrap();
+    env.withdraw_stake(&group, member_wallet).await.unwrap();
+
+    assert_group_error(env.close_member(&group, member_wallet).await, OsemeGroupError::TrustNotSettled);
+
+    env.settle_member(&group, &member_wallet.pubkey()).await.unwrap();
+    env.close_member(&group, member_wallet).await.unwrap();
+    assert!(!env.exists(&member(&group, &member_wallet.pubkey())).await);
+}
+
+#[tokio::test]
+async fn closes_a_member_after_the_group_was_closed() {
+    let mut env = TestEnv::start().await;
+    let (group, creator, members) = env
+        .group_with_members(GroupModel::Trust, 10 * USDC, GroupOptions { member_cap: Some(2), ..Default::default() }, 2)
+        .await;
+    for _ in 0..2 {
+        env.run_turn(&group, &[&creator, &members[0]]).await;
+    }
+    env.finalize_group(&group).await.unwrap();
+    env.withdraw_stake(&group, &creator).await.unwrap();
+    env.withdraw_stake(&group, &members[0]).await.unwrap();
+    env.close_group(&group, &creator).await.unwrap();
+
+    env.close_member(&group, &members[0]).await.unwrap();
+    assert!(!env.exists(&member(&group, &members[0].pubkey())).await);
+}
+
+#[tokio::test]
+async fn a_shorted_member_closes_after_the_debt_is_written_off() {
+    let mut env = TestEnv::start().await;
+    let (group, _creator, debtor, shorted) = env.finalized_group_with_debt().await;
+    env.withdraw_stake(&group, &shorted).await.unwrap();
+    env.settle_member(&group, &shorted.pubkey()).await.unwrap();
+
+    assert_group_error(env.close_member(&group, &shorted).await, OsemeGroupError::RecoveryNotClaimed);
+
+    // Recovery is only final once no debt can be repaid into the group
+    assert_group_error(env.claim_recovery(&group, &shorted).await, OsemeGroupError::NothingToClaim);
+    let authority = env.authority.insecure_clone();
+    env.write_off_debt(&group, &debtor.pubkey(), &authority).await.unwrap();
+    env.claim_recovery(&group, &shorted).await.unwrap();
+
+    let settled: Member = env.fetch(&member(&group, &shorted.pubkey())).await;
+    assert_eq!(settled.shorted_amount, 0);
+    let vault: EscrowVault = env.fetch(&escrow_vault(&group)).await;
+    assert_eq!(vault.total_shorted, 0);
+
+    env.close_member(&group, &shorted).await.unwrap();
+    assert!(!env.exists(&member(&group, &shorted.pubkey())).await);
+}
+
+#[tokio::test]
+async fn the_debtor_closes_once_settled() {
+    let mut env = TestEnv::start().await;
+    let (group, _creator, debtor, _shorted) = env.finalized_group_with_debt().await;
+    env.withdraw_stake(&group, &debtor).await.unwrap();
+    env.settle_member(&group, &debtor.pubkey()).await.unwrap();
+
+    // The slashed turn counts as settled; what is owed lives on in the Debt
+    env.close_member(&group, &debtor).await.unwrap();
+    assert!(env.exists(&debt(&group, &debtor.pubkey())).await);
+}
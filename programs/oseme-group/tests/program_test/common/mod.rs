@@ -0,0 +1,1406 @@
+//! Runs oseme-group, oseme-trust and oseme-treasury natively inside a
+//! solana-program-test bank, with the SPL token programs it bundles.
+
+#![allow(dead_code)]
+
+use anchor_lang::prelude::{AccountInfo, Pubkey};
+use anchor_lang::solana_program::entrypoint::ProgramResult;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction, InstructionError};
+use anchor_lang::solana_program::program_pack::Pack;
+use anchor_lang::solana_program::sysvar::clock::Clock;
+use anchor_lang::{system_program, AccountDeserialize, AccountSerialize, InstructionData, ToAccountMetas};
+use solana_program_test::{processor, BanksClientError, ProgramTest, ProgramTestContext};
+use solana_sdk::account::Account;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::system_instruction;
+use solana_sdk::transaction::{Transaction, TransactionError};
+
+pub use oseme_group::errors::OsemeGroupError;
+pub use oseme_group::state::*;
+pub use oseme_treasury::errors::OsemeTreasuryError;
+pub use oseme_treasury::state::{
+    SubscriptionTier, Treasury, BONUS_VAULT_SEED, CREATOR_REVENUE_SEED,
+    CREATOR_VAULT_SEED, FEE_VAULT_SEED, SUBSCRIPTION_SEED, TREASURY_SEED,
+};
+
+pub const USDC: u64 = 1_000_000;
+pub const DAY: i64 = 24 * 60 * 60;
+pub const GRACE_PERIOD_DAYS: u8 = 2;
+pub const STAKE_BONUS_BPS: u16 = 1_000;
+pub const TRUST_SUBSCRIPTION_PRICE: u64 = 100 * USDC;
+pub const SUPER_TRUST_SUBSCRIPTION_PRICE: u64 = 500 * USDC;
+pub const REBATE_BPS: u16 = 2_000;
+
+pub type TestResult = Result<(), BanksClientError>;
+
+// Anchor's entrypoints tie the account slice to the accounts' lifetime
+fn group_entry(program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let accounts = Box::leak(Box::new(accounts.to_vec()));
+    oseme_group::entry(program_id, accounts, data)
+}
+
+fn trust_entry(program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let accounts = Box::leak(Box::new(accounts.to_vec()));
+    oseme_trust::entry(program_id, accounts, data)
+}
+
+fn treasury_entry(program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let accounts = Box::leak(Box::new(accounts.to_vec()));
+    oseme_treasury::entry(program_id, accounts, data)
+}
+
+pub fn pda(seeds: &[&[u8]]) -> Pubkey {
+    Pubkey::find_program_address(seeds, &oseme_group::ID).0
+}
+
+pub fn treasury_pda(seeds: &[&[u8]]) -> Pubkey {
+    Pubkey::find_program_address(seeds, &oseme_treasury::ID).0
+}
+
+pub fn trust_pda(seeds: &[&[u8]]) -> Pubkey {
+    Pubkey::find_program_address(seeds, &oseme_trust::ID).0
+}
+
+pub fn platform_config() -> Pubkey {
+    pda(&[b"platform-config"])
+}
+
+pub fn model_config(model: GroupModel) -> Pubkey {
+    pda(&[b"model-config", &[model as u8]])
+}
+
+pub fn escrow_vault(group: &Pubkey) -> Pubkey {
+    pda(&[b"escrow", group.as_ref()])
+}
+
+pub fn escrow_token(group: &Pubkey) -> Pubkey {
+    pda(&[b"escrow-token", group.as_ref()])
+}
+
+pub fn member(group: &Pubkey, user: &Pubkey) -> Pubkey {
+    pda(&[b"member", group.as_ref(), user.as_ref()])
+}
+
+pub fn user_trust(user: &Pubkey) -> Pubkey {
+    trust_pda(&[b"user-trust", user.as_ref()])
+}
+
+pub fn trust_authority() -> Pubkey {
+    pda(&[b"trust-authority"])
+}
+
+pub fn treasury_authority() -> Pubkey {
+    pda(&[b"treasury-authority"])
+}
+
+pub fn treasury() -> Pubkey {
+    treasury_pda(&[TREASURY_SEED])
+}
+
+pub fn fee_vault() -> Pubkey {
+    treasury_pda(&[FEE_VAULT_SEED])
+}
+
+pub fn bonus_vault() -> Pubkey {
+    treasury_pda(&[BONUS_VAULT_SEED])
+}
+
+pub fn creator_vault() -> Pubkey {
+    treasury_pda(&[CREATOR_VAULT_SEED])
+}
+
+pub fn creator_revenue(creator: &Pubkey) -> Pubkey {
+    treasury_pda(&[CREATOR_REVENUE_SEED, creator.as_ref()])
+}
+
+pub fn subscription(creator: &Pubkey) -> Pubkey {
+    treasury_pda(&[SUBSCRIPTION_SEED, creator.as_ref()])
+}
+
+pub fn creator_volume(creator: &Pubkey) -> Pubkey {
+    pda(&[b"creator-volume", creator.as_ref()])
+}
+
+pub fn defaulter_record(user: &Pubkey) -> Pubkey {
+    pda(&[b"defaulter", user.as_ref()])
+}
+
+pub fn debt(group: &Pubkey, debtor: &Pubkey) -> Pubkey {
+    pda(&[b"debt", group.as_ref(), debtor.as_ref()])
+}
+
+pub fn vouch(group: &Pubkey, newcomer: &Pubkey) -> Pubkey {
+    pda(&[b"vouch", group.as_ref(), newcomer.as_ref()])
+}
+
+pub fn guarantor(group: &Pubkey, user: &Pubkey) -> Pubkey {
+    pda(&[b"guarantor", group.as_ref(), user.as_ref()])
+}
+
+pub fn fee_schedule(model: GroupModel) -> Pubkey {
+    pda(&[b"fee-schedule", &[model as u8]])
+}
+
+pub fn token_account(owner: &Pubkey) -> Pubkey {
+    spl_associated_token_account::get_associated_token_address(owner, &usdc_mint())
+}
+
+/// Fixed so the USDC mint address is known to every helper
+pub fn usdc_mint_keypair() -> Keypair {
+    solana_sdk::signer::keypair::keypair_from_seed(&[42; 32]).unwrap()
+}
+
+pub fn usdc_mint() -> Pubkey {
+    usdc_mint_keypair().pubkey()
+}
+
+/// The model's error code as reported by the runtime
+pub fn group_error(error: OsemeGroupError) -> u32 {
+    error.into()
+}
+
+pub fn treasury_error(error: OsemeTreasuryError) -> u32 {
+    error.into()
+}
+
+pub fn assert_group_error(result: TestResult, error: OsemeGroupError) {
+    assert_custom_error(result, group_error(error));
+}
+
+pub fn assert_treasury_error(result: TestResult, error: OsemeTreasuryError) {
+    assert_custom_error(result, treasury_error(error));
+}
+
+pub fn assert_custom_error(result: TestResult, code: u32) {
+    match result {
+        Err(BanksClientError::TransactionError(TransactionError::InstructionError(
+            _,
+            InstructionError::Custom(actual),
+        ))) => assert_eq!(actual, code, "unexpected error code"),
+        other => panic!("expected custom error {code}, got {other:?}"),
+    }
+}
+
+pub fn model_params(model: GroupModel) -> ModelConfig {
+    let (member_cap, stake_ratio_bps, fee_bps) = match model {
+        GroupModel::Basic => (5, 0, 100),
+        GroupModel::Trust => (10, 2_000, 200),
+        GroupModel::SuperTrust => (100, 2_000, 300),
+    };
+    ModelConfig {
+        version: 0,
+        model,
+        member_cap,
+        min_cycle_days: 1,
+        max_cycle_days: 30,
+        default_cycle_days: 7,
+        min_contribution: USDC,
+        max_contribution: 10_000 * USDC,
+        stake_ratio_bps,
+        fee_bps,
+        creator_share_bps: 2_500,
+        bump: 0,
+        min_member_score: 0,
+        min_creator_score: 0,
+        min_creator_groups_completed: 0,
+        exposure_stake_bps: 0,
+    }
+}
+
+/// Group creation parameters beyond the model and contribution
+#[derive(Default, Clone)]
+pub struct GroupOptions {
+    pub cycle_days: Option<u32>,
+    pub member_cap: Option<u8>,
+    pub payout_order: Option<Vec<Pubkey>>,
+    pub admit_past_defaulters: bool,
+    pub promo: Option<Pubkey>,
+}
+
+pub struct TestEnv {
+    pub ctx: ProgramTestContext,
+    pub authority: Keypair,
+}
+
+impl TestEnv {
+    pub async fn start() -> Self {
+        Self::start_with(|_| {}).await
+    }
+
+    /// The programs and USDC mint only, with `fixtures` preloaded
+    pub async fn start_bare(fixtures: Vec<(Pubkey, Account)>) -> Self {
+        let mut program_test = ProgramTest::new("oseme_group", oseme_group::ID, processor!(group_entry));
+        program_test.add_program("oseme_trust", oseme_trust::ID, processor!(trust_entry));
+        program_test.add_program("oseme_treasury", oseme_treasury::ID, processor!(treasury_entry));
+        program_test.prefer_bpf(false);
+        for (address, account) in fixtures {
+            program_test.add_account(address, account);
+        }
+
+        let ctx = program_test.start_with_context().await;
+        let mut env = Self {
+            ctx,
+            authority: Keypair::new(),
+        };
+        env.airdrop(&env.authority.pubkey(), 100_000_000_000).await;
+        env.create_mint().await;
+        env
+    }
+
+    /// `configure` may adjust the platform config before it is created
+    pub async fn start_with(configure: impl FnOnce(&mut PlatformConfig)) -> Self {
+        let mut env = Self::start_bare(vec![]).await;
+
+        let mut config = PlatformConfig {
+            version: 0,
+            authority: env.authority.pubkey(),
+            fee_bps: 250,
+            trust_subscription_price: TRUST_SUBSCRIPTION_PRICE,
+            super_trust_subscription_price: SUPER_TRUST_SUBSCRIPTION_PRICE,
+            basic_group_limit: 5,
+            basic_per_creator_limit: 1,
+            grace_period_days: GRACE_PERIOD_DAYS,
+            trust_penalty: -5,
+            trust_bonus: 2,
+            stake_bonus_bps: STAKE_BONUS_BPS,
+            kyc_threshold: 1_000 * USDC,
+            bonus_pool: 0,
+            usdc_mint: usdc_mint(),
+            bump: 0,
+            trust_decay_period_days: 0,
+            trust_decay_step: 0,
+            trust_recovery_groups: 0,
+            vouch_bonus: 1,
+            rebate_min_trust_score: 90,
+            rebate_bps: REBATE_BPS,
+            paused_at: 0,
+            total_paused_secs: 0,
+        };
+        configure(&mut config);
+
+        let authority = env.authority.pubkey();
+        env.send_as_authority(vec![Instruction {
+            program_id: oseme_group::ID,
+            accounts: oseme_group::accounts::InitPlatform {
+                platform_config: platform_config(),
+                authority,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: oseme_group::instruction::InitPlatform { config }.data(),
+        }])
+        .await
+        .unwrap();
+
+        for model in [GroupModel::Basic, GroupModel::Trust, GroupModel::SuperTrust] {
+            env.send_as_authority(vec![Instruction {
+                program_id: oseme_group::ID,
+                accounts: oseme_group::accounts::InitModelConfig {
+                    model_config: model_config(model),
+                    platform_config: platform_config(),
+                    authority,
+                    system_program: system_program::ID,
+                }
+                .to_account_metas(None),
+                data: oseme_group::instruction::InitModelConfig { config: model_params(model) }.data(),
+            }])
+            .await
+            .unwrap();
+        }
+
+        env.send_as_authority(vec![Instruction {
+            program_id: oseme_treasury::ID,
+            accounts: oseme_treasury::accounts::InitTreasury {
+                treasury: treasury(),
+                fee_vault: fee_vault(),
+                bonus_vault: bonus_vault(),
+                usdc_mint: usdc_mint(),
+                authority,
+                system_program: system_program::ID,
+                token_program: spl_token::ID,
+                rent: anchor_lang::solana_program::sysvar::rent::ID,
+            }
+            .to_account_metas(None),
+            data: oseme_treasury::instruction::InitTreasury {
+                withdrawal_limit: 1_000 * USDC,
+                withdrawal_period_days: 7,
+            }
+            .data(),
+        }])
+        .await
+        .unwrap();
+
+        env
+    }
+
+    // ----- Plumbing -----
+
+    pub async fn send(&mut self, instructions: Vec<Instruction>, signers: &[&Keypair]) -> TestResult {
+        let blockhash = self.ctx.get_new_latest_blockhash().await.unwrap();
+        let mut all_signers: Vec<&Keypair> = vec![&self.ctx.payer];
+        all_signers.extend(signers.iter().copied().filter(|signer| signer.pubkey() != self.ctx.payer.pubkey()));
+        let transaction = Transaction::new_signed_with_payer(
+            &instructions,
+            Some(&self.ctx.payer.pubkey()),
+            &all_signers,
+            blockhash,
+        );
+        self.ctx.banks_client.process_transaction(transaction).await
+    }
+
+    pub async fn send_as_authority(&mut self, instructions: Vec<Instruction>) -> TestResult {
+        let authority = self.authority.insecure_clone();
+        self.send(instructions, &[&authority]).await
+    }
+
+    pub async fn airdrop(&mut self, to: &Pubkey, lamports: u64) {
+        let payer = self.ctx.payer.insecure_clone();
+        self.send(vec![system_instruction::transfer(&payer.pubkey(), to, lamports)], &[])
+            .await
+            .unwrap();
+    }
+
+    pub async fn account(&mut self, address: &Pubkey) -> Option<Account> {
+        self.ctx.banks_client.get_account(*address).await.unwrap()
+    }
+
+    pub async fn exists(&mut self, address: &Pubkey) -> bool {
+        self.account(address).await.is_some()
+    }
+
+    pub async fn fetch<T: AccountDeserialize>(&mut self, address: &Pubkey) -> T {
+        let account = self.account(address).await.expect("account not found");
+        T::try_deserialize(&mut &account.data[..]).unwrap()
+    }
+
+    /// Overwrite a program account, for states the instructions never produce
+    pub async fn store<T: AccountSerialize>(&mut self, address: &Pubkey, value: &T) {
+        let mut account = self.account(address).await.unwrap();
+        value.try_serialize(&mut &mut account.data[..]).unwrap();
+        self.ctx.set_account(address, &account.into());
+    }
+
+    pub async fn group(&mut self, address: &Pubkey) -> Group {
+        let account = self.account(address).await.expect("group not found");
+        *bytemuck::from_bytes::<Group>(&account.data[8..Group::LEN])
+    }
+
+    pub async fn now(&mut self) -> i64 {
+        self.clock().await.unix_timestamp
+    }
+
+    pub async fn clock(&mut self) -> Clock {
+        self.ctx.banks_client.get_sysvar::<Clock>().await.unwrap()
+    }
+
+    /// Move the clock forward; slots are not advanced
+    pub async fn advance(&mut self, secs: i64) {
+        let mut clock = self.clock().await;
+        clock.unix_timestamp += secs;
+        self.ctx.set_sysvar(&clock);
+    }
+
+    // ----- Tokens -----
+
+    async fn create_mint(&mut self) {
+        let mint = usdc_mint_keypair();
+        let payer = self.ctx.payer.pubkey();
+        let rent = self.ctx.banks_client.get_rent().await.unwrap();
+        let len = spl_token::state::Mint::LEN;
+        self.send(
+            vec![
+                system_instruction::create_account(&payer, &mint.pubkey(), rent.minimum_balance(len), len as u64, &spl_token::ID),
+                spl_token::instruction::initialize_mint(&spl_token::ID, &mint.pubkey(), &payer, None, 6).unwrap(),
+            ],
+            &[&mint],
+        )
+        .await
+        .unwrap();
+    }
+
+    /// Create `owner`'s USDC account if needed and mint `amount` into it
+    pub async fn mint_usdc(&mut self, owner: &Pubkey, amount: u64) -> Pubkey {
+        let payer = self.ctx.payer.pubkey();
+        let account = token_account(owner);
+        let mut instructions = vec![];
+        if !self.exists(&account).await {
+            instructions.push(
+                spl_associated_token_account::instruction::create_associated_token_account(
+                    &payer,
+                    owner,
+                    &usdc_mint(),
+                    &spl_token::ID,
+                ),
+            );
+        }
+        if amount > 0 {
+            instructions.push(
+                spl_token::instruction::mint_to(&spl_token::ID, &usdc_mint(), &account, &payer, &[], amount).unwrap(),
+            );
+        }
+        if !instructions.is_empty() {
+            self.send(instructions, &[]).await.unwrap();
+        }
+        account
+    }
+
+    pub async fn balance(&mut self, token_account: &Pubkey) -> u64 {
+        let account = self.account(token_account).await.expect("token account not found");
+        spl_token::state::Account::unpack(&account.data).unwrap().amount
+    }
+
+    /// A wallet with SOL for rent and `usdc` in its USDC account
+    pub async fn user(&mut self, usdc: u64) -> Keypair {
+        let user = Keypair::new();
+        self.airdrop(&user.pubkey(), 10_000_000_000).await;
+        self.mint_usdc(&user.pubkey(), usdc).await;
+        user
+    }
+
+    // ----- oseme-treasury -----
+
+    pub async fn pay_subscription(&mut self, creator: &Keypair, tier: SubscriptionTier) -> TestResult {
+        let creator_key = creator.pubkey();
+        self.send(
+            vec![Instruction {
+                program_id: oseme_treasury::ID,
+                accounts: oseme_treasury::accounts::PaySubscription {
+                    subscription: subscription(&creator_key),
+                    treasury: treasury(),
+                    fee_vault: fee_vault(),
+                    platform_config: platform_config(),
+                    creator_token_account: token_account(&creator_key),
+                    creator: creator_key,
+                    system_program: system_program::ID,
+                    token_program: spl_token::ID,
+                }
+                .to_account_metas(None),
+                data: oseme_treasury::instruction::PaySubscription { tier }.data(),
+            }],
+            &[creator],
+        )
+        .await
+    }
+
+    pub async fn fund_bonus_pool(&mut self, amount: u64) -> TestResult {
+        let funder = self.user(amount).await;
+        let funder_key = funder.pubkey();
+        self.send(
+            vec![Instruction {
+                program_id: oseme_treasury::ID,
+                accounts: oseme_treasury::accounts::FundBonusPool {
+                    treasury: treasury(),
+                    bonus_vault: bonus_vault(),
+                    funder_token_account: token_account(&funder_key),
+                    funder: funder_key,
+                    token_program: spl_token::ID,
+                }
+                .to_account_metas(None),
+                data: oseme_treasury::instruction::FundBonusPool { amount }.data(),
+            }],
+            &[&funder],
+        )
+        .await
+    }
+
+    // ----- oseme-group -----
+
+    /// Create a group now; returns its address
+    pub async fn create_group(
+        &mut self,
+        creator: &Keypair,
+        model: GroupModel,
+        contribution_amount: u64,
+        options: GroupOptions,
+    ) -> Result<Pubkey, BanksClientError> {
+        let creator_key = creator.pubkey();
+        if model != GroupModel::Basic && !self.exists(&subscription(&creator_key)).await {
+            let tier = match model {
+                GroupModel::SuperTrust => SubscriptionTier::SuperTrust,
+                _ => SubscriptionTier::Trust,
+            };
+            self.mint_usdc(&creator_key, SUPER_TRUST_SUBSCRIPTION_PRICE).await;
+            self.pay_subscription(creator, tier).await?;
+        }
+
+        let now = self.now().await;
+        let group = pda(&[b"group", creator_key.as_ref(), &now.to_le_bytes()]);
+        let promo_redemption = options
+            .promo
+            .map(|promo| pda(&[b"promo-redemption", promo.as_ref(), creator_key.as_ref()]));
+        self.send(
+            vec![Instruction {
+                program_id: oseme_group::ID,
+                accounts: oseme_group::accounts::CreateGroup {
+                    group,
+                    escrow_vault: escrow_vault(&group),
+                    escrow_token_account: escrow_token(&group),
+                    platform_config: platform_config(),
+                    model_config: model_config(model),
+                    usdc_mint: usdc_mint(),
+                    user_trust: user_trust(&creator_key),
+                    subscription: subscription(&creator_key),
+                    promo: options.promo,
+                    promo_redemption,
+                    creator: creator_key,
+                    system_program: system_program::ID,
+                    token_program: spl_token::ID,
+                    associated_token_program: spl_associated_token_account::ID,
+                    rent: anchor_lang::solana_program::sysvar::rent::ID,
+                }
+                .to_account_metas(None),
+                data: oseme_group::instruction::CreateGroup {
+                    model,
+                    contribution_amount,
+                    cycle_days: options.cycle_days,
+                    member_cap: options.member_cap,
+                    payout_order: options.payout_order,
+                    admit_past_defaulters: options.admit_past_defaulters,
+                }
+                .data(),
+            }],
+            &[creator],
+        )
+        .await?;
+        Ok(group)
+    }
+
+    pub async fn join_group(&mut self, group: &Pubkey, user: &Keypair, full_collateral: bool) -> TestResult {
+        let user_key = user.pubkey();
+        let model = GroupModel::try_from(self.group(group).await.model).unwrap();
+        self.mint_usdc(&user_key, 0).await;
+        self.send(
+            vec![Instruction {
+                program_id: oseme_group::ID,
+                accounts: oseme_group::accounts::JoinGroup {
+                    group: *group,
+                    member: member(group, &user_key),
+                    escrow_vault: escrow_vault(group),
+                    escrow_token_account: escrow_token(group),
+                    user_token_account: token_account(&user_key),
+                    platform_config: platform_config(),
+                    model_config: model_config(model),
+                    user_trust: user_trust(&user_key),
+                    defaulter_record: defaulter_record(&user_key),
+                    vouch: vouch(group, &user_key),
+                    user: user_key,
+                    system_program: system_program::ID,
+                    token_program: spl_token::ID,
+                }
+                .to_account_metas(None),
+                data: oseme_group::instruction::JoinGroup { full_collateral }.data(),
+            }],
+            &[user],
+        )
+        .await
+    }
+
+    pub async fn contribute(&mut self, group: &Pubkey, user: &Keypair) -> TestResult {
+        let user_key = user.pubkey();
+        let state = self.group(group).await;
+        let model = GroupModel::try_from(state.model).unwrap();
+        self.send(
+            vec![Instruction {
+                program_id: oseme_group::ID,
+                accounts: oseme_group::accounts::Contribute {
+                    group: *group,
+                    member: member(group, &user_key),
+                    escrow_vault: escrow_vault(group),
+                    escrow_token_account: escrow_token(group),
+                    contributor_token_account: token_account(&user_key),
+                    platform_config: platform_config(),
+                    model_config: model_config(model),
+                    contributor: user_key,
+                    user_trust: user_trust(&user_key),
+                    trust_authority: trust_authority(),
+                    trust_program: oseme_trust::ID,
+                    token_program: spl_token::ID,
+                    system_program: system_program::ID,
+                }
+                .to_account_metas(None),
+                data: oseme_group::instruction::Contribute { amount: state.contribution_amount }.data(),
+            }],
+            &[user],
+        )
+        .await
+    }
+
+    /// Pay out the current turn to its recipient
+    pub async fn release_payout(&mut self, group: &Pubkey) -> TestResult {
+        let state = self.group(group).await;
+        let model = GroupModel::try_from(state.model).unwrap();
+        let recipient = state.current_recipient().expect("no open turn");
+        let creator = state.creator;
+        self.mint_usdc(&recipient, 0).await;
+        let (promo, promo_redemption) = if state.promo == Pubkey::default() {
+            (None, None)
+        } else {
+            (
+                Some(state.promo),
+                Some(pda(&[b"promo-redemption", state.promo.as_ref(), creator.as_ref()])),
+            )
+        };
+        let cranker = self.ctx.payer.pubkey();
+        self.send(
+            vec![Instruction {
+                program_id: oseme_group::ID,
+                accounts: oseme_group::accounts::ReleasePayout {
+                    group: *group,
+                    escrow_vault: escrow_vault(group),
+                    escrow_token_account: escrow_token(group),
+                    recipient_token_account: token_account(&recipient),
+                    recipient,
+                    recipient_member: member(group, &recipient),
+                    platform_config: platform_config(),
+                    model_config: model_config(model),
+                    promo,
+                    promo_redemption,
+                    fee_schedule: fee_schedule(model),
+                    treasury: treasury(),
+                    fee_vault: fee_vault(),
+                    creator,
+                    creator_volume: creator_volume(&creator),
+                    creator_revenue: creator_revenue(&creator),
+                    creator_vault: creator_vault(),
+                    usdc_mint: usdc_mint(),
+                    treasury_authority: treasury_authority(),
+                    cranker,
+                    treasury_program: oseme_treasury::ID,
+                    token_program: spl_token::ID,
+                    system_program: system_program::ID,
+                }
+                .to_account_metas(None),
+                data: oseme_group::instruction::ReleasePayout { turn_index: state.current_turn_index }.data(),
+            }],
+            &[],
+        )
+        .await
+    }
+
+    pub async fn finalize_group(&mut self, group: &Pubkey) -> TestResult {
+        let creator = self.group(group).await.creator;
+        self.mint_usdc(&creator, 0).await;
+        let cranker = self.ctx.payer.pubkey();
+        self.send(
+            vec![Instruction {
+                program_id: oseme_group::ID,
+                accounts: oseme_group::accounts::FinalizeGroup {
+                    group: *group,
+                    cranker,
+                    platform_config: platform_config(),
+                    escrow_vault: escrow_vault(group),
+                    escrow_token_account: escrow_token(group),
+                    subscription: subscription(&creator),
+                    creator_token_account: token_account(&creator),
+                    treasury: treasury(),
+                    fee_vault: fee_vault(),
+                    bonus_vault: bonus_vault(),
+                    treasury_authority: treasury_authority(),
+                    treasury_program: oseme_treasury::ID,
+                    token_program: spl_token::ID,
+                }
+                .to_account_metas(None),
+                data: oseme_group::instruction::FinalizeGroup {}.data(),
+            }],
+            &[],
+        )
+        .await
+    }
+
+    /// Every member pays into the open turn and it is paid out
+    pub async fn run_turn(&mut self, group: &Pubkey, members: &[&Keypair]) {
+        for member in members {
+            self.contribute(group, member).await.unwrap();
+        }
+        self.release_payout(group).await.unwrap();
+    }
+
+    /// A group of `model` with the creator and `others` joined in that order
+    pub async fn group_with_members(
+        &mut self,
+        model: GroupModel,
+        contribution_amount: u64,
+        options: GroupOptions,
+        member_count: usize,
+    ) -> (Pubkey, Keypair, Vec<Keypair>) {
+        let creator = self.user(100 * contribution_amount).await;
+        let group = self
+            .create_group(&creator, model, contribution_amount, options)
+            .await
+            .unwrap();
+        self.join_group(&group, &creator, false).await.unwrap();
+        let mut members = vec![];
+        for _ in 1..member_count {
+            let user = self.user(100 * contribution_amount).await;
+            self.join_group(&group, &user, false).await.unwrap();
+            members.push(user);
+        }
+        (group, creator, members)
+    }
+}
+
+/// An account owned by oseme-group holding `value` under `T`'s discriminator,
+/// zero-padded to `len` like the fixed-size allocations of earlier layouts
+pub fn legacy_account<T: anchor_lang::AnchorSerialize>(discriminator: [u8; 8], value: &T, len: usize) -> Account {
+    let mut data = discriminator.to_vec();
+    value.serialize(&mut data).unwrap();
+    assert!(data.len() <= len, "fixture larger than its layout");
+    data.resize(len, 0);
+    Account {
+        lamports: solana_sdk::rent::Rent::default().minimum_balance(len),
+        data,
+        owner: oseme_group::ID,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+impl TestEnv {
+    /// `with_platform_config` passes the (already migrated) platform config
+    pub async fn migrate_account(
+        &mut self,
+        account: &Pubkey,
+        group: Option<Pubkey>,
+        with_platform_config: bool,
+    ) -> TestResult {
+        let payer = self.ctx.payer.pubkey();
+        let platform_config = Some(platform_config()).filter(|_| with_platform_config);
+        self.send(
+            vec![Instruction {
+                program_id: oseme_group::ID,
+                accounts: oseme_group::accounts::MigrateAccount {
+                    account: *account,
+                    group,
+                    platform_config,
+                    payer,
+                    system_program: system_program::ID,
+                }
+                .to_account_metas(None),
+                data: oseme_group::instruction::MigrateAccount {}.data(),
+            }],
+            &[],
+        )
+        .await
+    }
+}
+
+impl TestEnv {
+    /// Move past the open turn's grace period
+    pub async fn pass_grace_period(&mut self, group: &Pubkey) {
+        let state = self.group(group).await;
+        let config: PlatformConfig = self.fetch(&platform_config()).await;
+        let now = self.now().await;
+        let deadline = state.grace_deadline(config.paused_secs(now), config.grace_period_days);
+        self.advance(deadline - now + 1).await;
+    }
+
+    pub async fn slash_member(&mut self, group: &Pubkey, user: &Pubkey) -> TestResult {
+        let guarantee = guarantor(group, user);
+        let guarantee_account = self.account(&guarantee).await
+            .map(|account| Guarantor::try_deserialize(&mut &account.data[..]).unwrap());
+        let guarantor_token_account = guarantee_account.as_ref().map(|guarantee| guarantee.token_account);
+        let guarantor_wallet = guarantee_account.as_ref().map(|guarantee| guarantee.guarantor);
+        let cranker = self.ctx.payer.pubkey();
+        self.send(
+            vec![Instruction {
+                program_id: oseme_group::ID,
+                accounts: oseme_group::accounts::SlashMember {
+                    group: *group,
+                    member: member(group, user),
+                    escrow_vault: escrow_vault(group),
+                    escrow_token_account: escrow_token(group),
+                    guarantor: guarantee,
+                    guarantor_token_account,
+                    guarantor_wallet,
+                    guarantor_trust: guarantor_wallet.as_ref().map(user_trust),
+                    platform_config: platform_config(),
+                    vouch: vouch(group, user),
+                    debt: Some(debt(group, user)),
+                    defaulter_record: defaulter_record(user),
+                    user: *user,
+                    user_trust: user_trust(user),
+                    trust_authority: trust_authority(),
+                    cranker,
+                    trust_program: oseme_trust::ID,
+                    token_program: spl_token::ID,
+                    system_program: system_program::ID,
+                }
+                .to_account_metas(None),
+                data: oseme_group::instruction::SlashMember {}.data(),
+            }],
+            &[],
+        )
+        .await
+    }
+
+    pub async fn settle_member(&mut self, group: &Pubkey, user: &Pubkey) -> TestResult {
+        let payer = self.ctx.payer.pubkey();
+        self.send(
+            vec![Instruction {
+                program_id: oseme_group::ID,
+                accounts: oseme_group::accounts::SettleMember {
+                    group: *group,
+                    member: member(group, user),
+                    platform_config: platform_config(),
+                    user: *user,
+                    user_trust: user_trust(user),
+                    trust_authority: trust_authority(),
+                    payer,
+                    trust_program: oseme_trust::ID,
+                    system_program: system_program::ID,
+                }
+                .to_account_metas(None),
+                data: oseme_group::instruction::SettleMember {}.data(),
+            }],
+            &[],
+        )
+        .await
+    }
+
+    pub async fn withdraw_stake(&mut self, group: &Pubkey, user: &Keypair) -> TestResult {
+        let user_key = user.pubkey();
+        self.send(
+            vec![Instruction {
+                program_id: oseme_group::ID,
+                accounts: oseme_group::accounts::WithdrawStake {
+                    group: *group,
+                    member: member(group, &user_key),
+                    escrow_vault: escrow_vault(group),
+                    escrow_token_account: escrow_token(group),
+                    user_token_account: token_account(&user_key),
+                    user: user_key,
+                    token_program: spl_token::ID,
+                }
+                .to_account_metas(None),
+                data: oseme_group::instruction::WithdrawStake {}.data(),
+            }],
+            &[user],
+        )
+        .await
+    }
+
+    pub fn repay_debt_instruction(group: &Pubkey, debtor: &Pubkey, payer: &Pubkey, amount: u64, with_treasury: bool) -> Instruction {
+        let treasury_account = |address: Pubkey| with_treasury.then_some(address);
+        Instruction {
+            program_id: oseme_group::ID,
+            accounts: oseme_group::accounts::RepayDebt {
+                group: *group,
+                debt: debt(group, debtor),
+                defaulter_record: defaulter_record(debtor),
+                escrow_vault: escrow_vault(group),
+                escrow_token_account: escrow_token(group),
+                payer_token_account: token_account(payer),
+                payer: *payer,
+                treasury: treasury_account(treasury()),
+                fee_vault: treasury_account(fee_vault()),
+                treasury_authority: treasury_account(treasury_authority()),
+                treasury_program: treasury_account(oseme_treasury::ID),
+                token_program: spl_token::ID,
+            }
+            .to_account_metas(None),
+            data: oseme_group::instruction::RepayDebt { amount }.data(),
+        }
+    }
+
+    pub async fn repay_debt(&mut self, group: &Pubkey, debtor: &Pubkey, payer: &Keypair, amount: u64) -> TestResult {
+        let instruction = Self::repay_debt_instruction(group, debtor, &payer.pubkey(), amount, true);
+        self.send(vec![instruction], &[payer]).await
+    }
+
+    pub async fn claim_recovery(&mut self, group: &Pubkey, user: &Keypair) -> TestResult {
+        let user_key = user.pubkey();
+        self.send(
+            vec![Instruction {
+                program_id: oseme_group::ID,
+                accounts: oseme_group::accounts::ClaimRecovery {
+                    group: *group,
+                    member: member(group, &user_key),
+                    escrow_vault: escrow_vault(group),
+                    escrow_token_account: escrow_token(group),
+                    user_token_account: token_account(&user_key),
+                    user: user_key,
+                    token_program: spl_token::ID,
+                }
+                .to_account_metas(None),
+                data: oseme_group::instruction::ClaimRecovery {}.data(),
+            }],
+            &[user],
+        )
+        .await
+    }
+
+    pub async fn write_off_debt(&mut self, group: &Pubkey, debtor: &Pubkey, authority: &Keypair) -> TestResult {
+        self.send(
+            vec![Instruction {
+                program_id: oseme_group::ID,
+                accounts: oseme_group::accounts::WriteOffDebt {
+                    group: *group,
+                    debt: debt(group, debtor),
+                    escrow_vault: escrow_vault(group),
+                    platform_config: platform_config(),
+                    authority: authority.pubkey(),
+                }
+                .to_account_metas(None),
+                data: oseme_group::instruction::WriteOffDebt {}.data(),
+            }],
+            &[authority],
+        )
+        .await
+    }
+
+    pub async fn close_member(&mut self, group: &Pubkey, user: &Keypair) -> TestResult {
+        let user_key = user.pubkey();
+        self.send(
+            vec![Instruction {
+                program_id: oseme_group::ID,
+                accounts: oseme_group::accounts::CloseMember {
+                    member: member(group, &user_key),
+                    group: *group,
+                    user: user_key,
+                }
+                .to_account_metas(None),
+                data: oseme_group::instruction::CloseMember {}.data(),
+            }],
+            &[user],
+        )
+        .await
+    }
+
+    pub async fn close_group(&mut self, group: &Pubkey, creator: &Keypair) -> TestResult {
+        self.send(
+            vec![Instruction {
+                program_id: oseme_group::ID,
+                accounts: oseme_group::accounts::CloseGroup {
+                    group: *group,
+                    escrow_vault: escrow_vault(group),
+                    escrow_token_account: escrow_token(group),
+                    creator: creator.pubkey(),
+                    token_program: spl_token::ID,
+                }
+                .to_account_metas(None),
+                data: oseme_group::instruction::CloseGroup {}.data(),
+            }],
+            &[creator],
+        )
+        .await
+    }
+
+    /// A finished three-member Trust group in which the second member, already
+    /// paid out, skipped the last turn: they owe the part their stake did not
+    /// cover and the last recipient was shorted by the same amount.
+    /// Returns the group, creator, debtor and shorted member.
+    pub async fn finalized_group_with_debt(&mut self) -> (Pubkey, Keypair, Keypair, Keypair) {
+        let options = GroupOptions {
+            member_cap: Some(3),
+            cycle_days: Some(1),
+            ..Default::default()
+        };
+        let (group, creator, mut others) = self.group_with_members(GroupModel::Trust, 10 * USDC, options, 3).await;
+        let shorted = others.pop().unwrap();
+        let debtor = others.pop().unwrap();
+
+        self.run_turn(&group, &[&creator, &debtor, &shorted]).await;
+        self.run_turn(&group, &[&creator, &debtor, &shorted]).await;
+
+        self.contribute(&group, &creator).await.unwrap();
+        self.contribute(&group, &shorted).await.unwrap();
+        self.pass_grace_period(&group).await;
+        self.slash_member(&group, &debtor.pubkey()).await.unwrap();
+        self.release_payout(&group).await.unwrap();
+        self.finalize_group(&group).await.unwrap();
+
+        (group, creator, debtor, shorted)
+    }
+}
+
+impl TestEnv {
+    /// Simulate a view instruction and decode its return data
+    pub async fn view<T: anchor_lang::AnchorDeserialize>(&mut self, instruction: Instruction) -> T {
+        let blockhash = self.ctx.get_new_latest_blockhash().await.unwrap();
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&self.ctx.payer.pubkey()),
+            &[&self.ctx.payer],
+            blockhash,
+        );
+        let simulation = self.ctx.banks_client.simulate_transaction(transaction).await.unwrap();
+        simulation.result.unwrap().unwrap();
+        let return_data = simulation.simulation_details.unwrap().return_data.expect("no return data");
+        T::deserialize(&mut &return_data.data[..]).unwrap()
+    }
+
+    pub async fn group_status(&mut self, group: &Pubkey) -> oseme_group::instructions::GroupStatusView {
+        self.view(Instruction {
+            program_id: oseme_group::ID,
+            accounts: oseme_group::accounts::GetGroupStatus {
+                group: *group,
+                platform_config: platform_config(),
+            }
+            .to_account_metas(None),
+            data: oseme_group::instruction::GetGroupStatus {}.data(),
+        })
+        .await
+    }
+
+    pub async fn member_obligations(
+        &mut self,
+        group: &Pubkey,
+        user: &Pubkey,
+    ) -> oseme_group::instructions::MemberObligationsView {
+        let model = GroupModel::try_from(self.group(group).await.model).unwrap();
+        self.view(Instruction {
+            program_id: oseme_group::ID,
+            accounts: oseme_group::accounts::GetMemberObligations {
+                group: *group,
+                member: member(group, user),
+                platform_config: platform_config(),
+                model_config: model_config(model),
+            }
+            .to_account_metas(None),
+            data: oseme_group::instruction::GetMemberObligations {}.data(),
+        })
+        .await
+    }
+}
+
+impl TestEnv {
+    /// Change a model's config through `update_model_config`
+    pub async fn update_model_config(&mut self, model: GroupModel, update: impl FnOnce(&mut ModelConfig)) -> TestResult {
+        let mut config: ModelConfig = self.fetch(&model_config(model)).await;
+        update(&mut config);
+        let authority = self.authority.pubkey();
+        self.send_as_authority(vec![Instruction {
+            program_id: oseme_group::ID,
+            accounts: oseme_group::accounts::UpdateModelConfig {
+                model_config: model_config(model),
+                platform_config: platform_config(),
+                authority,
+            }
+            .to_account_metas(None),
+            data: oseme_group::instruction::UpdateModelConfig { config }.data(),
+        }])
+        .await
+    }
+
+    pub fn vouch_for_member_instruction(group: &Pubkey, voucher: &Pubkey, newcomer: &Pubkey, amount: u64) -> Instruction {
+        Instruction {
+            program_id: oseme_group::ID,
+            accounts: oseme_group::accounts::VouchForMember {
+                group: *group,
+                voucher_member: member(group, voucher),
+                escrow_vault: escrow_vault(group),
+                vouch: vouch(group, newcomer),
+                voucher: *voucher,
+                member: *newcomer,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: oseme_group::instruction::VouchForMember { amount }.data(),
+        }
+    }
+
+    pub async fn vouch_for_member(&mut self, group: &Pubkey, voucher: &Keypair, newcomer: &Keypair, amount: u64) -> TestResult {
+        let instruction = Self::vouch_for_member_instruction(group, &voucher.pubkey(), &newcomer.pubkey(), amount);
+        self.send(vec![instruction], &[voucher, newcomer]).await
+    }
+
+    pub async fn release_vouch(&mut self, group: &Pubkey, voucher: &Keypair, newcomer: &Pubkey) -> TestResult {
+        let voucher_key = voucher.pubkey();
+        self.send(
+            vec![Instruction {
+                program_id: oseme_group::ID,
+                accounts: oseme_group::accounts::ReleaseVouch {
+                    group: *group,
+                    vouch: vouch(group, newcomer),
+                    escrow_vault: escrow_vault(group),
+                    escrow_token_account: escrow_token(group),
+                    voucher_token_account: token_account(&voucher_key),
+                    platform_config: platform_config(),
+                    user_trust: user_trust(&voucher_key),
+                    trust_authority: trust_authority(),
+                    voucher: voucher_key,
+                    trust_program: oseme_trust::ID,
+                    token_program: spl_token::ID,
+                    system_program: system_program::ID,
+                }
+                .to_account_metas(None),
+                data: oseme_group::instruction::ReleaseVouch {}.data(),
+            }],
+            &[voucher],
+        )
+        .await
+    }
+}
+
+impl TestEnv {
+    /// Guarantee `user`'s membership from `guarantor`'s associated token account
+    pub async fn add_guarantor(&mut self, group: &Pubkey, user: &Keypair, guarantor_wallet: &Keypair, allowance: u64) -> TestResult {
+        let user_key = user.pubkey();
+        let guarantor_key = guarantor_wallet.pubkey();
+        self.send(
+            vec![Instruction {
+                program_id: oseme_group::ID,
+                accounts: oseme_group::accounts::AddGuarantor {
+                    group: *group,
+                    member: member(group, &user_key),
+                    guarantor: guarantor(group, &user_key),
+                    escrow_vault: escrow_vault(group),
+                    guarantor_token_account: token_account(&guarantor_key),
+                    platform_config: platform_config(),
+                    user: user_key,
+                    guarantor_wallet: guarantor_key,
+                    token_program: spl_token::ID,
+                    system_program: system_program::ID,
+                }
+                .to_account_metas(None),
+                data: oseme_group::instruction::AddGuarantor { allowance }.data(),
+            }],
+            &[user, guarantor_wallet],
+        )
+        .await
+    }
+}
+
+impl TestEnv {
+    /// Mint straight into a token account that already exists, e.g. a vault
+    pub async fn mint_to(&mut self, account: &Pubkey, amount: u64) {
+        let payer = self.ctx.payer.pubkey();
+        let mint_to = spl_token::instruction::mint_to(&spl_token::ID, &usdc_mint(), account, &payer, &[], amount).unwrap();
+        self.send(vec![mint_to], &[]).await.unwrap();
+    }
+
+    pub async fn withdraw_fees(&mut self, authority: &Keypair, amount: u64) -> TestResult {
+        let destination = self.mint_usdc(&authority.pubkey(), 0).await;
+        self.send(
+            vec![Instruction {
+                program_id: oseme_treasury::ID,
+                accounts: oseme_treasury::accounts::WithdrawFees {
+                    treasury: treasury(),
+                    fee_vault: fee_vault(),
+                    destination,
+                    authority: authority.pubkey(),
+                    token_program: spl_token::ID,
+                }
+                .to_account_metas(None),
+                data: oseme_treasury::instruction::WithdrawFees { amount }.data(),
+            }],
+            &[authority],
+        )
+        .await
+    }
+
+    pub async fn update_treasury(&mut self, withdrawal_limit: u64, withdrawal_period_days: u16) -> TestResult {
+        let authority = self.authority.pubkey();
+        self.send_as_authority(vec![Instruction {
+            program_id: oseme_treasury::ID,
+            accounts: oseme_treasury::accounts::UpdateTreasury { treasury: treasury(), authority }.to_account_metas(None),
+            data: oseme_treasury::instruction::UpdateTreasury {
+                withdrawal_limit,
+                withdrawal_period_days,
+            }
+            .data(),
+        }])
+        .await
+    }
+}
+
+impl TestEnv {
+    /// Pay the completion bonuses of `users` in one batch
+    pub async fn distribute_bonuses(&mut self, group: &Pubkey, users: &[Pubkey]) -> TestResult {
+        let mut accounts = oseme_group::accounts::DistributeBonuses {
+            group: *group,
+            escrow_vault: escrow_vault(group),
+            escrow_token_account: escrow_token(group),
+            token_program: spl_token::ID,
+        }
+        .to_account_metas(None);
+        for user in users {
+            accounts.push(AccountMeta::new(member(group, user), false));
+            accounts.push(AccountMeta::new(token_account(user), false));
+        }
+        self.send(
+            vec![Instruction {
+                program_id: oseme_group::ID,
+                accounts,
+                data: oseme_group::instruction::DistributeBonuses {}.data(),
+            }],
+            &[],
+        )
+        .await
+    }
+}
+
+impl TestEnv {
+    pub async fn withdraw_creator_fees(&mut self, creator: &Keypair, amount: u64) -> TestResult {
+        let creator_key = creator.pubkey();
+        let destination = self.mint_usdc(&creator_key, 0).await;
+        self.send(
+            vec![Instruction {
+                program_id: oseme_treasury::ID,
+                accounts: oseme_treasury::accounts::WithdrawCreatorFees {
+                    treasury: treasury(),
+                    creator_revenue: creator_revenue(&creator_key),
+                    creator_vault: creator_vault(),
+                    destination,
+                    creator: creator_key,
+                    token_program: spl_token::ID,
+                }
+                .to_account_metas(None),
+                data: oseme_treasury::instruction::WithdrawCreatorFees { amount }.data(),
+            }],
+            &[creator],
+        )
+        .await
+    }
+}
+
+impl TestEnv {
+    pub async fn set_fee_schedule(&mut self, model: GroupModel, tiers: Vec<FeeTier>) -> TestResult {
+        let authority = self.authority.pubkey();
+        self.send_as_authority(vec![Instruction {
+            program_id: oseme_group::ID,
+            accounts: oseme_group::accounts::SetFeeSchedule {
+                fee_schedule: fee_schedule(model),
+                platform_config: platform_config(),
+                authority,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: oseme_group::instruction::SetFeeSchedule { model, tiers }.data(),
+        }])
+        .await
+    }
+}
+
+pub fn promo(code: &[u8; 16]) -> Pubkey {
+    pda(&[b"promo", code])
+}
+
+pub fn promo_redemption(promo: &Pubkey, creator: &Pubkey) -> Pubkey {
+    pda(&[b"promo-redemption", promo.as_ref(), creator.as_ref()])
+}
+
+impl TestEnv {
+    /// Create a promo open for a week
+    pub async fn create_promo(&mut self, code: [u8; 16], waiver_bps: u16, max_uses: u32) -> Pubkey {
+        let authority = self.authority.pubkey();
+        let expires_at = self.now().await + 7 * DAY;
+        self.send_as_authority(vec![Instruction {
+            program_id: oseme_group::ID,
+            accounts: oseme_group::accounts::CreatePromo {
+                promo: promo(&code),
+                platform_config: platform_config(),
+                authority,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: oseme_group::instruction::CreatePromo {
+                code,
+                waiver_bps,
+                max_uses,
+                expires_at,
+                per_wallet_limit: 0,
+            }
+            .data(),
+        }])
+        .await
+        .unwrap();
+        promo(&code)
+    }
+}
+
+impl TestEnv {
+    pub async fn reconcile_escrow(&mut self, group: &Pubkey) -> TestResult {
+        self.send(
+            vec![Instruction {
+                program_id: oseme_group::ID,
+                accounts: oseme_group::accounts::ReconcileEscrow {
+                    group: *group,
+                    escrow_vault: escrow_vault(group),
+                    escrow_token_account: escrow_token(group),
+                    platform_config: platform_config(),
+                }
+                .to_account_metas(None),
+                data: oseme_group::instruction::ReconcileEscrow {}.data(),
+            }],
+            &[],
+        )
+        .await
+    }
+}
+
+impl TestEnv {
+    pub async fn pause_platform(&mut self) -> TestResult {
+        let authority = self.authority.pubkey();
+        self.send_as_authority(vec![Instruction {
+            program_id: oseme_group::ID,
+            accounts: oseme_group::accounts::PausePlatform { platform_config: platform_config(), authority }
+                .to_account_metas(None),
+            data: oseme_group::instruction::PausePlatform {}.data(),
+        }])
+        .await
+    }
+
+    pub async fn resume_platform(&mut self) -> TestResult {
+        let authority = self.authority.pubkey();
+        self.send_as_authority(vec![Instruction {
+            program_id: oseme_group::ID,
+            accounts: oseme_group::accounts::ResumePlatform { platform_config: platform_config(), authority }
+                .to_account_metas(None),
+            data: oseme_group::instruction::ResumePlatform {}.data(),
+        }])
+        .await
+    }
+
+    pub async fn draw_collateral(&mut self, group: &Pubkey, user: &Pubkey) -> TestResult {
+        self.send(
+            vec![Instruction {
+                program_id: oseme_group::ID,
+                accounts: oseme_group::accounts::DrawCollateral {
+                    group: *group,
+                    member: member(group, user),
+                    escrow_vault: escrow_vault(group),
+                    escrow_token_account: escrow_token(group),
+                    user_token_account: token_account(user),
+                    platform_config: platform_config(),
+                    token_program: spl_token::ID,
+                }
+                .to_account_metas(None),
+                data: oseme_group::instruction::DrawCollateral {}.data(),
+            }],
+            &[],
+        )
+        .await
+    }
+}
+
+impl TestEnv {
+    pub async fn pause_group(&mut self, group: &Pubkey, reason: PauseReason) -> TestResult {
+        let creator = self.group(group).await.creator;
+        let authority = self.authority.pubkey();
+        self.send_as_authority(vec![Instruction {
+            program_id: oseme_group::ID,
+            accounts: oseme_group::accounts::PauseGroup {
+                group: *group,
+                pause_request: None,
+                creator,
+                platform_config: platform_config(),
+                authority,
+            }
+            .to_account_metas(None),
+            data: oseme_group::instruction::PauseGroup { reason }.data(),
+        }])
+        .await
+    }
+
+    pub async fn resume_group(&mut self, group: &Pubkey) -> TestResult {
+        let authority = self.authority.pubkey();
+        self.send_as_authority(vec![Instruction {
+            program_id: oseme_group::ID,
+            accounts: oseme_group::accounts::ResumeGroup { group: *group, platform_config: platform_config(), authority }
+                .to_account_metas(None),
+            data: oseme_group::instruction::ResumeGroup {}.data(),
+        }])
+        .await
+    }
+}
@@ -0,0 +1,50 @@
+//! `contribute`
+
+use solana_sdk::signature::Signer;
+
+use crate::common::*;
+
+const CONTRIBUTION: u64 = 10 * USDC;
+const EXPOSURE_STAKE_BPS: u16 = 1_000;
+
+#[tokio::test]
+async fn a_group_below_its_cap_counts_stake_against_its_payout_order() {
+    let mut env = TestEnv::start().await;
+    env.update_model_config(GroupModel::Trust, |config| config.exposure_stake_bps = EXPOSURE_STAKE_BPS)
+        .await
+        .unwrap();
+    let options = GroupOptions {
+        member_cap: Some(5),
+        cycle_days: Some(1),
+        ..Default::default()
+    };
+    let (group, creator, others) = env.group_with_members(GroupModel::Trust, CONTRIBUTION, options, 3).await;
+    let first = &others[0];
+    let config: ModelConfig = env.fetch(&model_config(GroupModel::Trust)).await;
+
+    // The group may still fill up, so position 1 stakes for the three turns
+    // after it that a full group would have
+    let staked = env.fetch::<Member>(&member(&group, &first.pubkey())).await.stake_amount;
+    assert_eq!(staked, config.stake_for(CONTRIBUTION, 3).unwrap());
+    assert_eq!(env.member_obligations(&group, &first.pubkey()).await.required_stake, staked);
+
+    // The first contribution fixes a three-turn rotation
+    env.contribute(&group, &creator).await.unwrap();
+    let required = config.stake_for(CONTRIBUTION, 1).unwrap();
+    assert_eq!(env.group(&group).await.turn_count(), 3);
+    assert_eq!(env.member_obligations(&group, &first.pubkey()).await.required_stake, required);
+
+    env.contribute(&group, first).await.unwrap();
+    env.contribute(&group, &others[1]).await.unwrap();
+    env.release_payout(&group).await.unwrap();
+    env.run_turn(&group, &[&creator, first, &others[1]]).await;
+
+    // Once paid out, the last contribution leaves nothing to stake for
+    let balance = env.balance(&token_account(&first.pubkey())).await;
+    env.contribute(&group, first).await.unwrap();
+    let released: Member = env.fetch(&member(&group, &first.pubkey())).await;
+    let required = config.stake_for(CONTRIBUTION, 0).unwrap();
+    assert_eq!(released.stake_amount, required);
+    assert_eq!(env.balance(&token_account(&first.pubkey())).await, balance - CONTRIBUTION + staked - required);
+    assert_eq!(env.member_obligations(&group, &first.pubkey()).await.required_stake, required);
+}
@@ -0,0 +1,41 @@
+//! `create_promo`, and the fee waivers `release_payout` draws from it
+
+use solana_sdk::signature::Signer;
+
+use crate::common::*;
+
+const CONTRIBUTION: u64 = 10 * USDC;
+
+#[tokio::test]
+async fn each_waived_payout_uses_up_the_promo() {
+    let mut env = TestEnv::start().await;
+    let promo_key = env.create_promo(*b"LAUNCH\0\0\0\0\0\0\0\0\0\0", 5_000, 1).await;
+    let options = GroupOptions { member_cap: Some(2), promo: Some(promo_key), ..Default::default() };
+    let (group, creator, others) = env.group_with_members(GroupModel::Trust, CONTRIBUTION, options, 2).await;
+    let promo: Promo = env.fetch(&promo_key).await;
+    assert_eq!(promo.uses_remaining, 1);
+    assert_eq!(promo.groups_attached, 1);
+    let fee = 2 * CONTRIBUTION * model_params(GroupModel::Trust).fee_bps as u64 / 10_000;
+
+    // The only use waives half the fee of the first payout
+    let recipient_balance = env.balance(&token_account(&creator.pubkey())).await;
+    env.run_turn(&group, &[&creator, &others[0]]).await;
+    let received = env.balance(&token_account(&creator.pubkey())).await + CONTRIBUTION - recipient_balance;
+    assert_eq!(received, 2 * CONTRIBUTION - fee / 2);
+    let promo: Promo = env.fetch(&promo_key).await;
+    assert_eq!(promo.uses_remaining, 0);
+    assert_eq!(promo.total_waived, fee / 2);
+    let redemption: PromoRedemption = env.fetch(&promo_redemption(&promo_key, &creator.pubkey())).await;
+    assert_eq!(redemption.payouts_waived, 1);
+    assert_eq!(redemption.total_waived, fee / 2);
+
+    // With no uses left the second payout is charged in full
+    let fees = env.balance(&fee_vault()).await + env.balance(&creator_vault()).await;
+    env.run_turn(&group, &[&creator, &others[0]]).await;
+    assert_eq!(env.balance(&fee_vault()).await + env.balance(&creator_vault()).await, fees + fee);
+    let promo: Promo = env.fetch(&promo_key).await;
+    assert_eq!(promo.uses_remaining, 0);
+    assert_eq!(promo.total_waived, fee / 2);
+    let redemption: PromoRedemption = env.fetch(&promo_redemption(&promo_key, &creator.pubkey())).await;
+    assert_eq!(redemption.payouts_waived, 1);
+}
@@ -0,0 +1,128 @@
+//! Completion bonuses reserved by `finalize_group` and paid by
+//! `distribute_bonuses` or `withdraw_stake`
+
+use anchor_lang::prelude::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::common::*;
+
+const CONTRIBUTION: u64 = 10 * USDC;
+
+/// A finalized three-member Trust group; `skip` misses the last turn
+async fn finalized_group(env: &mut TestEnv, bonus_pool: u64, skip: Option<usize>) -> (Pubkey, Keypair, Vec<Keypair>) {
+    if bonus_pool > 0 {
+        env.fund_bonus_pool(bonus_pool).await.unwrap();
+    }
+    let options = GroupOptions {
+        member_cap: Some(3),
+        cycle_days: Some(1),
+        ..Default::default()
+    };
+    let (group, creator, others) = env.group_with_members(GroupModel::Trust, CONTRIBUTION, options, 3).await;
+    env.run_turn(&group, &[&creator, &others[0], &others[1]]).await;
+    env.run_turn(&group, &[&creator, &others[0], &others[1]]).await;
+
+    env.contribute(&group, &creator).await.unwrap();
+    for (index, other) in others.iter().enumerate() {
+        if skip != Some(index) {
+            env.contribute(&group, other).await.unwrap();
+        }
+    }
+    if let Some(index) = skip {
+        env.pass_grace_period(&group).await;
+        env.slash_member(&group, &others[index].pubkey()).await.unwrap();
+    }
+    env.release_payout(&group).await.unwrap();
+    env.finalize_group(&group).await.unwrap();
+    (group, creator, others)
+}
+
+#[tokio::test]
+async fn finalize_reserves_the_bonus_for_compliant_stake() {
+    let mut env = TestEnv::start().await;
+    let (group, creator, others) = finalized_group(&mut env, 100 * USDC, None).await;
+    let stakes = [
+        env.fetch::<Member>(&member(&group, &others[0].pubkey())).await.stake_amount,
+        env.fetch::<Member>(&member(&group, &others[1].pubkey())).await.stake_amount,
+    ];
+
+    let vault: EscrowVault = env.fetch(&escrow_vault(&group)).await;
+    assert_eq!(vault.compliant_stake, stakes[0] + stakes[1]);
+    assert_eq!(vault.bonus_reserved, vault.bonus_owed(STAKE_BONUS_BPS));
+    assert_eq!(vault.bonus_reserved, (stakes[0] + stakes[1]) * STAKE_BONUS_BPS as u64 / 10_000);
+
+    let users: Vec<Pubkey> = [&creator, &others[0], &others[1]].iter().map(|user| user.pubkey()).collect();
+    let balances = [
+        env.balance(&token_account(&users[1])).await,
+        env.balance(&token_account(&users[2])).await,
+    ];
+    env.distribute_bonuses(&group, &users).await.unwrap();
+    for (index, user) in users[1..].iter().enumerate() {
+        let bonus = stakes[index] * STAKE_BONUS_BPS as u64 / 10_000;
+        assert_eq!(env.balance(&token_account(user)).await, balances[index] + bonus);
+        assert!(env.fetch::<Member>(&member(&group, user)).await.bonus_settled);
+    }
+    let vault: EscrowVault = env.fetch(&escrow_vault(&group)).await;
+    assert_eq!(vault.bonus_reserved, 0);
+    assert_eq!(vault.compliant_stake, 0);
+
+    // Settled members are skipped by a second batch
+    env.distribute_bonuses(&group, &users).await.unwrap();
+    assert_eq!(env.balance(&token_account(&users[1])).await, balances[0] + stakes[0] * STAKE_BONUS_BPS as u64 / 10_000);
+}
+
+#[tokio::test]
+async fn a_short_pool_is_shared_pro_rata_and_the_last_member_takes_the_remainder() {
+    let mut env = TestEnv::start().await;
+    let (group, _creator, others) = finalized_group(&mut env, 3, None).await;
+
+    let vault: EscrowVault = env.fetch(&escrow_vault(&group)).await;
+    assert!(vault.bonus_owed(STAKE_BONUS_BPS) > 3);
+    assert_eq!(vault.bonus_reserved, 3);
+
+    // Equal stakes: the first takes half rounded down, the last the rest
+    let balance = env.balance(&token_account(&others[0].pubkey())).await;
+    env.distribute_bonuses(&group, &[others[0].pubkey()]).await.unwrap();
+    assert_eq!(env.balance(&token_account(&others[0].pubkey())).await, balance + 1);
+
+    let stake = env.fetch::<Member>(&member(&group, &others[1].pubkey())).await.stake_amount;
+    let balance = env.balance(&token_account(&others[1].pubkey())).await;
+    env.withdraw_stake(&group, &others[1]).await.unwrap();
+    assert_eq!(env.balance(&token_account(&others[1].pubkey())).await, balance + stake + 2);
+    assert_eq!(env.fetch::<EscrowVault>(&escrow_vault(&group)).await.bonus_reserved, 0);
+}
+
+#[tokio::test]
+async fn a_member_with_a_missed_turn_earns_no_bonus() {
+    let mut env = TestEnv::start().await;
+    let (group, _creator, others) = finalized_group(&mut env, 100 * USDC, Some(0)).await;
+    let stake = env.fetch::<Member>(&member(&group, &others[1].pubkey())).await.stake_amount;
+
+    let vault: EscrowVault = env.fetch(&escrow_vault(&group)).await;
+    assert_eq!(vault.compliant_stake, stake);
+
+    let balances = [
+        env.balance(&token_account(&others[0].pubkey())).await,
+        env.balance(&token_account(&others[1].pubkey())).await,
+    ];
+    env.distribute_bonuses(&group, &[others[0].pubkey(), others[1].pubkey()]).await.unwrap();
+    assert_eq!(env.balance(&token_account(&others[0].pubkey())).await, balances[0]);
+    assert_eq!(
+        env.balance(&token_account(&others[1].pubkey())).await,
+        balances[1] + stake * STAKE_BONUS_BPS as u64 / 10_000,
+    );
+    assert!(!env.fetch::<Member>(&member(&group, &others[0].pubkey())).await.bonus_settled);
+}
+
+#[tokio::test]
+async fn bonuses_are_paid_only_once_finalized() {
+    let mut env = TestEnv::start().await;
+    let (group, creator, others) = env
+        .group_with_members(GroupModel::Trust, CONTRIBUTION, GroupOptions { member_cap: Some(2), ..Default::default() }, 2)
+        .await;
+
+    assert_group_error(
+        env.distribute_bonuses(&group, &[creator.pubkey(), others[0].pubkey()]).await,
+        OsemeGroupError::GroupNotClosedOut,
+    );
+}
@@ -0,0 +1,31 @@
+//! `draw_collateral` while the platform is paused
+
+use solana_sdk::signature::Signer;
+
+use crate::common::*;
+
+const CONTRIBUTION: u64 = 10 * USDC;
+
+#[tokio::test]
+async fn collateral_is_not_drawn_while_the_platform_is_paused() {
+    let mut env = TestEnv::start().await;
+    let creator = env.user(100 * CONTRIBUTION).await;
+    let options = GroupOptions { member_cap: Some(2), ..Default::default() };
+    let group = env.create_group(&creator, GroupModel::Trust, CONTRIBUTION, options).await.unwrap();
+    env.join_group(&group, &creator, false).await.unwrap();
+    let collateralized = env.user(100 * CONTRIBUTION).await;
+    env.join_group(&group, &collateralized, true).await.unwrap();
+
+    env.pause_platform().await.unwrap();
+    assert_group_error(
+        env.draw_collateral(&group, &collateralized.pubkey()).await,
+        OsemeGroupError::PlatformPaused,
+    );
+    let state: Member = env.fetch(&member(&group, &collateralized.pubkey())).await;
+    assert_eq!(state.collateral_balance, 2 * CONTRIBUTION);
+
+    env.resume_platform().await.unwrap();
+    env.draw_collateral(&group, &collateralized.pubkey()).await.unwrap();
+    let state: Member = env.fetch(&member(&group, &collateralized.pubkey())).await;
+    assert_eq!(state.collateral_balance, CONTRIBUTION);
+}
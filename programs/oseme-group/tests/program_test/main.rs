@@ -0,0 +1,22 @@
+//! Instruction behavior tests run against the native programs
+
+mod common;
+
+mod add_guarantor;
+mod close_group;
+mod close_member;
+mod contribute;
+mod distribute_bonuses;
+mod views;
+mod vouch_for_member;
+mod withdraw_creator_fees;
+mod withdraw_fees;
+mod migrate_account;
+mod process_rebate;
+mod repay_debt;
+mod slash_member;
+mod set_fee_schedule;
+mod create_promo;
+mod reconcile_escrow;
+mod draw_collateral;
+mod pause_group;
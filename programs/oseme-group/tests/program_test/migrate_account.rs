@@ -0,0 +1,217 @@
+//! `migrate_account` against fixtures in the baseline, unversioned layouts
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::Discriminator;
+use oseme_group::legacy::{EscrowVaultV0, GroupV0, MemberV0, PlatformConfigV0};
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::common::*;
+
+const TURN_START: i64 = 1_700_000_000;
+
+struct Fixture {
+    group: Pubkey,
+    creator: Pubkey,
+    payout_order: Vec<Pubkey>,
+    platform_authority: Pubkey,
+}
+
+impl Fixture {
+    fn new() -> Self {
+        let creator = Keypair::new().pubkey();
+        Self {
+            group: pda(&[b"group", creator.as_ref(), &TURN_START.to_le_bytes()]),
+            creator,
+            payout_order: vec![creator, Keypair::new().pubkey(), Keypair::new().pubkey()],
+            platform_authority: Keypair::new().pubkey(),
+        }
+    }
+
+    fn platform_config(&self) -> PlatformConfigV0 {
+        PlatformConfigV0 {
+            authority: self.platform_authority,
+            fee_bps: 250,
+            trust_subscription_price: TRUST_SUBSCRIPTION_PRICE,
+            super_trust_subscription_price: SUPER_TRUST_SUBSCRIPTION_PRICE,
+            basic_group_limit: 5,
+            basic_per_creator_limit: 1,
+            grace_period_days: 2,
+            trust_penalty: -5,
+            trust_bonus: 2,
+            stake_bonus_bps: 100,
+            kyc_threshold: 1_000 * USDC,
+            bonus_pool: 0,
+            usdc_mint: usdc_mint(),
+            bump: Pubkey::find_program_address(&[b"platform-config"], &oseme_group::ID).1,
+        }
+    }
+
+    fn group(&self) -> GroupV0 {
+        GroupV0 {
+            group_id: TURN_START as u64,
+            model: GroupModel::Trust,
+            creator: self.creator,
+            member_cap: 10,
+            current_turn_index: 1,
+            cycle_days: 7,
+            payout_order: self.payout_order.clone(),
+            escrow_vault: escrow_vault(&self.group),
+            stake_vault: None,
+            status: GroupStatus::Active,
+            total_members: 3,
+            current_turn_start: TURN_START + 7 * DAY,
+            contribution_amount: 10 * USDC,
+            total_pool: 30 * USDC,
+            trust_score: 100,
+            created_at: TURN_START,
+            bump: 253,
+        }
+    }
+
+    fn member(&self, position: usize, contributed_turns: Vec<bool>) -> MemberV0 {
+        MemberV0 {
+            group: self.group,
+            user: self.payout_order[position],
+            stake_amount: 2 * USDC,
+            contributed_turns,
+            missed_count: 0,
+            trust_delta: 0,
+            join_timestamp: TURN_START,
+            is_creator: position == 0,
+            bump: 252,
+        }
+    }
+
+    fn escrow_vault(&self) -> EscrowVaultV0 {
+        EscrowVaultV0 {
+            group: self.group,
+            vault_authority: escrow_vault(&self.group),
+            current_balance: 20 * USDC,
+            bump: 251,
+        }
+    }
+
+    fn accounts(&self) -> Vec<(Pubkey, solana_sdk::account::Account)> {
+        vec![
+            (
+                platform_config(),
+                legacy_account(PlatformConfig::DISCRIMINATOR, &self.platform_config(), PlatformConfigV0::LEN),
+            ),
+            (self.group, legacy_account(Group::DISCRIMINATOR, &self.group(), GroupV0::LEN)),
+            (
+                member(&self.group, &self.payout_order[1]),
+                legacy_account(Member::DISCRIMINATOR, &self.member(1, vec![true, true]), MemberV0::LEN),
+            ),
+            (
+                escrow_vault(&self.group),
+                legacy_account(EscrowVault::DISCRIMINATOR, &self.escrow_vault(), EscrowVaultV0::LEN),
+            ),
+        ]
+    }
+}
+
+#[tokio::test]
+async fn migrates_v0_platform_config() {
+    let fixture = Fixture::new();
+    let mut env = TestEnv::start_bare(fixture.accounts()).await;
+
+    env.migrate_account(&platform_config(), None, false).await.unwrap();
+
+    let config: PlatformConfig = env.fetch(&platform_config()).await;
+    assert_eq!(config.version, PlatformConfig::VERSION);
+    assert_eq!(config.authority, fixture.platform_authority);
+    assert_eq!(config.fee_bps, 250);
+    assert_eq!(config.super_trust_subscription_price, SUPER_TRUST_SUBSCRIPTION_PRICE);
+    assert_eq!(config.grace_period_days, 2);
+    assert_eq!(config.trust_penalty, -5);
+    assert_eq!(config.stake_bonus_bps, 100);
+    assert_eq!(config.usdc_mint, usdc_mint());
+    assert_eq!(config.rebate_bps, 0);
+    assert!(!config.is_paused());
+
+    let account = env.account(&platform_config()).await.unwrap();
+    assert_eq!(account.data.len(), PlatformConfig::LEN);
+}
+
+#[tokio::test]
+async fn migrates_v0_group_then_its_members() {
+    let fixture = Fixture::new();
+    let mut env = TestEnv::start_bare(fixture.accounts()).await;
+    env.migrate_account(&platform_config(), None, false).await.unwrap();
+
+    env.migrate_account(&fixture.group, None, true).await.unwrap();
+
+    let group = env.group(&fixture.group).await;
+    assert_eq!(group.version, Group::VERSION);
+    assert_eq!(group.model, GroupModel::Trust as u8);
+    assert_eq!(group.status, GroupStatus::Active as u8);
+    assert_eq!(group.creator, fixture.creator);
+    assert_eq!(group.member_cap, 10);
+    assert_eq!(group.current_turn_index, 1);
+    assert_eq!(group.total_members, 3);
+    assert_eq!(group.payout_order(), &fixture.payout_order[..]);
+    assert_eq!(group.current_turn_start, TURN_START + 7 * DAY);
+    assert_eq!(group.contribution_amount, 10 * USDC);
+    assert_eq!(group.cycle_days, 7);
+    assert_eq!(group.created_at, TURN_START);
+    assert_eq!(group.bump, 253);
+    assert_eq!(group.stake_vault, Pubkey::default());
+    assert_eq!(group.pause_offset, 0);
+    assert_eq!(group.paid_count(0), 0);
+
+    // Members carry their contributions into the group's turn bitmaps
+    let member_key = member(&fixture.group, &fixture.payout_order[1]);
+    env.migrate_account(&member_key, Some(fixture.group), false).await.unwrap();
+
+    let migrated: Member = env.fetch(&member_key).await;
+    assert_eq!(migrated.version, Member::VERSION);
+    assert_eq!(migrated.user, fixture.payout_order[1]);
+    assert_eq!(migrated.payout_position, 1);
+    assert_eq!(migrated.stake_amount, 2 * USDC);
+    assert!(migrated.has_contributed(0));
+    assert!(migrated.has_contributed(1));
+    assert!(!migrated.has_contributed(2));
+    assert_eq!(migrated.shorted_amount, 0);
+    assert_eq!(migrated.collateral_balance, 0);
+
+    let group = env.group(&fixture.group).await;
+    assert!(group.has_contributed(0, 1));
+    assert!(group.has_contributed(1, 1));
+    assert_eq!(group.paid_count(1), 1);
+}
+
+#[tokio::test]
+async fn migrates_v0_escrow_vault() {
+    let fixture = Fixture::new();
+    let mut env = TestEnv::start_bare(fixture.accounts()).await;
+
+    env.migrate_account(&escrow_vault(&fixture.group), None, false).await.unwrap();
+
+    let vault: EscrowVault = env.fetch(&escrow_vault(&fixture.group)).await;
+    assert_eq!(vault.version, EscrowVault::VERSION);
+    assert_eq!(vault.group, fixture.group);
+    assert_eq!(vault.vault_authority, escrow_vault(&fixture.group));
+    assert_eq!(vault.current_balance, 20 * USDC);
+    assert_eq!(vault.stake_balance, 0);
+    assert_eq!(vault.outstanding_debt, 0);
+    assert_eq!(vault.bump, 251);
+}
+
+#[tokio::test]
+async fn rejects_migrating_twice() {
+    let fixture = Fixture::new();
+    let mut env = TestEnv::start_bare(fixture.accounts()).await;
+    env.migrate_account(&escrow_vault(&fixture.group), None, false).await.unwrap();
+
+    let result = env.migrate_account(&escrow_vault(&fixture.group), None, false).await;
+    assert_group_error(result, OsemeGroupError::AccountAlreadyMigrated);
+}
+
+#[tokio::test]
+async fn requires_the_platform_config_to_migrate_a_group() {
+    let fixture = Fixture::new();
+    let mut env = TestEnv::start_bare(fixture.accounts()).await;
+
+    let result = env.migrate_account(&fixture.group, None, false).await;
+    assert_group_error(result, OsemeGroupError::PlatformConfigRequired);
+}
@@ -0,0 +1,85 @@
+//! `pause_group` and `resume_group` overlapping a platform pause; the turn
+//! moves back by the time either pause was running, counted once
+
+use anchor_lang::prelude::Pubkey;
+
+use crate::common::*;
+
+const CONTRIBUTION: u64 = 10 * USDC;
+
+async fn active_group(env: &mut TestEnv) -> (Pubkey, i64) {
+    let options = GroupOptions { member_cap: Some(2), ..Default::default() };
+    let (group, _, _) = env.group_with_members(GroupModel::Trust, CONTRIBUTION, options, 2).await;
+    let turn_start = env.group_status(&group).await.turn_start;
+    (group, turn_start)
+}
+
+#[tokio::test]
+async fn a_group_pause_starting_during_a_platform_pause() {
+    let mut env = TestEnv::start().await;
+    let (group, turn_start) = active_group(&mut env).await;
+
+    env.pause_platform().await.unwrap();
+    env.advance(DAY).await;
+    env.pause_group(&group, PauseReason::Maintenance).await.unwrap();
+    env.advance(DAY).await;
+    env.resume_platform().await.unwrap();
+    env.advance(DAY).await;
+    env.resume_group(&group).await.unwrap();
+
+    let status = env.group_status(&group).await;
+    assert_eq!(status.turn_start, turn_start + 3 * DAY);
+    let cycle_days = model_params(GroupModel::Trust).default_cycle_days as i64;
+    assert_eq!(status.next_deadline, status.turn_start + cycle_days * DAY);
+}
+
+#[tokio::test]
+async fn a_group_resumed_during_a_platform_pause() {
+    let mut env = TestEnv::start().await;
+    let (group, turn_start) = active_group(&mut env).await;
+
+    env.pause_group(&group, PauseReason::Maintenance).await.unwrap();
+    env.advance(DAY).await;
+    env.pause_platform().await.unwrap();
+    env.advance(DAY).await;
+    env.resume_group(&group).await.unwrap();
+
+    // The platform pause still running keeps moving the turn
+    env.advance(DAY).await;
+    assert_eq!(env.group_status(&group).await.turn_start, turn_start + 3 * DAY);
+    env.resume_platform().await.unwrap();
+    env.advance(DAY).await;
+    assert_eq!(env.group_status(&group).await.turn_start, turn_start + 3 * DAY);
+}
+
+#[tokio::test]
+async fn a_platform_pause_inside_a_group_pause() {
+    let mut env = TestEnv::start().await;
+    let (group, turn_start) = active_group(&mut env).await;
+
+    env.pause_group(&group, PauseReason::Maintenance).await.unwrap();
+    env.advance(DAY).await;
+    env.pause_platform().await.unwrap();
+    env.advance(DAY).await;
+    env.resume_platform().await.unwrap();
+    env.advance(DAY).await;
+    env.resume_group(&group).await.unwrap();
+
+    assert_eq!(env.group_status(&group).await.turn_start, turn_start + 3 * DAY);
+}
+
+#[tokio::test]
+async fn separate_pauses_add_up() {
+    let mut env = TestEnv::start().await;
+    let (group, turn_start) = active_group(&mut env).await;
+
+    env.pause_platform().await.unwrap();
+    env.advance(DAY).await;
+    env.resume_platform().await.unwrap();
+    env.advance(DAY).await;
+    env.pause_group(&group, PauseReason::Maintenance).await.unwrap();
+    env.advance(DAY).await;
+    env.resume_group(&group).await.unwrap();
+
+    assert_eq!(env.group_status(&group).await.turn_start, turn_start + 2 * DAY);
+}
@@ -0,0 +1,82 @@
+//! oseme-treasury `process_rebate`, paid when `finalize_group` closes a clean group
+
+use anchor_lang::prelude::Pubkey;
+use oseme_treasury::state::Subscription;
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::common::*;
+
+const CONTRIBUTION: u64 = 10 * USDC;
+const LARGE_REBATE_BPS: u16 = 6_000;
+
+/// A two-member Trust group of `creator` that has run all of its turns
+async fn completed_group(env: &mut TestEnv, creator: &Keypair) -> Pubkey {
+    let options = GroupOptions {
+        member_cap: Some(2),
+        cycle_days: Some(1),
+        ..Default::default()
+    };
+    let group = env.create_group(creator, GroupModel::Trust, CONTRIBUTION, options).await.unwrap();
+    let member_wallet = env.user(100 * CONTRIBUTION).await;
+    env.join_group(&group, creator, false).await.unwrap();
+    env.join_group(&group, &member_wallet, false).await.unwrap();
+    for _ in 0..2 {
+        env.run_turn(&group, &[creator, &member_wallet]).await;
+    }
+    // Group addresses are seeded with the creation time
+    env.advance(1).await;
+    group
+}
+
+async fn rebate_on_finalize(env: &mut TestEnv, group: &Pubkey, creator: &Keypair) -> u64 {
+    let balance = env.balance(&token_account(&creator.pubkey())).await;
+    env.finalize_group(group).await.unwrap();
+    env.balance(&token_account(&creator.pubkey())).await - balance
+}
+
+#[tokio::test]
+async fn a_clean_group_earns_a_share_of_the_subscription() {
+    let mut env = TestEnv::start().await;
+    let creator = env.user(100 * CONTRIBUTION).await;
+    let group = completed_group(&mut env, &creator).await;
+
+    let rebate = rebate_on_finalize(&mut env, &group, &creator).await;
+    assert_eq!(rebate, TRUST_SUBSCRIPTION_PRICE * REBATE_BPS as u64 / 10_000);
+    let paid: Subscription = env.fetch(&subscription(&creator.pubkey())).await;
+    assert_eq!(paid.rebated, rebate);
+}
+
+#[tokio::test]
+async fn rebates_across_groups_are_capped_at_the_period_price() {
+    let mut env = TestEnv::start_with(|config| config.rebate_bps = LARGE_REBATE_BPS).await;
+    let creator = env.user(100 * CONTRIBUTION).await;
+    let first = completed_group(&mut env, &creator).await;
+    let second = completed_group(&mut env, &creator).await;
+    let third = completed_group(&mut env, &creator).await;
+    let full_rebate = TRUST_SUBSCRIPTION_PRICE * LARGE_REBATE_BPS as u64 / 10_000;
+
+    assert_eq!(rebate_on_finalize(&mut env, &first, &creator).await, full_rebate);
+
+    // The second group only gets what is left of the period's price
+    assert_eq!(
+        rebate_on_finalize(&mut env, &second, &creator).await,
+        TRUST_SUBSCRIPTION_PRICE - full_rebate,
+    );
+    let paid: Subscription = env.fetch(&subscription(&creator.pubkey())).await;
+    assert_eq!(paid.rebated, TRUST_SUBSCRIPTION_PRICE);
+
+    // A new period allows rebates again
+    env.pay_subscription(&creator, SubscriptionTier::Trust).await.unwrap();
+    assert_eq!(env.fetch::<Subscription>(&subscription(&creator.pubkey())).await.rebated, 0);
+    assert_eq!(rebate_on_finalize(&mut env, &third, &creator).await, full_rebate);
+}
+
+#[tokio::test]
+async fn a_group_with_a_slash_earns_no_rebate() {
+    let mut env = TestEnv::start().await;
+    let (group, creator, _debtor, _shorted) = env.finalized_group_with_debt().await;
+
+    assert_eq!(env.group(&group).await.status, GroupStatus::Finalized as u8);
+    let paid: Subscription = env.fetch(&subscription(&creator.pubkey())).await;
+    assert_eq!(paid.rebated, 0);
+}
@@ -0,0 +1,77 @@
+//! `reconcile_escrow` shortfall and surplus detection
+
+use anchor_lang::prelude::Pubkey;
+
+use crate::common::*;
+
+const CONTRIBUTION: u64 = 10 * USDC;
+
+async fn active_group(env: &mut TestEnv) -> Pubkey {
+    let options = GroupOptions { member_cap: Some(2), ..Default::default() };
+    let (group, creator, _) = env.group_with_members(GroupModel::Trust, CONTRIBUTION, options, 2).await;
+    env.contribute(&group, &creator).await.unwrap();
+    group
+}
+
+async fn assert_active(env: &mut TestEnv, group: &Pubkey) {
+    let state = env.group(group).await;
+    assert_eq!(state.status, GroupStatus::Active as u8);
+    assert_eq!(state.paused_at, 0);
+}
+
+async fn assert_paused_for_discrepancy(env: &mut TestEnv, group: &Pubkey) {
+    let now = env.now().await;
+    let state = env.group(group).await;
+    assert_eq!(state.status, GroupStatus::Paused as u8);
+    assert_eq!(state.pause_reason, PauseReason::EscrowDiscrepancy as u8);
+    assert_eq!(state.paused_at, now);
+}
+
+#[tokio::test]
+async fn a_balanced_escrow_is_left_alone() {
+    let mut env = TestEnv::start().await;
+    let group = active_group(&mut env).await;
+
+    env.reconcile_escrow(&group).await.unwrap();
+    assert_active(&mut env, &group).await;
+}
+
+#[tokio::test]
+async fn missing_tokens_pause_the_group() {
+    let mut env = TestEnv::start().await;
+    let group = active_group(&mut env).await;
+
+    // The vault claims one unit of stake more than the token account holds
+    let mut vault: EscrowVault = env.fetch(&escrow_vault(&group)).await;
+    vault.stake_balance += 1;
+    env.store(&escrow_vault(&group), &vault).await;
+
+    env.reconcile_escrow(&group).await.unwrap();
+    assert_paused_for_discrepancy(&mut env, &group).await;
+}
+
+#[tokio::test]
+async fn an_underfunded_turn_pool_pauses_the_group() {
+    let mut env = TestEnv::start().await;
+    let group = active_group(&mut env).await;
+
+    // The token account still matches the vault, but one unit of the
+    // recorded contribution has moved into the stake balance
+    let mut vault: EscrowVault = env.fetch(&escrow_vault(&group)).await;
+    vault.current_balance -= 1;
+    vault.stake_balance += 1;
+    env.store(&escrow_vault(&group), &vault).await;
+
+    env.reconcile_escrow(&group).await.unwrap();
+    assert_paused_for_discrepancy(&mut env, &group).await;
+}
+
+#[tokio::test]
+async fn a_surplus_is_only_reported() {
+    let mut env = TestEnv::start().await;
+    let group = active_group(&mut env).await;
+
+    env.mint_to(&escrow_token(&group), 5).await;
+    env.reconcile_escrow(&group).await.unwrap();
+    assert_active(&mut env, &group).await;
+}
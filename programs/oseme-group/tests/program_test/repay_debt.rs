@@ -0,0 +1,109 @@
+//! `repay_debt` and `claim_recovery`
+
+use solana_sdk::signature::Signer;
+
+use crate::common::*;
+
+const CONTRIBUTION: u64 = 10 * USDC;
+
+#[tokio::test]
+async fn a_repayment_refills_the_open_turn_before_shorted_members() {
+    let mut env = TestEnv::start().await;
+    let options = GroupOptions {
+        member_cap: Some(3),
+        cycle_days: Some(1),
+        ..Default::default()
+    };
+    let (group, creator, others) = env.group_with_members(GroupModel::Trust, CONTRIBUTION, options, 3).await;
+    let (debtor, shorted) = (&others[0], &others[1]);
+    env.run_turn(&group, &[&creator, debtor, shorted]).await;
+    env.run_turn(&group, &[&creator, debtor, shorted]).await;
+
+    env.contribute(&group, &creator).await.unwrap();
+    env.contribute(&group, shorted).await.unwrap();
+    env.pass_grace_period(&group).await;
+    env.slash_member(&group, &debtor.pubkey()).await.unwrap();
+    let owed: Debt = env.fetch(&debt(&group, &debtor.pubkey())).await;
+    assert_eq!(env.group(&group).await.turn_shortfall, owed.outstanding);
+
+    assert_group_error(
+        env.repay_debt(&group, &debtor.pubkey(), debtor, owed.outstanding + 1).await,
+        OsemeGroupError::RepaymentExceedsDebt,
+    );
+
+    // Repaid before the payout, so it goes to the open turn
+    env.repay_debt(&group, &debtor.pubkey(), debtor, 5 * USDC).await.unwrap();
+    assert_eq!(env.group(&group).await.turn_shortfall, owed.outstanding - 5 * USDC);
+    let vault: EscrowVault = env.fetch(&escrow_vault(&group)).await;
+    assert_eq!(vault.recovery_balance, 0);
+
+    env.release_payout(&group).await.unwrap();
+    let recipient: Member = env.fetch(&member(&group, &shorted.pubkey())).await;
+    assert_eq!(recipient.shorted_amount, owed.outstanding - 5 * USDC);
+    assert_group_error(env.claim_recovery(&group, shorted).await, OsemeGroupError::NothingToClaim);
+
+    // The rest goes to the member who was shorted
+    env.repay_debt(&group, &debtor.pubkey(), debtor, recipient.shorted_amount).await.unwrap();
+    let repaid: Debt = env.fetch(&debt(&group, &debtor.pubkey())).await;
+    assert_eq!(repaid.outstanding, 0);
+    assert_eq!(repaid.repaid, owed.outstanding);
+    let record: DefaulterRecord = env.fetch(&defaulter_record(&debtor.pubkey())).await;
+    assert_eq!(record.open_debts, 0);
+    assert_eq!(record.outstanding_debt, 0);
+
+    let balance = env.balance(&token_account(&shorted.pubkey())).await;
+    env.claim_recovery(&group, shorted).await.unwrap();
+    assert_eq!(env.balance(&token_account(&shorted.pubkey())).await, balance + recipient.shorted_amount);
+}
+
+#[tokio::test]
+async fn a_claim_after_full_repayment_settles_the_shorted_member() {
+    let mut env = TestEnv::start().await;
+    let (group, _creator, debtor, shorted) = env.finalized_group_with_debt().await;
+    let owed: Debt = env.fetch(&debt(&group, &debtor.pubkey())).await;
+
+    // Anyone may repay on the debtor's behalf
+    let payer = env.user(100 * USDC).await;
+    env.repay_debt(&group, &debtor.pubkey(), &payer, owed.outstanding).await.unwrap();
+    let vault: EscrowVault = env.fetch(&escrow_vault(&group)).await;
+    assert_eq!(vault.recovery_balance, owed.outstanding);
+    assert_eq!(vault.outstanding_debt, 0);
+
+    let balance = env.balance(&token_account(&shorted.pubkey())).await;
+    env.claim_recovery(&group, &shorted).await.unwrap();
+    assert_eq!(env.balance(&token_account(&shorted.pubkey())).await, balance + owed.outstanding);
+
+    let settled: Member = env.fetch(&member(&group, &shorted.pubkey())).await;
+    assert_eq!(settled.shorted_amount, 0);
+    let vault: EscrowVault = env.fetch(&escrow_vault(&group)).await;
+    assert_eq!(vault.total_shorted, 0);
+    assert_eq!(vault.recovery_balance, 0);
+    assert_group_error(env.claim_recovery(&group, &shorted).await, OsemeGroupError::NothingToClaim);
+}
+
+#[tokio::test]
+async fn a_repayment_without_shorted_members_goes_to_the_treasury() {
+    let mut env = TestEnv::start().await;
+    let (group, _creator, debtor, _shorted) = env.finalized_group_with_debt().await;
+    let owed: Debt = env.fetch(&debt(&group, &debtor.pubkey())).await;
+
+    // An escrow whose shorted members are all gone
+    let mut vault: EscrowVault = env.fetch(&escrow_vault(&group)).await;
+    vault.total_shorted = 0;
+    env.store(&escrow_vault(&group), &vault).await;
+
+    let instruction = TestEnv::repay_debt_instruction(&group, &debtor.pubkey(), &debtor.pubkey(), owed.outstanding, false);
+    assert_group_error(
+        env.send(vec![instruction], &[&debtor]).await,
+        OsemeGroupError::TreasuryAccountsRequired,
+    );
+
+    let fees = env.balance(&fee_vault()).await;
+    env.repay_debt(&group, &debtor.pubkey(), &debtor, owed.outstanding).await.unwrap();
+    assert_eq!(env.balance(&fee_vault()).await, fees + owed.outstanding);
+
+    let vault: EscrowVault = env.fetch(&escrow_vault(&group)).await;
+    assert_eq!(vault.recovery_balance, 0);
+    assert_eq!(vault.outstanding_debt, 0);
+    assert_eq!(env.fetch::<Debt>(&debt(&group, &debtor.pubkey())).await.outstanding, 0);
+}
@@ -0,0 +1,79 @@
+//! `set_fee_schedule`, and the tier discounts `release_payout` charges
+
+use crate::common::*;
+
+const CONTRIBUTION: u64 = 10 * USDC;
+
+fn tier(min_trust_score: u8, min_creator_volume: u64, fee_bps: u16) -> FeeTier {
+    FeeTier { min_trust_score, min_creator_volume, fee_bps }
+}
+
+#[tokio::test]
+async fn invalid_schedules_are_rejected() {
+    let mut env = TestEnv::start().await;
+
+    let too_many = vec![tier(0, 0, 100); FeeSchedule::MAX_TIERS + 1];
+    assert_group_error(
+        env.set_fee_schedule(GroupModel::Trust, too_many).await,
+        OsemeGroupError::InvalidFeeSchedule,
+    );
+    assert_group_error(
+        env.set_fee_schedule(GroupModel::Trust, vec![tier(0, 0, 10_001)]).await,
+        OsemeGroupError::InvalidFeeSchedule,
+    );
+    assert!(!env.exists(&fee_schedule(GroupModel::Trust)).await);
+
+    env.set_fee_schedule(GroupModel::Trust, vec![tier(0, 0, 100); FeeSchedule::MAX_TIERS]).await.unwrap();
+    let schedule: FeeSchedule = env.fetch(&fee_schedule(GroupModel::Trust)).await;
+    assert_eq!(schedule.tiers().len(), FeeSchedule::MAX_TIERS);
+}
+
+#[tokio::test]
+async fn the_cheapest_qualifying_tier_applies() {
+    let mut env = TestEnv::start().await;
+    let tiers = vec![tier(50, 0, 180), tier(80, 1_000 * USDC, 150), tier(90, 0, 160)];
+    env.set_fee_schedule(GroupModel::Trust, tiers).await.unwrap();
+    let schedule: FeeSchedule = env.fetch(&fee_schedule(GroupModel::Trust)).await;
+    assert!(schedule.model == GroupModel::Trust);
+    assert_eq!(schedule.tiers().len(), 3);
+
+    assert_eq!(schedule.discount(200, 40, u64::MAX), None);
+    assert_eq!(schedule.discount(200, 50, 0), Some((0, 180)));
+    assert_eq!(schedule.discount(200, 90, 0), Some((2, 160)));
+    assert_eq!(schedule.discount(200, 90, 1_000 * USDC), Some((1, 150)));
+    // Tiers that do not undercut the model's fee are ignored
+    assert_eq!(schedule.discount(150, 90, 1_000 * USDC), None);
+    assert_eq!(schedule.discount(170, 90, 0), Some((2, 160)));
+
+    // Replacing the tiers drops the old ones
+    env.set_fee_schedule(GroupModel::Trust, vec![]).await.unwrap();
+    let schedule: FeeSchedule = env.fetch(&fee_schedule(GroupModel::Trust)).await;
+    assert!(schedule.tiers().is_empty());
+    assert_eq!(schedule.discount(200, 100, u64::MAX), None);
+}
+
+#[tokio::test]
+async fn discounts_are_capped_at_half_the_model_fee() {
+    let mut env = TestEnv::start().await;
+    env.set_fee_schedule(GroupModel::Trust, vec![tier(0, 0, 0), tier(0, 0, 120)]).await.unwrap();
+    let schedule: FeeSchedule = env.fetch(&fee_schedule(GroupModel::Trust)).await;
+
+    assert_eq!(schedule.discount(200, 0, 0), Some((0, 100)));
+    assert_eq!(schedule.discount(201, 0, 0), Some((0, 101)));
+    assert_eq!(schedule.discount(0, 0, 0), None);
+}
+
+#[tokio::test]
+async fn payouts_are_charged_the_capped_tier_rate() {
+    let mut env = TestEnv::start().await;
+    env.set_fee_schedule(GroupModel::Trust, vec![tier(0, 0, 50)]).await.unwrap();
+    let options = GroupOptions { member_cap: Some(2), ..Default::default() };
+    let (group, creator, others) = env.group_with_members(GroupModel::Trust, CONTRIBUTION, options, 2).await;
+    let fees = env.balance(&fee_vault()).await;
+
+    // The creator vault is opened by this first payout
+    env.run_turn(&group, &[&creator, &others[0]]).await;
+    let base_bps = model_params(GroupModel::Trust).fee_bps as u64;
+    let charged = env.balance(&fee_vault()).await + env.balance(&creator_vault()).await - fees;
+    assert_eq!(charged, 2 * CONTRIBUTION * (base_bps / 2) / 10_000);
+}
@@ -0,0 +1,60 @@
+//! `slash_member`
+
+use solana_sdk::signature::Signer;
+
+use crate::common::*;
+
+const CONTRIBUTION: u64 = 10 * USDC;
+
+#[tokio::test]
+async fn a_member_not_yet_paid_out_owes_no_debt() {
+    let mut env = TestEnv::start().await;
+    let options = GroupOptions {
+        member_cap: Some(3),
+        cycle_days: Some(1),
+        ..Default::default()
+    };
+    let (group, creator, others) = env.group_with_members(GroupModel::Trust, CONTRIBUTION, options, 3).await;
+    let (defaulter, other) = (&others[1], &others[0]);
+    let stake = env.fetch::<Member>(&member(&group, &defaulter.pubkey())).await.stake_amount;
+
+    env.contribute(&group, &creator).await.unwrap();
+    env.contribute(&group, other).await.unwrap();
+    assert_group_error(
+        env.slash_member(&group, &defaulter.pubkey()).await,
+        OsemeGroupError::TurnDeadlineNotReached,
+    );
+    env.pass_grace_period(&group).await;
+    env.slash_member(&group, &defaulter.pubkey()).await.unwrap();
+
+    // The shortfall is the open recipient's, and no debt account is created
+    assert_eq!(env.group(&group).await.turn_shortfall, CONTRIBUTION - stake);
+    assert!(!env.exists(&debt(&group, &defaulter.pubkey())).await);
+    let record: DefaulterRecord = env.fetch(&defaulter_record(&defaulter.pubkey())).await;
+    assert_eq!(record.defaults, 1);
+    assert_eq!(record.open_debts, 0);
+
+    let slashed: Member = env.fetch(&member(&group, &defaulter.pubkey())).await;
+    assert_eq!(slashed.stake_amount, 0);
+    assert_eq!(slashed.missed_count, 1);
+    assert_group_error(
+        env.slash_member(&group, &defaulter.pubkey()).await,
+        OsemeGroupError::TurnAlreadyCompleted,
+    );
+}
+
+#[tokio::test]
+async fn a_paid_out_member_owes_the_shortfall_as_debt() {
+    let mut env = TestEnv::start().await;
+    let (group, _creator, debtor, shorted) = env.finalized_group_with_debt().await;
+
+    let owed: Debt = env.fetch(&debt(&group, &debtor.pubkey())).await;
+    assert_eq!(owed.debtor, debtor.pubkey());
+    assert_eq!(owed.outstanding, 8 * USDC);
+    assert_eq!(owed.defaulted_turns, 1);
+    let record: DefaulterRecord = env.fetch(&defaulter_record(&debtor.pubkey())).await;
+    assert_eq!(record.open_debts, 1);
+    assert_eq!(record.outstanding_debt, owed.outstanding);
+    let recipient: Member = env.fetch(&member(&group, &shorted.pubkey())).await;
+    assert_eq!(recipient.shorted_amount, owed.outstanding);
+}
@@ -0,0 +1,113 @@
+//! `get_group_status` and `get_member_obligations`
+
+use solana_sdk::signature::Signer;
+
+use crate::common::*;
+
+const CONTRIBUTION: u64 = 10 * USDC;
+
+fn options() -> GroupOptions {
+    GroupOptions {
+        member_cap: Some(3),
+        cycle_days: Some(7),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn group_status_follows_the_open_turn() {
+    let mut env = TestEnv::start().await;
+    let (group, creator, members) = env.group_with_members(GroupModel::Trust, CONTRIBUTION, options(), 3).await;
+    let turn_start = env.group(&group).await.current_turn_start;
+
+    let status = env.group_status(&group).await;
+    assert!(status.status == GroupStatus::Active);
+    assert!(status.model == GroupModel::Trust);
+    assert_eq!(status.current_turn_index, 0);
+    assert_eq!(status.total_members, 3);
+    assert_eq!(status.paid_count, 0);
+    assert_eq!(status.contribution_amount, CONTRIBUTION);
+    assert_eq!(status.turn_start, turn_start);
+    assert_eq!(status.next_deadline, turn_start + 7 * DAY);
+    assert_eq!(status.grace_deadline, turn_start + (7 + GRACE_PERIOD_DAYS as i64) * DAY);
+    assert!(!status.in_grace);
+    assert_eq!(status.current_recipient, Some(creator.pubkey()));
+    assert_eq!(status.next_recipient, Some(members[0].pubkey()));
+
+    env.contribute(&group, &creator).await.unwrap();
+    env.contribute(&group, &members[1]).await.unwrap();
+    assert_eq!(env.group_status(&group).await.paid_count, 2);
+
+    env.advance(7 * DAY + 1).await;
+    assert!(env.group_status(&group).await.in_grace);
+}
+
+#[tokio::test]
+async fn group_status_after_the_last_payout() {
+    let mut env = TestEnv::start().await;
+    let (group, creator, members) = env.group_with_members(GroupModel::Trust, CONTRIBUTION, options(), 3).await;
+    let everyone = [&creator, &members[0], &members[1]];
+    env.run_turn(&group, &everyone).await;
+
+    let status = env.group_status(&group).await;
+    assert_eq!(status.current_turn_index, 1);
+    assert_eq!(status.current_recipient, Some(members[0].pubkey()));
+    assert_eq!(status.next_recipient, Some(members[1].pubkey()));
+
+    env.run_turn(&group, &everyone).await;
+    env.run_turn(&group, &everyone).await;
+
+    let status = env.group_status(&group).await;
+    assert!(status.status == GroupStatus::Completed);
+    assert_eq!(status.current_turn_index, 3);
+    assert_eq!(status.paid_count, 0);
+    assert_eq!(status.current_recipient, None);
+    assert_eq!(status.next_recipient, None);
+}
+
+#[tokio::test]
+async fn member_obligations_track_the_current_turn() {
+    let mut env = TestEnv::start().await;
+    let (group, _creator, members) = env.group_with_members(GroupModel::Trust, CONTRIBUTION, options(), 3).await;
+    let second = &members[0];
+
+    let obligations = env.member_obligations(&group, &second.pubkey()).await;
+    assert_eq!(obligations.user, second.pubkey());
+    assert_eq!(obligations.payout_position, 1);
+    assert!(!obligations.has_received_payout);
+    assert!(!obligations.paid_current_turn);
+    assert_eq!(obligations.amount_due, CONTRIBUTION);
+    assert_eq!(obligations.unpaid_turns, 0);
+    assert_eq!(obligations.missed_count, 0);
+    assert_eq!(obligations.stake_amount, obligations.required_stake);
+    assert_eq!(obligations.collateral_balance, 0);
+
+    env.contribute(&group, second).await.unwrap();
+    let obligations = env.member_obligations(&group, &second.pubkey()).await;
+    assert!(obligations.paid_current_turn);
+    assert_eq!(obligations.amount_due, 0);
+}
+
+#[tokio::test]
+async fn member_obligations_after_a_slash() {
+    let mut env = TestEnv::start().await;
+    let (group, creator, members) = env.group_with_members(GroupModel::Trust, CONTRIBUTION, options(), 3).await;
+    let everyone = [&creator, &members[0], &members[1]];
+    env.run_turn(&group, &everyone).await;
+    env.run_turn(&group, &everyone).await;
+
+    // The second member was paid out last turn and skips this one
+    env.contribute(&group, &creator).await.unwrap();
+    env.contribute(&group, &members[1]).await.unwrap();
+    env.pass_grace_period(&group).await;
+    let obligations = env.member_obligations(&group, &members[0].pubkey()).await;
+    assert!(obligations.has_received_payout);
+    assert_eq!(obligations.amount_due, CONTRIBUTION);
+    assert!(!obligations.in_grace);
+
+    env.slash_member(&group, &members[0].pubkey()).await.unwrap();
+    let obligations = env.member_obligations(&group, &members[0].pubkey()).await;
+    assert_eq!(obligations.missed_count, 1);
+    assert_eq!(obligations.amount_due, 0);
+    assert_eq!(obligations.stake_amount, 0);
+}
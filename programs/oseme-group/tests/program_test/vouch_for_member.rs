@@ -0,0 +1,101 @@
+//! `vouch_for_member` and `release_vouch`
+
+use anchor_lang::error::ErrorCode;
+use anchor_lang::prelude::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::common::*;
+
+const CONTRIBUTION: u64 = 10 * USDC;
+
+/// A Trust group with a staked member, closed to wallets without a vouch
+async fn vouching_group(env: &mut TestEnv) -> (Pubkey, Keypair, Keypair) {
+    let options = GroupOptions {
+        member_cap: Some(3),
+        ..Default::default()
+    };
+    let (group, creator, mut members) = env.group_with_members(GroupModel::Trust, CONTRIBUTION, options, 2).await;
+    env.update_model_config(GroupModel::Trust, |config| config.min_member_score = 101)
+        .await
+        .unwrap();
+    (group, creator, members.pop().unwrap())
+}
+
+#[tokio::test]
+async fn the_newcomer_must_co_sign() {
+    let mut env = TestEnv::start().await;
+    let (group, _creator, voucher) = vouching_group(&mut env).await;
+    let newcomer = Keypair::new();
+
+    let mut instruction = TestEnv::vouch_for_member_instruction(&group, &voucher.pubkey(), &newcomer.pubkey(), 2 * USDC);
+    for meta in instruction.accounts.iter_mut().filter(|meta| meta.pubkey == newcomer.pubkey()) {
+        meta.is_signer = false;
+    }
+    let result = env.send(vec![instruction], &[&voucher]).await;
+    assert_custom_error(result, ErrorCode::AccountNotSigner.into());
+    assert!(!env.exists(&vouch(&group, &newcomer.pubkey())).await);
+}
+
+#[tokio::test]
+async fn a_vouched_newcomer_joins_below_the_trust_threshold() {
+    let mut env = TestEnv::start().await;
+    let (group, _creator, voucher) = vouching_group(&mut env).await;
+    let newcomer = env.user(100 * USDC).await;
+    assert_group_error(env.join_group(&group, &newcomer, false).await, OsemeGroupError::InsufficientTrustScore);
+
+    let stake = env.fetch::<Member>(&member(&group, &voucher.pubkey())).await.stake_amount;
+    env.vouch_for_member(&group, &voucher, &newcomer, stake).await.unwrap();
+
+    let recorded: Vouch = env.fetch(&vouch(&group, &newcomer.pubkey())).await;
+    assert_eq!(recorded.voucher, voucher.pubkey());
+    assert_eq!(recorded.member, newcomer.pubkey());
+    assert_eq!(recorded.amount, stake);
+    assert_eq!(recorded.joined_at, 0);
+    let voucher_member: Member = env.fetch(&member(&group, &voucher.pubkey())).await;
+    assert_eq!(voucher_member.stake_amount, 0);
+
+    env.join_group(&group, &newcomer, false).await.unwrap();
+    let recorded: Vouch = env.fetch(&vouch(&group, &newcomer.pubkey())).await;
+    assert!(recorded.joined_at > 0);
+
+    // Locked until the group is over
+    assert_group_error(
+        env.release_vouch(&group, &voucher, &newcomer.pubkey()).await,
+        OsemeGroupError::VouchLocked,
+    );
+}
+
+#[tokio::test]
+async fn an_under_sized_vouch_is_released_and_replaced_before_joining() {
+    let mut env = TestEnv::start().await;
+    let (group, _creator, voucher) = vouching_group(&mut env).await;
+    let newcomer = env.user(100 * USDC).await;
+    let stake = env.fetch::<Member>(&member(&group, &voucher.pubkey())).await.stake_amount;
+
+    env.vouch_for_member(&group, &voucher, &newcomer, stake / 2).await.unwrap();
+    assert_group_error(env.join_group(&group, &newcomer, false).await, OsemeGroupError::VouchTooSmall);
+
+    let balance = env.balance(&token_account(&voucher.pubkey())).await;
+    env.release_vouch(&group, &voucher, &newcomer.pubkey()).await.unwrap();
+    assert!(!env.exists(&vouch(&group, &newcomer.pubkey())).await);
+    assert_eq!(env.balance(&token_account(&voucher.pubkey())).await, balance + stake / 2);
+
+    // Released stake goes back to the voucher's wallet, leaving only half to back a new vouch
+    assert_group_error(
+        env.vouch_for_member(&group, &voucher, &newcomer, stake).await,
+        OsemeGroupError::VouchExceedsStake,
+    );
+    env.vouch_for_member(&group, &voucher, &newcomer, stake / 2).await.unwrap();
+    assert_group_error(env.join_group(&group, &newcomer, false).await, OsemeGroupError::VouchTooSmall);
+}
+
+#[tokio::test]
+async fn cannot_vouch_for_self() {
+    let mut env = TestEnv::start().await;
+    let (group, _creator, voucher) = vouching_group(&mut env).await;
+
+    assert_group_error(
+        env.vouch_for_member(&group, &voucher, &voucher, USDC).await,
+        OsemeGroupError::CannotVouchForSelf,
+    );
+}
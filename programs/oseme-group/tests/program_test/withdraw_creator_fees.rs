@@ -0,0 +1,85 @@
+//! oseme-treasury `credit_creator_fees`, reached through `release_payout`,
+//! and `withdraw_creator_fees`
+
+use anchor_lang::error::ErrorCode;
+use oseme_treasury::state::CreatorRevenue;
+use solana_sdk::signature::Signer;
+
+use crate::common::*;
+
+const CONTRIBUTION: u64 = 10 * USDC;
+
+/// Fee and creator share of one payout of a two-member Trust group
+fn payout_fees() -> (u64, u64) {
+    let params = model_params(GroupModel::Trust);
+    let fee = 2 * CONTRIBUTION * params.fee_bps as u64 / 10_000;
+    (fee, fee * params.creator_share_bps as u64 / 10_000)
+}
+
+#[tokio::test]
+async fn each_payout_credits_the_creator_share() {
+    let mut env = TestEnv::start().await;
+    let options = GroupOptions { member_cap: Some(2), ..Default::default() };
+    let (group, creator, others) = env.group_with_members(GroupModel::Trust, CONTRIBUTION, options, 2).await;
+    let (fee, creator_fee) = payout_fees();
+    let fees = env.balance(&fee_vault()).await;
+
+    env.run_turn(&group, &[&creator, &others[0]]).await;
+    let revenue: CreatorRevenue = env.fetch(&creator_revenue(&creator.pubkey())).await;
+    assert_eq!(revenue.creator, creator.pubkey());
+    assert_eq!(revenue.balance, creator_fee);
+    assert_eq!(revenue.total_earned, creator_fee);
+    assert_eq!(revenue.payouts_credited, 1);
+    assert_eq!(env.balance(&creator_vault()).await, creator_fee);
+    assert_eq!(env.balance(&fee_vault()).await, fees + fee - creator_fee);
+
+    env.run_turn(&group, &[&creator, &others[0]]).await;
+    let revenue: CreatorRevenue = env.fetch(&creator_revenue(&creator.pubkey())).await;
+    assert_eq!(revenue.balance, 2 * creator_fee);
+    assert_eq!(revenue.payouts_credited, 2);
+}
+
+#[tokio::test]
+async fn a_creator_withdraws_only_their_own_balance() {
+    let mut env = TestEnv::start().await;
+    let options = GroupOptions { member_cap: Some(2), ..Default::default() };
+    let (group, creator, others) = env.group_with_members(GroupModel::Trust, CONTRIBUTION, options.clone(), 2).await;
+    let (other_group, other_creator, other_members) = env.group_with_members(GroupModel::Trust, CONTRIBUTION, options, 2).await;
+    env.run_turn(&group, &[&creator, &others[0]]).await;
+    env.run_turn(&other_group, &[&other_creator, &other_members[0]]).await;
+    let (_, creator_fee) = payout_fees();
+    assert_eq!(env.balance(&creator_vault()).await, 2 * creator_fee);
+
+    assert_treasury_error(
+        env.withdraw_creator_fees(&creator, creator_fee + 1).await,
+        OsemeTreasuryError::InsufficientCreatorBalance,
+    );
+    assert_treasury_error(env.withdraw_creator_fees(&creator, 0).await, OsemeTreasuryError::InvalidAmount);
+
+    let balance = env.balance(&token_account(&creator.pubkey())).await;
+    env.withdraw_creator_fees(&creator, creator_fee / 2).await.unwrap();
+    env.withdraw_creator_fees(&creator, creator_fee - creator_fee / 2).await.unwrap();
+    assert_eq!(env.balance(&token_account(&creator.pubkey())).await, balance + creator_fee);
+
+    let revenue: CreatorRevenue = env.fetch(&creator_revenue(&creator.pubkey())).await;
+    assert_eq!(revenue.balance, 0);
+    assert_eq!(revenue.total_withdrawn, creator_fee);
+    assert_eq!(revenue.total_earned, creator_fee);
+    assert_treasury_error(env.withdraw_creator_fees(&creator, 1).await, OsemeTreasuryError::InsufficientCreatorBalance);
+
+    // The other creator's share is still in the shared vault
+    assert_eq!(env.balance(&creator_vault()).await, creator_fee);
+    let revenue: CreatorRevenue = env.fetch(&creator_revenue(&other_creator.pubkey())).await;
+    assert_eq!(revenue.balance, creator_fee);
+}
+
+#[tokio::test]
+async fn a_wallet_without_revenue_cannot_withdraw() {
+    let mut env = TestEnv::start().await;
+    let wallet = env.user(0).await;
+
+    assert_custom_error(
+        env.withdraw_creator_fees(&wallet, 1).await,
+        ErrorCode::AccountNotInitialized.into(),
+    );
+}
@@ -0,0 +1,87 @@
+//! oseme-treasury `withdraw_fees` and its per-period limit
+
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::common::*;
+
+const LIMIT: u64 = 1_000 * USDC;
+const PERIOD: i64 = 7 * DAY;
+
+async fn funded_treasury() -> (TestEnv, Keypair) {
+    let mut env = TestEnv::start().await;
+    env.mint_to(&fee_vault(), 2 * LIMIT).await;
+    let authority = env.authority.insecure_clone();
+    (env, authority)
+}
+
+#[tokio::test]
+async fn withdrawals_are_limited_per_period() {
+    let (mut env, authority) = funded_treasury().await;
+    let start = env.fetch::<Treasury>(&treasury()).await.period_start;
+
+    env.withdraw_fees(&authority, 600 * USDC).await.unwrap();
+    assert_treasury_error(
+        env.withdraw_fees(&authority, 400 * USDC + 1).await,
+        OsemeTreasuryError::WithdrawalLimitExceeded,
+    );
+    env.withdraw_fees(&authority, 400 * USDC).await.unwrap();
+    assert_eq!(env.balance(&token_account(&authority.pubkey())).await, LIMIT);
+
+    let state: Treasury = env.fetch(&treasury()).await;
+    assert_eq!(state.withdrawn_this_period, LIMIT);
+    assert_eq!(state.withdrawable(), 0);
+    assert_eq!(state.total_fees_withdrawn, LIMIT);
+
+    // The limit resets only once the whole period has elapsed
+    let now = env.now().await;
+    env.advance(start + PERIOD - 1 - now).await;
+    assert_treasury_error(env.withdraw_fees(&authority, 1).await, OsemeTreasuryError::WithdrawalLimitExceeded);
+    env.advance(1).await;
+    env.withdraw_fees(&authority, 500 * USDC).await.unwrap();
+
+    let state: Treasury = env.fetch(&treasury()).await;
+    assert_eq!(state.period_start, start + PERIOD);
+    assert_eq!(state.withdrawn_this_period, 500 * USDC);
+    assert_eq!(state.total_fees_withdrawn, LIMIT + 500 * USDC);
+}
+
+#[tokio::test]
+async fn periods_stay_aligned_to_the_first_one() {
+    let (mut env, authority) = funded_treasury().await;
+    let start = env.fetch::<Treasury>(&treasury()).await.period_start;
+
+    env.advance(2 * PERIOD + 3 * DAY).await;
+    env.withdraw_fees(&authority, USDC).await.unwrap();
+
+    let mut state: Treasury = env.fetch(&treasury()).await;
+    assert_eq!(state.period_start, start + 2 * PERIOD);
+
+    // Rolling within the period keeps what was withdrawn
+    state.roll_period(start + 3 * PERIOD - 1);
+    assert_eq!(state.period_start, start + 2 * PERIOD);
+    assert_eq!(state.withdrawn_this_period, USDC);
+    state.roll_period(start + 5 * PERIOD + DAY);
+    assert_eq!(state.period_start, start + 5 * PERIOD);
+    assert_eq!(state.withdrawn_this_period, 0);
+}
+
+#[tokio::test]
+async fn a_lower_limit_counts_what_was_already_withdrawn() {
+    let (mut env, authority) = funded_treasury().await;
+
+    env.withdraw_fees(&authority, 600 * USDC).await.unwrap();
+    env.update_treasury(500 * USDC, 7).await.unwrap();
+
+    assert_eq!(env.fetch::<Treasury>(&treasury()).await.withdrawable(), 0);
+    assert_treasury_error(env.withdraw_fees(&authority, 1).await, OsemeTreasuryError::WithdrawalLimitExceeded);
+    assert_treasury_error(env.update_treasury(LIMIT, 0).await, OsemeTreasuryError::InvalidWithdrawalPeriod);
+}
+
+#[tokio::test]
+async fn only_the_authority_withdraws() {
+    let (mut env, authority) = funded_treasury().await;
+    let intruder = env.user(0).await;
+
+    assert_treasury_error(env.withdraw_fees(&intruder, USDC).await, OsemeTreasuryError::Unauthorized);
+    assert_treasury_error(env.withdraw_fees(&authority, 0).await, OsemeTreasuryError::InvalidAmount);
+}
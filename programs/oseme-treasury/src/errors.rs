@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum OsemeTreasuryError {
+    #[msg("Only the treasury authority can perform this action")]
+    Unauthorized,
+    
+    #[msg("Account must be migrated to the current layout")]
+    AccountVersionMismatch,
+    
+    #[msg("Amount must be greater than zero")]
+    InvalidAmount,
+    
+    #[msg("Withdrawal period must be at least one day")]
+    InvalidWithdrawalPeriod,
+    
+    #[msg("Withdrawal exceeds the limit for the current period")]
+    WithdrawalLimitExceeded,
+    
+    #[msg("Cannot switch to a lower tier while the subscription is active")]
+    SubscriptionDowngrade,
+    
+    #[msg("Platform configuration must be migrated to a versioned layout")]
+    PlatformConfigNotMigrated,
+    
+    #[msg("Withdrawal exceeds the creator's balance")]
+    InsufficientCreatorBalance,
+    
+    #[msg("USDC calculation error")]
+    UsdcCalculationError,
+}
@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct TreasuryInitialized {
+    pub authority: Pubkey,
+    pub fee_vault: Pubkey,
+    pub bonus_vault: Pubkey,
+    pub withdrawal_limit: u64,
+    pub withdrawal_period_days: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TreasuryUpdated {
+    pub withdrawal_limit: u64,
+    pub withdrawal_period_days: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FeesCollected {
+    pub source: Pubkey,                  // Escrow token account the fee came from
+    pub amount: u64,
+    pub total_fees_collected: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BonusPoolFunded {
+    pub funder: Pubkey,
+    pub amount: u64,
+    pub total_bonus_funded: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FeesWithdrawn {
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub withdrawn_this_period: u64,
+    pub period_start: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SubscriptionPaid {
+    pub creator: Pubkey,
+    pub tier: u8, // SubscriptionTier as u8
+    pub amount: u64,
+    pub expires_at: i64,
+    pub renewal: bool,                   // Extended an active subscription at the same tier
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RebatePaid {
+    pub creator: Pubkey,
+    pub group: Pubkey,
+    pub trust_score: u8,
+    pub amount: u64,
+    pub rebated: u64,                    // Rebated against the period so far
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BonusReleased {
+    pub destination: Pubkey,             // Group escrow token account holding the bonuses
+    pub amount: u64,
+    pub total_bonuses_paid: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CreatorFeesCredited {
+    pub creator: Pubkey,
+    pub source: Pubkey,                  // Escrow token account the share came from
+    pub amount: u64,
+    pub balance: u64,
+    pub total_earned: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CreatorFeesWithdrawn {
+    pub creator: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub balance: u64,
+    pub total_withdrawn: u64,
+    pub timestamp: i64,
+}
@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount, Transfer, transfer};
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::oseme_group_program;
+
+#[derive(Accounts)]
+pub struct CollectFees<'info> {
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED],
+        bump = treasury.bump,
+        constraint = treasury.version == Treasury::VERSION @ OsemeTreasuryError::AccountVersionMismatch
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    #[account(
+        mut,
+        address = treasury.fee_vault
+    )]
+    pub fee_vault: Account<'info, TokenAccount>,
+    
+    /// Group escrow token account paying the fee
+    #[account(mut)]
+    pub source: Account<'info, TokenAccount>,
+    
+    /// Owner of `source`, signs through the oseme-group CPI
+    pub source_authority: Signer<'info>,
+    
+    /// Signer PDA of the oseme-group program
+    #[account(
+        seeds = [b"treasury-authority"],
+        bump,
+        seeds::program = oseme_group_program::ID
+    )]
+    pub group_authority: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn collect_fees(ctx: Context<CollectFees>, amount: u64) -> Result<()> {
+    let treasury = &mut ctx.accounts.treasury;
+    let clock = Clock::get()?;
+    
+    if amount == 0 {
+        return Err(OsemeTreasuryError::InvalidAmount.into());
+    }
+    
+    let transfer_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.source.to_account_info(),
+            to: ctx.accounts.fee_vault.to_account_info(),
+            authority: ctx.accounts.source_authority.to_account_info(),
+        }
+    );
+    transfer(transfer_ctx, amount)?;
+    
+    treasury.total_fees_collected = treasury.total_fees_collected
+        .checked_add(amount)
+        .ok_or(OsemeTreasuryError::UsdcCalculationError)?;
+    
+    emit!(FeesCollected {
+        source: ctx.accounts.source.key(),
+        amount,
+        total_fees_collected: treasury.total_fees_collected,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
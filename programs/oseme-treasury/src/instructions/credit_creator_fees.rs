@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount, Transfer, transfer};
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::oseme_group_program;
+
+#[derive(Accounts)]
+pub struct CreditCreatorFees<'info> {
+    #[account(
+        seeds = [TREASURY_SEED],
+        bump = treasury.bump,
+        constraint = treasury.version == Treasury::VERSION @ OsemeTreasuryError::AccountVersionMismatch
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = CreatorRevenue::LEN,
+        seeds = [CREATOR_REVENUE_SEED, creator.key().as_ref()],
+        bump
+    )]
+    pub creator_revenue: Account<'info, CreatorRevenue>,
+    
+    /// CHECK: Group creator, only used as a seed
+    pub creator: UncheckedAccount<'info>,
+    
+    #[account(
+        init_if_needed,
+        payer = payer,
+        token::mint = usdc_mint,
+        token::authority = treasury,
+        seeds = [CREATOR_VAULT_SEED],
+        bump
+    )]
+    pub creator_vault: Account<'info, TokenAccount>,
+    
+    #[account(address = treasury.usdc_mint)]
+    pub usdc_mint: Account<'info, Mint>,
+    
+    /// Group escrow token account paying the share
+    #[account(mut)]
+    pub source: Account<'info, TokenAccount>,
+    
+    /// Owner of `source`, signs through the oseme-group CPI
+    pub source_authority: Signer<'info>,
+    
+    /// Signer PDA of the oseme-group program
+    #[account(
+        seeds = [b"treasury-authority"],
+        bump,
+        seeds::program = oseme_group_program::ID
+    )]
+    pub group_authority: Signer<'info>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+}
+
+/// The creator's account and the shared creator vault are created on the
+/// first credit
+pub fn credit_creator_fees(ctx: Context<CreditCreatorFees>, amount: u64) -> Result<()> {
+    let creator_revenue = &mut ctx.accounts.creator_revenue;
+    let clock = Clock::get()?;
+    
+    if amount == 0 {
+        return Err(OsemeTreasuryError::InvalidAmount.into());
+    }
+    
+    if creator_revenue.version == 0 {
+        creator_revenue.version = CreatorRevenue::VERSION;
+        creator_revenue.creator = ctx.accounts.creator.key();
+        creator_revenue.created_at = clock.unix_timestamp;
+        creator_revenue.bump = ctx.bumps.creator_revenue;
+    }
+    if creator_revenue.version != CreatorRevenue::VERSION {
+        return Err(OsemeTreasuryError::AccountVersionMismatch.into());
+    }
+    
+    let transfer_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.source.to_account_info(),
+            to: ctx.accounts.creator_vault.to_account_info(),
+            authority: ctx.accounts.source_authority.to_account_info(),
+        }
+    );
+    transfer(transfer_ctx, amount)?;
+    
+    creator_revenue.balance = creator_revenue.balance
+        .checked_add(amount)
+        .ok_or(OsemeTreasuryError::UsdcCalculationError)?;
+    creator_revenue.total_earned = creator_revenue.total_earned
+        .checked_add(amount)
+        .ok_or(OsemeTreasuryError::UsdcCalculationError)?;
+    creator_revenue.payouts_credited = creator_revenue.payouts_credited.saturating_add(1);
+    creator_revenue.last_credited_at = clock.unix_timestamp;
+    
+    emit!(CreatorFeesCredited {
+        creator: creator_revenue.creator,
+        source: ctx.accounts.source.key(),
+        amount,
+        balance: creator_revenue.balance,
+        total_earned: creator_revenue.total_earned,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount, Transfer, transfer};
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct FundBonusPool<'info> {
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED],
+        bump = treasury.bump,
+        constraint = treasury.version == Treasury::VERSION @ OsemeTreasuryError::AccountVersionMismatch
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    #[account(
+        mut,
+        address = treasury.bonus_vault
+    )]
+    pub bonus_vault: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = funder_token_account.mint == treasury.usdc_mint,
+        constraint = funder_token_account.owner == funder.key()
+    )]
+    pub funder_token_account: Account<'info, TokenAccount>,
+    
+    pub funder: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn fund_bonus_pool(ctx: Context<FundBonusPool>, amount: u64) -> Result<()> {
+    let treasury = &mut ctx.accounts.treasury;
+    let clock = Clock::get()?;
+    
+    if amount == 0 {
+        return Err(OsemeTreasuryError::InvalidAmount.into());
+    }
+    
+    let transfer_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.funder_token_account.to_account_info(),
+            to: ctx.accounts.bonus_vault.to_account_info(),
+            authority: ctx.accounts.funder.to_account_info(),
+        }
+    );
+    transfer(transfer_ctx, amount)?;
+    
+    treasury.total_bonus_funded = treasury.total_bonus_funded
+        .checked_add(amount)
+        .ok_or(OsemeTreasuryError::UsdcCalculationError)?;
+    
+    emit!(BonusPoolFunded {
+        funder: ctx.accounts.funder.key(),
+        amount,
+        total_bonus_funded: treasury.total_bonus_funded,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
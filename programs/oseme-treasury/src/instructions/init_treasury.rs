@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct InitTreasury<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = Treasury::LEN,
+        seeds = [TREASURY_SEED],
+        bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    #[account(
+        init,
+        payer = authority,
+        token::mint = usdc_mint,
+        token::authority = treasury,
+        seeds = [FEE_VAULT_SEED],
+        bump
+    )]
+    pub fee_vault: Account<'info, TokenAccount>,
+    
+    #[account(
+        init,
+        payer = authority,
+        token::mint = usdc_mint,
+        token::authority = treasury,
+        seeds = [BONUS_VAULT_SEED],
+        bump
+    )]
+    pub bonus_vault: Account<'info, TokenAccount>,
+    
+    pub usdc_mint: Account<'info, Mint>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn init_treasury(ctx: Context<InitTreasury>, withdrawal_limit: u64, withdrawal_period_days: u16) -> Result<()> {
+    let treasury = &mut ctx.accounts.treasury;
+    let clock = Clock::get()?;
+    
+    if withdrawal_period_days == 0 {
+        return Err(OsemeTreasuryError::InvalidWithdrawalPeriod.into());
+    }
+    
+    treasury.version = Treasury::VERSION;
+    treasury.authority = ctx.accounts.authority.key();
+    treasury.usdc_mint = ctx.accounts.usdc_mint.key();
+    treasury.fee_vault = ctx.accounts.fee_vault.key();
+    treasury.bonus_vault = ctx.accounts.bonus_vault.key();
+    treasury.total_fees_collected = 0;
+    treasury.total_fees_withdrawn = 0;
+    treasury.total_bonus_funded = 0;
+    treasury.total_bonuses_paid = 0;
+    treasury.withdrawal_limit = withdrawal_limit;
+    treasury.withdrawal_period_days = withdrawal_period_days;
+    treasury.period_start = clock.unix_timestamp;
+    treasury.withdrawn_this_period = 0;
+    treasury.created_at = clock.unix_timestamp;
+    treasury.bump = ctx.bumps.treasury;
+    
+    emit!(TreasuryInitialized {
+        authority: treasury.authority,
+        fee_vault: treasury.fee_vault,
+        bonus_vault: treasury.bonus_vault,
+        withdrawal_limit,
+        withdrawal_period_days,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,21 @@
+pub mod init_treasury;
+pub mod update_treasury;
+pub mod collect_fees;
+pub mod fund_bonus_pool;
+pub mod withdraw_fees;
+pub mod pay_subscription;
+pub mod process_rebate;
+pub mod release_bonus;
+pub mod credit_creator_fees;
+pub mod withdraw_creator_fees;
+
+pub use init_treasury::*;
+pub use update_treasury::*;
+pub use collect_fees::*;
+pub use fund_bonus_pool::*;
+pub use withdraw_fees::*;
+pub use pay_subscription::*;
+pub use process_rebate::*;
+pub use release_bonus::*;
+pub use credit_creator_fees::*;
+pub use withdraw_creator_fees::*;
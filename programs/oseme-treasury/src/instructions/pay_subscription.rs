@@ -0,0 +1,118 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount, Transfer, transfer};
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::oseme_group_program;
+
+#[derive(Accounts)]
+pub struct PaySubscription<'info> {
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = Subscription::LEN,
+        seeds = [SUBSCRIPTION_SEED, creator.key().as_ref()],
+        bump
+    )]
+    pub subscription: Account<'info, Subscription>,
+    
+    #[account(
+        seeds = [TREASURY_SEED],
+        bump = treasury.bump,
+        constraint = treasury.version == Treasury::VERSION @ OsemeTreasuryError::AccountVersionMismatch
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    #[account(
+        mut,
+        address = treasury.fee_vault
+    )]
+    pub fee_vault: Account<'info, TokenAccount>,
+    
+    /// CHECK: oseme-group PlatformConfig, read by SubscriptionPrices::load
+    #[account(
+        seeds = [b"platform-config"],
+        bump,
+        seeds::program = oseme_group_program::ID
+    )]
+    pub platform_config: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        constraint = creator_token_account.mint == treasury.usdc_mint,
+        constraint = creator_token_account.owner == creator.key()
+    )]
+    pub creator_token_account: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+}
+
+/// Paying at the active tier extends the subscription by one period;
+/// a new, lapsed or upgraded subscription starts a fresh period now
+pub fn pay_subscription(ctx: Context<PaySubscription>, tier: SubscriptionTier) -> Result<()> {
+    let subscription = &mut ctx.accounts.subscription;
+    let clock = Clock::get()?;
+    
+    let price = SubscriptionPrices::load(&ctx.accounts.platform_config)?.price(tier);
+    if price == 0 {
+        return Err(OsemeTreasuryError::InvalidAmount.into());
+    }
+    
+    if subscription.version == 0 {
+        subscription.version = Subscription::VERSION;
+        subscription.creator = ctx.accounts.creator.key();
+        subscription.tier = tier;
+        subscription.created_at = clock.unix_timestamp;
+        subscription.bump = ctx.bumps.subscription;
+    }
+    if subscription.version != Subscription::VERSION {
+        return Err(OsemeTreasuryError::AccountVersionMismatch.into());
+    }
+    
+    let active = subscription.is_active(clock.unix_timestamp);
+    if active && tier < subscription.tier {
+        return Err(OsemeTreasuryError::SubscriptionDowngrade.into());
+    }
+    
+    let transfer_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.creator_token_account.to_account_info(),
+            to: ctx.accounts.fee_vault.to_account_info(),
+            authority: ctx.accounts.creator.to_account_info(),
+        }
+    );
+    transfer(transfer_ctx, price)?;
+    
+    let period = Subscription::PERIOD_DAYS * Treasury::SECONDS_PER_DAY;
+    let renewal = active && tier == subscription.tier;
+    if renewal {
+        subscription.expires_at += period;
+    } else {
+        if !active {
+            subscription.started_at = clock.unix_timestamp;
+        }
+        subscription.tier = tier;
+        subscription.expires_at = clock.unix_timestamp + period;
+    }
+    subscription.period_price = price;
+    subscription.rebated = 0;
+    subscription.total_paid = subscription.total_paid
+        .checked_add(price)
+        .ok_or(OsemeTreasuryError::UsdcCalculationError)?;
+    
+    emit!(SubscriptionPaid {
+        creator: subscription.creator,
+        tier: tier as u8,
+        amount: price,
+        expires_at: subscription.expires_at,
+        renewal,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount, Transfer, transfer};
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::oseme_group_program;
+
+#[derive(Accounts)]
+pub struct ProcessRebate<'info> {
+    #[account(
+        seeds = [TREASURY_SEED],
+        bump = treasury.bump,
+        constraint = treasury.version == Treasury::VERSION @ OsemeTreasuryError::AccountVersionMismatch
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    #[account(
+        mut,
+        address = treasury.fee_vault
+    )]
+    pub fee_vault: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        seeds = [SUBSCRIPTION_SEED, subscription.creator.as_ref()],
+        bump = subscription.bump,
+        constraint = subscription.version == Subscription::VERSION @ OsemeTreasuryError::AccountVersionMismatch
+    )]
+    pub subscription: Account<'info, Subscription>,
+    
+    #[account(
+        mut,
+        constraint = creator_token_account.mint == treasury.usdc_mint,
+        constraint = creator_token_account.owner == subscription.creator
+    )]
+    pub creator_token_account: Account<'info, TokenAccount>,
+    
+    /// CHECK: Finalized group, only recorded in the event
+    pub group: UncheckedAccount<'info>,
+    
+    /// Signer PDA of the oseme-group program
+    #[account(
+        seeds = [b"treasury-authority"],
+        bump,
+        seeds::program = oseme_group_program::ID
+    )]
+    pub group_authority: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+/// Ineligible groups are not an error, so finalization never depends on the
+/// rebate; the rebate is capped by what is left of the period's price and
+/// by what the fee vault holds
+pub fn process_rebate(ctx: Context<ProcessRebate>, trust_score: u8, defaults: u8, params: RebateParams) -> Result<()> {
+    let treasury = &ctx.accounts.treasury;
+    let subscription = &mut ctx.accounts.subscription;
+    let clock = Clock::get()?;
+    
+    let amount = match params.rebate_for(trust_score, defaults, subscription.period_price) {
+        Some(rebate) => rebate
+            .min(subscription.rebate_left())
+            .min(ctx.accounts.fee_vault.amount),
+        None => return Ok(()),
+    };
+    if amount == 0 {
+        return Ok(());
+    }
+    
+    let seeds = &[
+        TREASURY_SEED,
+        &[treasury.bump]
+    ];
+    let signer_seeds = &[&seeds[..]];
+    
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.fee_vault.to_account_info(),
+            to: ctx.accounts.creator_token_account.to_account_info(),
+            authority: treasury.to_account_info(),
+        },
+        signer_seeds
+    );
+    transfer(transfer_ctx, amount)?;
+    
+    subscription.rebated += amount;
+    
+    emit!(RebatePaid {
+        creator: subscription.creator,
+        group: ctx.accounts.group.key(),
+        trust_score,
+        amount,
+        rebated: subscription.rebated,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
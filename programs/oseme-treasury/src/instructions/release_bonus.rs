@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount, Transfer, transfer};
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::oseme_group_program;
+
+#[derive(Accounts)]
+pub struct ReleaseBonus<'info> {
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED],
+        bump = treasury.bump,
+        constraint = treasury.version == Treasury::VERSION @ OsemeTreasuryError::AccountVersionMismatch
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    #[account(
+        mut,
+        address = treasury.bonus_vault
+    )]
+    pub bonus_vault: Account<'info, TokenAccount>,
+    
+    /// Group escrow token account the bonuses are paid from
+    #[account(
+        mut,
+        constraint = destination.mint == treasury.usdc_mint
+    )]
+    pub destination: Account<'info, TokenAccount>,
+    
+    /// Signer PDA of the oseme-group program
+    #[account(
+        seeds = [b"treasury-authority"],
+        bump,
+        seeds::program = oseme_group_program::ID
+    )]
+    pub group_authority: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+/// Bonuses count as paid once they leave the pool; oseme-group hands them
+/// out to the members
+pub fn release_bonus(ctx: Context<ReleaseBonus>, amount: u64) -> Result<()> {
+    let treasury = &mut ctx.accounts.treasury;
+    let clock = Clock::get()?;
+    
+    if amount == 0 {
+        return Err(OsemeTreasuryError::InvalidAmount.into());
+    }
+    
+    let seeds = &[
+        TREASURY_SEED,
+        &[treasury.bump]
+    ];
+    let signer_seeds = &[&seeds[..]];
+    
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.bonus_vault.to_account_info(),
+            to: ctx.accounts.destination.to_account_info(),
+            authority: treasury.to_account_info(),
+        },
+        signer_seeds
+    );
+    transfer(transfer_ctx, amount)?;
+    
+    treasury.total_bonuses_paid = treasury.total_bonuses_paid
+        .checked_add(amount)
+        .ok_or(OsemeTreasuryError::UsdcCalculationError)?;
+    
+    emit!(BonusReleased {
+        destination: ctx.accounts.destination.key(),
+        amount,
+        total_bonuses_paid: treasury.total_bonuses_paid,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
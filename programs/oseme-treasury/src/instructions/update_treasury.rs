@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct UpdateTreasury<'info> {
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED],
+        bump = treasury.bump,
+        has_one = authority @ OsemeTreasuryError::Unauthorized,
+        constraint = treasury.version == Treasury::VERSION @ OsemeTreasuryError::AccountVersionMismatch
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    pub authority: Signer<'info>,
+}
+
+/// A new period length takes effect from the current period's start
+pub fn update_treasury(ctx: Context<UpdateTreasury>, withdrawal_limit: u64, withdrawal_period_days: u16) -> Result<()> {
+    let treasury = &mut ctx.accounts.treasury;
+    let clock = Clock::get()?;
+    
+    if withdrawal_period_days == 0 {
+        return Err(OsemeTreasuryError::InvalidWithdrawalPeriod.into());
+    }
+    
+    treasury.withdrawal_limit = withdrawal_limit;
+    treasury.withdrawal_period_days = withdrawal_period_days;
+    
+    emit!(TreasuryUpdated {
+        withdrawal_limit,
+        withdrawal_period_days,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount, Transfer, transfer};
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct WithdrawCreatorFees<'info> {
+    #[account(
+        seeds = [TREASURY_SEED],
+        bump = treasury.bump,
+        constraint = treasury.version == Treasury::VERSION @ OsemeTreasuryError::AccountVersionMismatch
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    #[account(
+        mut,
+        seeds = [CREATOR_REVENUE_SEED, creator.key().as_ref()],
+        bump = creator_revenue.bump,
+        constraint = creator_revenue.version == CreatorRevenue::VERSION @ OsemeTreasuryError::AccountVersionMismatch
+    )]
+    pub creator_revenue: Account<'info, CreatorRevenue>,
+    
+    #[account(
+        mut,
+        seeds = [CREATOR_VAULT_SEED],
+        bump
+    )]
+    pub creator_vault: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = destination.mint == treasury.usdc_mint
+    )]
+    pub destination: Account<'info, TokenAccount>,
+    
+    pub creator: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn withdraw_creator_fees(ctx: Context<WithdrawCreatorFees>, amount: u64) -> Result<()> {
+    let treasury = &ctx.accounts.treasury;
+    let creator_revenue = &mut ctx.accounts.creator_revenue;
+    let clock = Clock::get()?;
+    
+    if amount == 0 {
+        return Err(OsemeTreasuryError::InvalidAmount.into());
+    }
+    if amount > creator_revenue.balance {
+        return Err(OsemeTreasuryError::InsufficientCreatorBalance.into());
+    }
+    
+    let seeds = &[
+        TREASURY_SEED,
+        &[treasury.bump]
+    ];
+    let signer_seeds = &[&seeds[..]];
+    
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.creator_vault.to_account_info(),
+            to: ctx.accounts.destination.to_account_info(),
+            authority: treasury.to_account_info(),
+        },
+        signer_seeds
+    );
+    transfer(transfer_ctx, amount)?;
+    
+    creator_revenue.balance -= amount;
+    creator_revenue.total_withdrawn = creator_revenue.total_withdrawn
+        .checked_add(amount)
+        .ok_or(OsemeTreasuryError::UsdcCalculationError)?;
+    
+    emit!(CreatorFeesWithdrawn {
+        creator: creator_revenue.creator,
+        destination: ctx.accounts.destination.key(),
+        amount,
+        balance: creator_revenue.balance,
+        total_withdrawn: creator_revenue.total_withdrawn,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
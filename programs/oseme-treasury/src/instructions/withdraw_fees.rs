@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount, Transfer, transfer};
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct WithdrawFees<'info> {
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED],
+        bump = treasury.bump,
+        has_one = authority @ OsemeTreasuryError::Unauthorized,
+        constraint = treasury.version == Treasury::VERSION @ OsemeTreasuryError::AccountVersionMismatch
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    #[account(
+        mut,
+        address = treasury.fee_vault
+    )]
+    pub fee_vault: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = destination.mint == treasury.usdc_mint
+    )]
+    pub destination: Account<'info, TokenAccount>,
+    
+    pub authority: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn withdraw_fees(ctx: Context<WithdrawFees>, amount: u64) -> Result<()> {
+    let treasury = &mut ctx.accounts.treasury;
+    let clock = Clock::get()?;
+    
+    if amount == 0 {
+        return Err(OsemeTreasuryError::InvalidAmount.into());
+    }
+    
+    treasury.roll_period(clock.unix_timestamp);
+    if amount > treasury.withdrawable() {
+        return Err(OsemeTreasuryError::WithdrawalLimitExceeded.into());
+    }
+    
+    let seeds = &[
+        TREASURY_SEED,
+        &[treasury.bump]
+    ];
+    let signer_seeds = &[&seeds[..]];
+    
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.fee_vault.to_account_info(),
+            to: ctx.accounts.destination.to_account_info(),
+            authority: treasury.to_account_info(),
+        },
+        signer_seeds
+    );
+    transfer(transfer_ctx, amount)?;
+    
+    treasury.withdrawn_this_period += amount;
+    treasury.total_fees_withdrawn = treasury.total_fees_withdrawn
+        .checked_add(amount)
+        .ok_or(OsemeTreasuryError::UsdcCalculationError)?;
+    
+    emit!(FeesWithdrawn {
+        destination: ctx.accounts.destination.key(),
+        amount,
+        withdrawn_this_period: treasury.withdrawn_this_period,
+        period_start: treasury.period_start,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
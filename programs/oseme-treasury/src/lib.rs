@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+
+declare_id!("2zYUNQevyLwCftM924EoYnDtdzWkVR3rKi4XUUMpabNo");
+
+pub mod instructions;
+pub mod state;
+pub mod errors;
+pub mod events;
+
+use instructions::*;
+use state::{RebateParams, SubscriptionTier};
+
+/// The oseme-group program; only its `treasury-authority` PDA may collect fees
+pub mod oseme_group_program {
+    use anchor_lang::declare_id;
+    declare_id!("HJoSULsRS49HQaLfDWvsM8qvsnhxHhQRsFTgQCRzGCvs");
+}
+
+#[program]
+pub mod oseme_treasury {
+    use super::*;
+
+    /// Create the treasury with its fee vault and bonus pool (admin only)
+    pub fn init_treasury(ctx: Context<InitTreasury>, withdrawal_limit: u64, withdrawal_period_days: u16) -> Result<()> {
+        instructions::init_treasury(ctx, withdrawal_limit, withdrawal_period_days)
+    }
+
+    /// Change the per-period fee withdrawal limit (admin only)
+    pub fn update_treasury(ctx: Context<UpdateTreasury>, withdrawal_limit: u64, withdrawal_period_days: u16) -> Result<()> {
+        instructions::update_treasury(ctx, withdrawal_limit, withdrawal_period_days)
+    }
+
+    /// Move a payout's platform fee into the fee vault (CPI from oseme-group)
+    pub fn collect_fees(ctx: Context<CollectFees>, amount: u64) -> Result<()> {
+        instructions::collect_fees(ctx, amount)
+    }
+
+    /// Add USDC to the bonus pool; anyone may fund it
+    pub fn fund_bonus_pool(ctx: Context<FundBonusPool>, amount: u64) -> Result<()> {
+        instructions::fund_bonus_pool(ctx, amount)
+    }
+
+    /// Withdraw collected fees within the current period's limit (admin only)
+    pub fn withdraw_fees(ctx: Context<WithdrawFees>, amount: u64) -> Result<()> {
+        instructions::withdraw_fees(ctx, amount)
+    }
+
+    /// Buy or renew a creator subscription for Trust or Super-Trust groups
+    pub fn pay_subscription(ctx: Context<PaySubscription>, tier: SubscriptionTier) -> Result<()> {
+        instructions::pay_subscription(ctx, tier)
+    }
+
+    /// Return part of the subscription to the creator of a clean, high-trust group (CPI from oseme-group)
+    pub fn process_rebate(ctx: Context<ProcessRebate>, trust_score: u8, defaults: u8, params: RebateParams) -> Result<()> {
+        instructions::process_rebate(ctx, trust_score, defaults, params)
+    }
+
+    /// Move completion bonuses for a finalized group out of the bonus pool (CPI from oseme-group)
+    pub fn release_bonus(ctx: Context<ReleaseBonus>, amount: u64) -> Result<()> {
+        instructions::release_bonus(ctx, amount)
+    }
+
+    /// Credit a creator's share of a payout fee (CPI from oseme-group)
+    pub fn credit_creator_fees(ctx: Context<CreditCreatorFees>, amount: u64) -> Result<()> {
+        instructions::credit_creator_fees(ctx, amount)
+    }
+
+    /// Withdraw accumulated creator fee shares
+    pub fn withdraw_creator_fees(ctx: Context<WithdrawCreatorFees>, amount: u64) -> Result<()> {
+        instructions::withdraw_creator_fees(ctx, amount)
+    }
+}
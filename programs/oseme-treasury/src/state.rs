@@ -0,0 +1,226 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use oseme_math::{Rounding, UsdcAmount};
+use crate::errors::OsemeTreasuryError;
+
+/// Seed of the treasury PDA, which owns both vaults
+pub const TREASURY_SEED: &[u8] = b"treasury";
+
+/// Seed of the USDC account holding collected platform fees
+pub const FEE_VAULT_SEED: &[u8] = b"fee-vault";
+
+/// Seed of the USDC account holding the completion bonus pool
+pub const BONUS_VAULT_SEED: &[u8] = b"bonus-vault";
+
+/// Seed of a creator's Subscription PDA
+pub const SUBSCRIPTION_SEED: &[u8] = b"subscription";
+
+/// Seed of a creator's CreatorRevenue PDA
+pub const CREATOR_REVENUE_SEED: &[u8] = b"creator-revenue";
+
+/// Seed of the USDC account holding every creator's unwithdrawn fee share
+pub const CREATOR_VAULT_SEED: &[u8] = b"creator-vault";
+
+/// Platform treasury and its lifetime accounting
+#[account]
+pub struct Treasury {
+    pub version: u8,                     // Layout version, see Treasury::VERSION
+    pub authority: Pubkey,
+    pub usdc_mint: Pubkey,
+    pub fee_vault: Pubkey,
+    pub bonus_vault: Pubkey,
+    pub total_fees_collected: u64,       // Lifetime platform fees received from payouts
+    pub total_fees_withdrawn: u64,       // Lifetime fees withdrawn by the authority
+    pub total_bonus_funded: u64,         // Lifetime deposits into the bonus pool
+    pub total_bonuses_paid: u64,         // Lifetime bonuses paid out of the pool
+    pub withdrawal_limit: u64,           // Max fees withdrawn per period
+    pub withdrawal_period_days: u16,
+    pub period_start: i64,               // Start of the current withdrawal period
+    pub withdrawn_this_period: u64,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl Treasury {
+    pub const VERSION: u8 = 1;
+    pub const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+    pub const LEN: usize = 8 + // discriminator
+        1 + // version
+        32 + // authority
+        32 + // usdc_mint
+        32 + // fee_vault
+        32 + // bonus_vault
+        8 + // total_fees_collected
+        8 + // total_fees_withdrawn
+        8 + // total_bonus_funded
+        8 + // total_bonuses_paid
+        8 + // withdrawal_limit
+        2 + // withdrawal_period_days
+        8 + // period_start
+        8 + // withdrawn_this_period
+        8 + // created_at
+        1; // bump
+
+    /// Start a new withdrawal period once the current one has elapsed;
+    /// periods stay aligned to the first one
+    pub fn roll_period(&mut self, now: i64) {
+        let period = self.withdrawal_period_days as i64 * Self::SECONDS_PER_DAY;
+        let elapsed = now.saturating_sub(self.period_start);
+        if elapsed >= period {
+            self.period_start += elapsed - elapsed % period;
+            self.withdrawn_this_period = 0;
+        }
+    }
+
+    /// Amount the authority may still withdraw in the current period
+    pub fn withdrawable(&self) -> u64 {
+        self.withdrawal_limit.saturating_sub(self.withdrawn_this_period)
+    }
+}
+
+/// Paid tier required to create Trust and Super-Trust groups
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(u8)]
+pub enum SubscriptionTier {
+    Trust,
+    SuperTrust,
+}
+
+/// A creator's subscription; renewals extend `expires_at`
+#[account]
+pub struct Subscription {
+    pub version: u8,                     // Layout version, see Subscription::VERSION
+    pub creator: Pubkey,
+    pub tier: SubscriptionTier,
+    pub started_at: i64,                 // Start of the current uninterrupted subscription
+    pub expires_at: i64,
+    pub period_price: u64,               // USDC paid for the most recent period
+    pub total_paid: u64,                 // Lifetime USDC paid by this creator
+    pub rebated: u64,                    // Rebates paid against the most recent period
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl Subscription {
+    pub const VERSION: u8 = 1;
+    pub const PERIOD_DAYS: i64 = 30;
+
+    pub const LEN: usize = 8 + // discriminator
+        1 + // version
+        32 + // creator
+        1 + // tier
+        8 + // started_at
+        8 + // expires_at
+        8 + // period_price
+        8 + // total_paid
+        8 + // rebated
+        8 + // created_at
+        1; // bump
+
+    pub fn is_active(&self, now: i64) -> bool {
+        now < self.expires_at
+    }
+
+    /// Active at `tier` or above
+    pub fn covers(&self, tier: SubscriptionTier, now: i64) -> bool {
+        self.is_active(now) && self.tier >= tier
+    }
+
+    /// Rebates across all of the creator's groups never exceed what the
+    /// most recent period cost
+    pub fn rebate_left(&self) -> u64 {
+        self.period_price.saturating_sub(self.rebated)
+    }
+}
+
+/// Fee shares earned by a creator across all of their groups
+#[account]
+pub struct CreatorRevenue {
+    pub version: u8,                     // Layout version, see CreatorRevenue::VERSION
+    pub creator: Pubkey,
+    pub balance: u64,                    // Held in the creator vault, not yet withdrawn
+    pub total_earned: u64,               // Lifetime fee shares credited
+    pub total_withdrawn: u64,
+    pub payouts_credited: u32,           // Payouts that paid this creator a share
+    pub last_credited_at: i64,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl CreatorRevenue {
+    pub const VERSION: u8 = 1;
+
+    pub const LEN: usize = 8 + // discriminator
+        1 + // version
+        32 + // creator
+        8 + // balance
+        8 + // total_earned
+        8 + // total_withdrawn
+        4 + // payouts_credited
+        8 + // last_credited_at
+        8 + // created_at
+        1; // bump
+}
+
+/// Rebate policy, passed in by oseme-group from its PlatformConfig
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct RebateParams {
+    pub min_trust_score: u8,             // Group trust score needed at finalization
+    pub rebate_bps: u16,                 // Share of the subscription period price returned (0 disables)
+}
+
+impl RebateParams {
+    /// Rebate owed for a finalized group, before capping by the fee vault
+    pub fn rebate_for(&self, trust_score: u8, defaults: u8, period_price: u64) -> Option<u64> {
+        if self.rebate_bps == 0 || defaults > 0 || trust_score < self.min_trust_score {
+            return None;
+        }
+        UsdcAmount::new(period_price)
+            .bps(self.rebate_bps, Rounding::Down)
+            .map(UsdcAmount::units)
+    }
+}
+
+/// Subscription prices, read from the stable prefix of oseme-group's
+/// PlatformConfig (discriminator, version, authority, fee_bps, prices)
+pub struct SubscriptionPrices {
+    pub trust: u64,
+    pub super_trust: u64,
+}
+
+impl SubscriptionPrices {
+    const TRUST_PRICE_OFFSET: usize = 8 + 1 + 32 + 2;
+    const SUPER_TRUST_PRICE_OFFSET: usize = Self::TRUST_PRICE_OFFSET + 8;
+
+    /// The caller checks the account address; the owner, discriminator and
+    /// a versioned layout are checked here
+    pub fn load(platform_config: &AccountInfo) -> Result<Self> {
+        if platform_config.owner != &crate::oseme_group_program::ID {
+            return Err(ErrorCode::AccountOwnedByWrongProgram.into());
+        }
+
+        let data = platform_config.try_borrow_data()?;
+        let discriminator = hash(b"account:PlatformConfig").to_bytes();
+        if data.len() < Self::SUPER_TRUST_PRICE_OFFSET + 8 || data[..8] != discriminator[..8] {
+            return Err(ErrorCode::AccountDidNotDeserialize.into());
+        }
+        // Unversioned configs have the authority right after the discriminator
+        if data[8] == 0 {
+            return Err(OsemeTreasuryError::PlatformConfigNotMigrated.into());
+        }
+
+        let read_u64 = |offset: usize| u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap());
+        Ok(Self {
+            trust: read_u64(Self::TRUST_PRICE_OFFSET),
+            super_trust: read_u64(Self::SUPER_TRUST_PRICE_OFFSET),
+        })
+    }
+
+    pub fn price(&self, tier: SubscriptionTier) -> u64 {
+        match tier {
+            SubscriptionTier::Trust => self.trust,
+            SubscriptionTier::SuperTrust => self.super_trust,
+        }
+    }
+}
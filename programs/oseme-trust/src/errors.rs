@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum OsemeTrustError {
+    #[msg("Trust account belongs to a different wallet")]
+    UserMismatch,
+    
+    #[msg("Account must be migrated to the current layout")]
+    AccountVersionMismatch,
+    
+    #[msg("Account is already on the current layout")]
+    AccountAlreadyMigrated,
+    
+    #[msg("Account layout is not supported for migration")]
+    UnsupportedAccountLayout,
+}
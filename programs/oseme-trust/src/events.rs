@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct TrustUpdated {
+    pub user: Pubkey,
+    pub kind: u8, // TrustEventKind as u8
+    pub delta: i8,
+    pub recovered: u16,                  // Default penalties won back by this event
+    pub score: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UserTrustMigrated {
+    pub user: Pubkey,
+    pub from_version: u8,
+    pub to_version: u8,
+    pub timestamp: i64,
+}
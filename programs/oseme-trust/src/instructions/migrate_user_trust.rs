@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_lang::Discriminator;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct MigrateUserTrust<'info> {
+    /// CHECK: Older layouts cannot be deserialized as the current one
+    #[account(mut, owner = crate::ID)]
+    pub user_trust: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Append the fields added since the account was created
+pub fn migrate_user_trust(ctx: Context<MigrateUserTrust>) -> Result<()> {
+    let account = ctx.accounts.user_trust.to_account_info();
+    let clock = Clock::get()?;
+    
+    let (from_version, user, last_updated) = {
+        let data = account.try_borrow_data()?;
+        if data.len() < UserTrust::LEN_V1 || data[..8] != UserTrust::DISCRIMINATOR {
+            return Err(OsemeTrustError::UnsupportedAccountLayout.into());
+        }
+        let user = Pubkey::try_from(&data[9..41]).unwrap();
+        let last_updated = i64::from_le_bytes(data[UserTrust::LEN_V1 - 9..UserTrust::LEN_V1 - 1].try_into().unwrap());
+        (data[8], user, last_updated)
+    };
+    
+    if from_version == UserTrust::VERSION {
+        return Err(OsemeTrustError::AccountAlreadyMigrated.into());
+    }
+    if from_version != 1 {
+        return Err(OsemeTrustError::UnsupportedAccountLayout.into());
+    }
+    
+    // Keep the resized account rent exempt
+    let rent_exempt = Rent::get()?.minimum_balance(UserTrust::LEN);
+    let shortfall = rent_exempt.saturating_sub(account.lamports());
+    if shortfall > 0 {
+        let transfer_ctx = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.payer.to_account_info(),
+                to: account.clone(),
+            }
+        );
+        transfer(transfer_ctx, shortfall)?;
+    }
+    
+    account.realloc(UserTrust::LEN, true)?;
+    let mut data = account.try_borrow_mut_data()?;
+    data[8] = UserTrust::VERSION;
+    // Decay starts from the last recorded event; no recovery is outstanding
+    data[UserTrust::LEN_V1..UserTrust::LEN_V1 + 8].copy_from_slice(&last_updated.to_le_bytes());
+    
+    emit!(UserTrustMigrated {
+        user,
+        from_version,
+        to_version: UserTrust::VERSION,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
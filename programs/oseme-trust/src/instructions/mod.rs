@@ -0,0 +1,5 @@
+pub mod record_trust_event;
+pub mod migrate_user_trust;
+
+pub use record_trust_event::*;
+pub use migrate_user_trust::*;
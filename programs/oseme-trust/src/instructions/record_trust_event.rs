@@ -0,0 +1,118 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::oseme_group_program;
+
+#[derive(Accounts)]
+pub struct RecordTrustEvent<'info> {
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = UserTrust::LEN,
+        seeds = [b"user-trust", user.key().as_ref()],
+        bump
+    )]
+    pub user_trust: Account<'info, UserTrust>,
+    
+    /// CHECK: Wallet whose reputation is updated, only used as a seed
+    pub user: UncheckedAccount<'info>,
+    
+    /// Signer PDA of the oseme-group program
+    #[account(
+        seeds = [b"trust-authority"],
+        bump,
+        seeds::program = oseme_group_program::ID
+    )]
+    pub group_authority: Signer<'info>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn record_contribution(ctx: Context<RecordTrustEvent>, params: TrustParams) -> Result<()> {
+    let user_trust = load_user_trust(&mut ctx.accounts.user_trust, ctx.accounts.user.key(), ctx.bumps.user_trust, &params)?;
+    
+    user_trust.contributions = user_trust.contributions.saturating_add(1);
+    
+    emit_trust_updated(user_trust, TrustEventKind::Contribution, 0, 0)
+}
+
+pub fn record_default(ctx: Context<RecordTrustEvent>, penalty: i8, params: TrustParams) -> Result<()> {
+    let user_trust = load_user_trust(&mut ctx.accounts.user_trust, ctx.accounts.user.key(), ctx.bumps.user_trust, &params)?;
+    
+    user_trust.defaults = user_trust.defaults.saturating_add(1);
+    user_trust.apply_delta(penalty);
+    user_trust.start_recovery(penalty, &params);
+    
+    emit_trust_updated(user_trust, TrustEventKind::Default, penalty, 0)
+}
+
+pub fn record_completion(ctx: Context<RecordTrustEvent>, bonus: i8, clean: bool, params: TrustParams) -> Result<()> {
+    let user_trust = load_user_trust(&mut ctx.accounts.user_trust, ctx.accounts.user.key(), ctx.bumps.user_trust, &params)?;
+    
+    user_trust.groups_completed = user_trust.groups_completed.saturating_add(1);
+    let (delta, recovered) = if clean {
+        user_trust.clean_groups_completed = user_trust.clean_groups_completed.saturating_add(1);
+        (bonus, user_trust.recover())
+    } else {
+        (0, 0)
+    };
+    user_trust.apply_delta(delta);
+    
+    emit_trust_updated(user_trust, TrustEventKind::Completion, delta, recovered)
+}
+
+pub fn record_vouch(ctx: Context<RecordTrustEvent>, bonus: i8, params: TrustParams) -> Result<()> {
+    let user_trust = load_user_trust(&mut ctx.accounts.user_trust, ctx.accounts.user.key(), ctx.bumps.user_trust, &params)?;
+    
+    user_trust.apply_delta(bonus);
+    
+    emit_trust_updated(user_trust, TrustEventKind::Vouch, bonus, 0)
+}
+
+/// Initialize a freshly created account, otherwise check it is usable
+fn load_user_trust<'a>(
+    user_trust: &'a mut Account<UserTrust>,
+    user: Pubkey,
+    bump: u8,
+    params: &TrustParams,
+) -> Result<&'a mut UserTrust> {
+    let clock = Clock::get()?;
+    
+    if user_trust.version == 0 {
+        user_trust.version = UserTrust::VERSION;
+        user_trust.user = user;
+        user_trust.score = UserTrust::INITIAL_SCORE;
+        user_trust.created_at = clock.unix_timestamp;
+        user_trust.decayed_at = clock.unix_timestamp;
+        user_trust.bump = bump;
+    }
+    
+    if user_trust.version != UserTrust::VERSION {
+        return Err(OsemeTrustError::AccountVersionMismatch.into());
+    }
+    if user_trust.user != user {
+        return Err(OsemeTrustError::UserMismatch.into());
+    }
+    
+    // Bring the stored score up to date before applying the event
+    user_trust.apply_decay(clock.unix_timestamp, params);
+    user_trust.last_updated = clock.unix_timestamp;
+    Ok(user_trust)
+}
+
+fn emit_trust_updated(user_trust: &UserTrust, kind: TrustEventKind, delta: i8, recovered: u16) -> Result<()> {
+    emit!(TrustUpdated {
+        user: user_trust.user,
+        kind: kind as u8,
+        delta,
+        recovered,
+        score: user_trust.score,
+        timestamp: user_trust.last_updated,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+declare_id!("FVMJytziHytWzZRpQgHmNUQEjtaLnMsgjfQfApVZxdbS");
+
+pub mod instructions;
+pub mod state;
+pub mod errors;
+pub mod events;
+
+use instructions::*;
+use state::TrustParams;
+
+/// The oseme-group program; only its `trust-authority` PDA may update reputation
+pub mod oseme_group_program {
+    use anchor_lang::declare_id;
+    declare_id!("HJoSULsRS49HQaLfDWvsM8qvsnhxHhQRsFTgQCRzGCvs");
+}
+
+#[program]
+pub mod oseme_trust {
+    use super::*;
+
+    /// Record an on-time or late contribution (CPI from oseme-group)
+    pub fn record_contribution(ctx: Context<RecordTrustEvent>, params: TrustParams) -> Result<()> {
+        instructions::record_contribution(ctx, params)
+    }
+
+    /// Record a missed contribution and apply the platform penalty (CPI from oseme-group)
+    pub fn record_default(ctx: Context<RecordTrustEvent>, penalty: i8, params: TrustParams) -> Result<()> {
+        instructions::record_default(ctx, penalty, params)
+    }
+
+    /// Record a finished group membership and apply the completion bonus (CPI from oseme-group)
+    pub fn record_completion(ctx: Context<RecordTrustEvent>, bonus: i8, clean: bool, params: TrustParams) -> Result<()> {
+        instructions::record_completion(ctx, bonus, clean, params)
+    }
+
+    /// Reward a voucher whose newcomer finished a group cleanly (CPI from oseme-group)
+    pub fn record_vouch(ctx: Context<RecordTrustEvent>, bonus: i8, params: TrustParams) -> Result<()> {
+        instructions::record_vouch(ctx, bonus, params)
+    }
+
+    /// Upgrade a UserTrust created by an earlier program version to the current layout
+    pub fn migrate_user_trust(ctx: Context<MigrateUserTrust>) -> Result<()> {
+        instructions::migrate_user_trust(ctx)
+    }
+}
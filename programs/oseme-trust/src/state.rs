@@ -0,0 +1,128 @@
+use anchor_lang::prelude::*;
+
+/// Long-term reputation of a wallet across all groups
+#[account]
+pub struct UserTrust {
+    pub version: u8,                     // Layout version, see UserTrust::VERSION
+    pub user: Pubkey,
+    pub score: u16,                      // Cumulative trust score (starts at 100)
+    pub contributions: u32,              // Contributions made across all groups
+    pub defaults: u32,                   // Missed contributions across all groups
+    pub groups_completed: u32,           // Memberships that reached finalization
+    pub clean_groups_completed: u32,     // Of which without a missed contribution
+    pub created_at: i64,
+    pub last_updated: i64,
+    pub bump: u8,
+    pub decayed_at: i64,                 // Decay has been applied to `score` up to here
+    pub recovery_debt: u16,              // Default penalties not yet won back
+    pub recovery_remaining: u8,          // Clean completions left to win back recovery_debt
+}
+
+/// Decay and recovery schedule, passed in by oseme-group from its PlatformConfig
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct TrustParams {
+    pub decay_period_days: u16,          // Score moves one step toward neutral per period (0 disables)
+    pub decay_step: u8,                  // Points per decay period
+    pub recovery_groups: u8,             // Clean completions to win back default penalties (0 disables)
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum TrustEventKind {
+    Contribution,
+    Default,
+    Completion,
+    Vouch,
+}
+
+impl UserTrust {
+    pub const VERSION: u8 = 2;
+    pub const INITIAL_SCORE: u16 = 100;
+    pub const NEUTRAL_SCORE: u16 = Self::INITIAL_SCORE;
+    pub const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+    pub const LEN: usize = 8 + // discriminator
+        1 + // version
+        32 + // user
+        2 + // score
+        4 + // contributions
+        4 + // defaults
+        4 + // groups_completed
+        4 + // clean_groups_completed
+        8 + // created_at
+        8 + // last_updated
+        1 + // bump
+        8 + // decayed_at
+        2 + // recovery_debt
+        1; // recovery_remaining
+
+    /// Size of the v1 layout, before decay and recovery
+    pub const LEN_V1: usize = Self::LEN - 8 - 2 - 1;
+
+    /// Score as of `now`; decay is derived from stored timestamps so it can
+    /// be evaluated on read without writing the account
+    pub fn effective_score(&self, now: i64, params: &TrustParams) -> u16 {
+        self.decayed(now, params).0
+    }
+
+    /// Write decay up to `now` into the score; partial periods carry over
+    pub fn apply_decay(&mut self, now: i64, params: &TrustParams) {
+        let (score, decayed_at) = self.decayed(now, params);
+
+        // Decay back up to neutral already forgives that much of a default
+        if score > self.score {
+            self.recovery_debt = self.recovery_debt.saturating_sub(score - self.score);
+            if self.recovery_debt == 0 {
+                self.recovery_remaining = 0;
+            }
+        }
+        self.score = score;
+        self.decayed_at = decayed_at;
+    }
+
+    /// Record a default's penalty so clean completions can win it back
+    pub fn start_recovery(&mut self, penalty: i8, params: &TrustParams) {
+        if params.recovery_groups > 0 && penalty < 0 {
+            self.recovery_debt = self.recovery_debt.saturating_add(penalty.unsigned_abs() as u16);
+            self.recovery_remaining = params.recovery_groups;
+        }
+    }
+
+    /// Win back an equal share of the outstanding penalties, returns the points restored
+    pub fn recover(&mut self) -> u16 {
+        if self.recovery_remaining == 0 {
+            return 0;
+        }
+        let restored = self.recovery_debt / self.recovery_remaining as u16;
+        self.recovery_debt -= restored;
+        self.recovery_remaining -= 1;
+        self.score = self.score.saturating_add(restored);
+        restored
+    }
+
+    fn decayed(&self, now: i64, params: &TrustParams) -> (u16, i64) {
+        let period = params.decay_period_days as i64 * Self::SECONDS_PER_DAY;
+        if period == 0 || params.decay_step == 0 {
+            return (self.score, now.max(self.decayed_at));
+        }
+
+        let periods = (now - self.decayed_at).max(0) / period;
+        let decay = (periods as u64)
+            .saturating_mul(params.decay_step as u64)
+            .min(u16::MAX as u64) as u16;
+        let score = if self.score > Self::NEUTRAL_SCORE {
+            self.score.saturating_sub(decay).max(Self::NEUTRAL_SCORE)
+        } else {
+            self.score.saturating_add(decay).min(Self::NEUTRAL_SCORE)
+        };
+        (score, self.decayed_at + periods * period)
+    }
+
+    pub fn apply_delta(&mut self, delta: i8) {
+        self.score = if delta < 0 {
+            self.score.saturating_sub(delta.unsigned_abs() as u16)
+        } else {
+            self.score.saturating_add(delta as u16)
+        };
+    }
+}